@@ -1,24 +1,106 @@
-use crate::config::Difficulty;
-use crate::config::ui_text;
-use crate::core::Game;
+use crate::bot::{Bot, RandomBot, SolverBot, Tournament, print_report};
+use crate::config::game::MAX_CUSTOM_NEIGHBOURS;
+use crate::config::{
+    Difficulty, coop_config, countdown_config, cube_board_config, difficulty_to_board_config,
+    difficulty_to_hex_board_config, difficulty_to_neighbourhood_board_config, flags_board_config,
+    graph, graph_mine_count, infinite_board_config, multi_mine_board_config,
+    negative_mine_board_config, time_attack_config, versus_config,
+};
+use crate::config::{Settings, coop, lives, tournament, ui, ui_text, versus};
+use crate::core::coop::MAX_PLAYERS;
+use crate::core::{
+    BoardGraph, CustomOffsets, FlagsGame, Game, Game3D, GraphGame, InfiniteGame, Neighbourhood,
+    TimedGame,
+};
+use crate::i18n::{select_locale, set_locale};
+use crate::net::{CoopClient, CoopServer, VersusClient, VersusServer};
+use crate::render::{
+    ColorDepth, GlyphSet, Palette, RenderMode, Theme, select_render_mode, set_render_mode,
+    set_theme,
+};
+use crate::t;
 use std::io::{self, Write};
+use std::thread;
 
 /// 应用程序主入口点
 pub fn run() {
-    println!("{}", ui_text::GAME_TITLE);
-    println!("{}", ui_text::DIFFICULTY_PROMPT);
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    // --lang <语言>：优先于设置文件和环境变量
+    let (settings, unrecognised) = Settings::load(ui::SETTINGS_FILE);
+    set_locale(select_locale(option_value(&args, "--lang"), settings.lang));
+    // --render <ansi|ascii|compact|accessible>：优先于设置文件，默认按终端环境选择；
+    // --accessible 是 --render accessible 的简写
+    let render = if args.iter().any(|arg| arg == "--accessible") {
+        Some(RenderMode::Accessible.name())
+    } else {
+        option_value(&args, "--render")
+    };
+    set_render_mode(select_render_mode(render, settings.render));
+    // --theme <配色> --glyphs <图标> --colors <颜色深度>：优先于设置文件，颜色深度默认按终端判断
+    let detected = Theme::detect();
+    set_theme(Theme {
+        palette: option_value(&args, "--theme")
+            .and_then(Palette::parse)
+            .or(settings.theme)
+            .unwrap_or(detected.palette),
+        glyphs: option_value(&args, "--glyphs")
+            .and_then(GlyphSet::parse)
+            .or(settings.glyphs)
+            .unwrap_or(detected.glyphs),
+        depth: option_value(&args, "--colors")
+            .and_then(ColorDepth::parse)
+            .or(settings.colors)
+            .unwrap_or(detected.depth),
+    });
+    for (line, text) in unrecognised {
+        eprintln!("{}", t!("error.settings", ui::SETTINGS_FILE, line, text));
+    }
+
+    // --bot：以 JSON-lines 协议在标准输入输出上运行，供程序驱动
+    if args.iter().any(|arg| arg == "--bot") {
+        if let Err(err) = crate::bot::run_stdio() {
+            eprintln!("{}", t!("error.bot_io", err));
+        }
+        return;
+    }
+
+    // --tournament [局数]：内置机器人在各难度的相同种子上对局并输出比较
+    if let Some(index) = args.iter().position(|arg| arg == "--tournament") {
+        let games = args
+            .get(index + 1)
+            .and_then(|games| games.parse().ok())
+            .unwrap_or(tournament::DEFAULT_GAMES);
+        run_tournament(games);
+        return;
+    }
+
+    println!("{}", t!("menu.title"));
+    println!("{}", t!("menu.prompt"));
     println!("{}", ui_text::easy_desc());
     println!("{}", ui_text::medium_desc());
     println!("{}", ui_text::hard_desc());
-    println!("{}", ui_text::DEMO_DESC);
+    println!("{}", t!("menu.demo"));
+    println!("{}", ui_text::hex_desc());
+    println!("{}", ui_text::cube_desc());
+    println!("{}", ui_text::infinite_desc());
+    println!("{}", ui_text::graph_desc());
+    println!("{}", t!("menu.neighbourhood"));
+    println!("{}", ui_text::multi_mine_desc());
+    println!("{}", ui_text::negative_mine_desc());
+    println!("{}", ui_text::lives_desc());
+    println!("{}", ui_text::timed_desc());
+    println!("{}", ui_text::flags_desc());
+    println!("{}", ui_text::versus_desc());
+    println!("{}", ui_text::coop_desc());
 
     let difficulty = loop {
-        print!("{}", ui_text::INPUT_PROMPT);
+        print!("{}", t!("menu.input"));
         io::stdout().flush().unwrap();
 
         let mut input = String::new();
         if io::stdin().read_line(&mut input).is_err() {
-            println!("{}", ui_text::INPUT_ERROR);
+            println!("{}", t!("menu.input_error"));
             continue;
         }
 
@@ -30,16 +112,298 @@ pub fn run() {
                 crate::demo::run_demo_mode();
                 return;
             }
+            "5" => {
+                println!("{}", t!("menu.game_start"));
+                let mut game = Game::with_config(difficulty_to_hex_board_config(Difficulty::Easy));
+                game.run();
+                return;
+            }
+            "6" => {
+                println!("{}", t!("menu.game_start"));
+                let mut game = Game3D::new(cube_board_config());
+                game.run();
+                return;
+            }
+            "7" => {
+                println!("{}", t!("menu.game_start"));
+                let mut game = InfiniteGame::new(infinite_board_config(rand::random()));
+                game.run();
+                return;
+            }
+            "8" => {
+                run_graph_mode();
+                return;
+            }
+            "9" => {
+                run_neighbourhood_mode();
+                return;
+            }
+            "10" => {
+                println!("{}", t!("menu.game_start"));
+                let mut game = Game::with_config(multi_mine_board_config());
+                game.run();
+                return;
+            }
+            "11" => {
+                println!("{}", t!("menu.game_start"));
+                let mut game = Game::with_config(negative_mine_board_config());
+                game.run();
+                return;
+            }
+            "12" => {
+                run_lives_mode();
+                return;
+            }
+            "13" => {
+                run_timed_mode();
+                return;
+            }
+            "14" => {
+                run_flags_mode();
+                return;
+            }
+            "15" => {
+                run_versus_mode();
+                return;
+            }
+            "16" => {
+                run_coop_mode();
+                return;
+            }
             _ => {
-                println!("{}", ui_text::INVALID_CHOICE);
+                println!("{}", t!("menu.invalid_choice"));
                 continue;
             }
         }
     };
 
-    println!("{}", ui_text::GAME_START);
+    println!("{}", t!("menu.game_start"));
 
     // 创建并运行游戏
     let mut game = Game::new(difficulty);
     game.run();
 }
+
+// 命令行中紧跟在 name 后面的值
+fn option_value<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    let index = args.iter().position(|arg| arg == name)?;
+    args.get(index + 1).map(String::as_str)
+}
+
+// 询问图文件路径，加载成功后启动图结构模式
+fn run_graph_mode() {
+    let Some(input) = read_line(t!("prompt.graph_path")) else {
+        println!("{}", t!("menu.input_error"));
+        return;
+    };
+    let path = match input.as_str() {
+        "" => graph::DEFAULT_FILE,
+        path => path,
+    };
+
+    match BoardGraph::load(path) {
+        Ok(board_graph) => {
+            println!("{}", t!("menu.game_start"));
+            let mine_count = graph_mine_count(&board_graph);
+            let mut game = GraphGame::new(board_graph, mine_count);
+            game.run();
+        }
+        Err(err) => println!("{}", t!("error.graph_load", path, err)),
+    }
+}
+
+// 读取一行输入，读取失败时返回 None
+fn read_line(prompt: &str) -> Option<String> {
+    print!("{}", prompt);
+    io::stdout().flush().unwrap();
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).ok()?;
+    Some(input.trim().to_string())
+}
+
+// 询问生命数后以简单难度开始生命模式
+fn run_lives_mode() {
+    let lives = loop {
+        let Some(input) = read_line(&ui_text::lives_prompt()) else {
+            println!("{}", t!("menu.input_error"));
+            continue;
+        };
+        if input.is_empty() {
+            break lives::DEFAULT_LIVES;
+        }
+        match input.parse::<u32>() {
+            Ok(count) if (1..=lives::MAX_LIVES).contains(&count) => break count,
+            _ => println!("{}", t!("error.invalid_lives", lives::MAX_LIVES)),
+        }
+    };
+
+    println!("{}", t!("menu.game_start"));
+    let mut game = Game::with_lives(difficulty_to_board_config(Difficulty::Easy), lives);
+    game.run();
+}
+
+// 选择倒计时或限时挑战，以随机种子开始
+fn run_timed_mode() {
+    let config = loop {
+        let Some(choice) = read_line(t!("prompt.timed_mode")) else {
+            println!("{}", t!("menu.input_error"));
+            continue;
+        };
+        match choice.as_str() {
+            "1" => break countdown_config(rand::random()),
+            "2" => break time_attack_config(rand::random()),
+            _ => println!("{}", t!("error.invalid_choice_2")),
+        }
+    };
+
+    println!("{}", t!("menu.game_start"));
+    let mut game = TimedGame::new(config);
+    game.run();
+}
+
+// 选择对手后开始双人对战
+fn run_flags_mode() {
+    let mut game = loop {
+        let Some(choice) = read_line(t!("prompt.flags_opponent")) else {
+            println!("{}", t!("menu.input_error"));
+            continue;
+        };
+        match choice.as_str() {
+            "1" => break FlagsGame::new(flags_board_config()),
+            "2" => break FlagsGame::against_computer(flags_board_config()),
+            _ => println!("{}", t!("error.invalid_choice_2")),
+        }
+    };
+
+    println!("{}", t!("menu.game_start"));
+    game.run();
+}
+
+// 创建房间时在后台线程运行服务器并以本机客户端加入；加入房间时连接指定地址
+fn run_versus_mode() {
+    let Some((address, name)) = setup_room(versus::DEFAULT_PORT, || {
+        let server = VersusServer::bind(
+            ("0.0.0.0", versus::DEFAULT_PORT),
+            versus_config(rand::random()),
+        )?;
+        thread::spawn(move || server.run());
+        Ok(())
+    }) else {
+        return;
+    };
+
+    let result = VersusClient::connect(address.as_str(), &name).and_then(|mut client| client.run());
+    if let Err(err) = result {
+        println!("{}", t!("error.online", address, err));
+    }
+}
+
+// 同联机对战，创建房间时先询问人数
+fn run_coop_mode() {
+    let Some((address, name)) = setup_room(coop::DEFAULT_PORT, || {
+        let players = read_coop_players();
+        let server = CoopServer::bind(
+            ("0.0.0.0", coop::DEFAULT_PORT),
+            coop_config(rand::random(), players),
+        )?;
+        thread::spawn(move || server.run());
+        Ok(())
+    }) else {
+        return;
+    };
+
+    let result = CoopClient::connect(address.as_str(), &name).and_then(|mut client| client.run());
+    if let Err(err) = result {
+        println!("{}", t!("error.online", address, err));
+    }
+}
+
+fn read_coop_players() -> usize {
+    loop {
+        let Some(input) = read_line(&ui_text::coop_players_prompt()) else {
+            println!("{}", t!("menu.input_error"));
+            continue;
+        };
+        if input.is_empty() {
+            return coop::DEFAULT_PLAYERS;
+        }
+        match input.parse::<usize>() {
+            Ok(count) if (1..=MAX_PLAYERS).contains(&count) => return count,
+            _ => println!("{}", t!("error.invalid_players", MAX_PLAYERS)),
+        }
+    }
+}
+
+// 选择创建或加入房间，返回要连接的地址和玩家名字；创建房间失败时返回 None
+fn setup_room(port: u16, host: impl FnOnce() -> io::Result<()>) -> Option<(String, String)> {
+    let hosting = loop {
+        let Some(choice) = read_line(t!("prompt.room_role")) else {
+            println!("{}", t!("menu.input_error"));
+            continue;
+        };
+        match choice.as_str() {
+            "1" => break true,
+            "2" => break false,
+            _ => println!("{}", t!("error.invalid_choice_2")),
+        }
+    };
+
+    let default_address = format!("127.0.0.1:{}", port);
+    let address = if hosting {
+        if let Err(err) = host() {
+            println!("{}", t!("error.host", port, err));
+            return None;
+        }
+        println!("{}", t!("info.room_created", port));
+        default_address
+    } else {
+        match read_line(&ui_text::address_prompt(port)) {
+            Some(address) if !address.is_empty() => address,
+            _ => default_address,
+        }
+    };
+
+    let name = match read_line(t!("prompt.player_name")) {
+        Some(name) if !name.is_empty() => name,
+        _ => t!("default.player_name").to_string(),
+    };
+    Some((address, name))
+}
+
+// 选择相邻规则后以简单难度的尺寸开始游戏
+fn run_neighbourhood_mode() {
+    let neighbourhood = loop {
+        let Some(choice) = read_line(t!("prompt.neighbourhood")) else {
+            println!("{}", t!("menu.input_error"));
+            continue;
+        };
+        match choice.as_str() {
+            "1" => break Neighbourhood::Orthogonal,
+            "2" => break Neighbourhood::Knight,
+            "3" => break Neighbourhood::Radius2,
+            "4" => {
+                let offsets = read_line(t!("prompt.custom_offsets"))
+                    .and_then(|text| CustomOffsets::parse(&text));
+                match offsets {
+                    Some(offsets) => break Neighbourhood::Custom(offsets),
+                    None => println!("{}", t!("error.custom_offsets", MAX_CUSTOM_NEIGHBOURS)),
+                }
+            }
+            _ => println!("{}", t!("error.invalid_choice_4")),
+        }
+    };
+
+    println!("{}", t!("menu.game_start"));
+    let config = difficulty_to_neighbourhood_board_config(Difficulty::Easy, neighbourhood);
+    let mut game = Game::with_config(config);
+    game.run();
+}
+
+/// 内置机器人比赛
+fn run_tournament(games: usize) {
+    println!("{}", t!("tournament.title", games));
+    let mut bots: Vec<Box<dyn Bot>> = vec![Box::new(SolverBot::new()), Box::new(RandomBot::new())];
+    let results = Tournament::new(games, tournament::BASE_SEED).run(&mut bots);
+    print_report(&results);
+}
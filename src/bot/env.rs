@@ -0,0 +1,350 @@
+//! 强化学习用的 gym 风格环境
+//!
+//! `reset(seed, config)` 开始新局，`step(action)` 返回观测、奖励、是否结束和附加信息。
+//!
+//! 动作是整数：`0..格子数` 翻开对应格子（行优先），开启插旗时 `格子数..2*格子数` 插旗或拔旗。
+//! 观测是形状固定为 `[PLANES, 高, 宽]` 的 one-hot 平面，每个格子恰好有一个平面为 1：
+//! 隐藏、旗子、数字 0-8、已翻开的地雷。多雷和负雷变体的数字超出 0-8 时归入最近的数字平面。
+//!
+//! `VecEnv` 把多个环境分到多个线程上同时推进，结束的环境自动用下一个种子重开。
+
+use crate::core::{Board, BoardConfig, ClickResult, Position, VisibleBoard, VisibleCell};
+use std::thread;
+
+/// 观测的平面数：隐藏、旗子、数字 0-8、地雷
+pub const PLANES: usize = 12;
+/// 旗子平面
+pub const FLAG_PLANE: usize = 1;
+/// 数字 0 所在的平面，数字 n 在 NUMBER_PLANE + n
+pub const NUMBER_PLANE: usize = 2;
+/// 已翻开的地雷平面
+pub const MINE_PLANE: usize = 11;
+
+// 每个线程至少推进的环境数，环境太少时分线程得不偿失
+const MIN_ENVS_PER_THREAD: usize = 16;
+
+/// 奖励设置
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rewards {
+    pub win: f32,
+    pub loss: f32,
+    /// 每新翻开一个格子的奖励
+    pub per_cell: f32,
+    /// 每一步的固定奖励，通常为负以鼓励尽快完成
+    pub step: f32,
+    /// 无效动作（翻开已翻开的格子等）的奖励
+    pub invalid: f32,
+    /// 插旗或拔旗的奖励
+    pub flag: f32,
+}
+
+impl Default for Rewards {
+    fn default() -> Self {
+        Rewards {
+            win: 1.0,
+            loss: -1.0,
+            per_cell: 0.0,
+            step: 0.0,
+            invalid: -0.1,
+            flag: 0.0,
+        }
+    }
+}
+
+/// 环境设置，默认只提供翻开动作，无效动作不结束本局
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EnvOptions {
+    pub rewards: Rewards,
+    /// 是否提供插旗动作
+    pub allow_flags: bool,
+    /// 无效动作是否直接结束本局
+    pub end_on_invalid: bool,
+}
+
+/// 固定形状的观测，按 `[平面, 行, 列]` 展开
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observation {
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<f32>,
+}
+
+impl Observation {
+    fn encode(view: &VisibleBoard) -> Self {
+        let size = view.config().board_size;
+        let cells = size.cell_count();
+        let mut data = vec![0.0; PLANES * cells];
+        for (index, &cell) in view.cells().iter().enumerate() {
+            data[plane_of(cell) * cells + index] = 1.0;
+        }
+        Observation {
+            width: size.width,
+            height: size.height,
+            data,
+        }
+    }
+
+    pub fn shape(&self) -> [usize; 3] {
+        [PLANES, self.height, self.width]
+    }
+
+    pub fn get(&self, plane: usize, pos: Position) -> f32 {
+        self.data[(plane * self.height + pos.row) * self.width + pos.col]
+    }
+}
+
+fn plane_of(cell: VisibleCell) -> usize {
+    match cell {
+        VisibleCell::Hidden => 0,
+        VisibleCell::Flagged => FLAG_PLANE,
+        VisibleCell::Number(n) => NUMBER_PLANE + n.clamp(0, 8) as usize,
+        VisibleCell::Mine => MINE_PLANE,
+    }
+}
+
+/// 一步的附加信息
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StepInfo {
+    /// 本步新翻开的格子数
+    pub revealed: usize,
+    pub invalid: bool,
+    pub won: bool,
+    /// 本局已走的步数
+    pub steps: usize,
+}
+
+/// 一步的结果
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    pub observation: Observation,
+    pub reward: f32,
+    pub done: bool,
+    pub info: StepInfo,
+}
+
+/// 单个扫雷环境
+pub struct MinesweeperEnv {
+    options: EnvOptions,
+    board: Board,
+    done: bool,
+    steps: usize,
+}
+
+impl MinesweeperEnv {
+    /// 创建环境并以给定种子和配置开局
+    pub fn new(options: EnvOptions, seed: u64, config: BoardConfig) -> Self {
+        MinesweeperEnv {
+            options,
+            board: Board::with_seed(config, seed),
+            done: false,
+            steps: 0,
+        }
+    }
+
+    pub fn options(&self) -> &EnvOptions {
+        &self.options
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// 动作总数
+    pub fn action_count(&self) -> usize {
+        let cells = self.board.get_board_config().board_size.cell_count();
+        if self.options.allow_flags {
+            cells * 2
+        } else {
+            cells
+        }
+    }
+
+    /// 开始新局，返回初始观测
+    pub fn reset(&mut self, seed: u64, config: BoardConfig) -> Observation {
+        self.board = Board::with_seed(config, seed);
+        self.done = false;
+        self.steps = 0;
+        self.observation()
+    }
+
+    pub fn observation(&self) -> Observation {
+        Observation::encode(&VisibleBoard::of(&self.board))
+    }
+
+    /// 当前可以执行的动作：翻开只对隐藏格子有效，插旗对隐藏和已插旗的格子有效；
+    /// 本局结束后全部无效
+    pub fn action_mask(&self) -> Vec<bool> {
+        let view = VisibleBoard::of(&self.board);
+        let reveal = view
+            .cells()
+            .iter()
+            .map(|&cell| !self.done && cell == VisibleCell::Hidden);
+        if !self.options.allow_flags {
+            return reveal.collect();
+        }
+        let flag = view
+            .cells()
+            .iter()
+            .map(|&cell| !self.done && matches!(cell, VisibleCell::Hidden | VisibleCell::Flagged));
+        reveal.chain(flag).collect()
+    }
+
+    /// 执行一个动作；本局结束后的动作按无效处理，不再改变棋盘
+    pub fn step(&mut self, action: usize) -> Step {
+        let rewards = self.options.rewards;
+        let size = self.board.get_board_config().board_size;
+        let cells = size.cell_count();
+        self.steps += 1;
+
+        let mut info = StepInfo {
+            steps: self.steps,
+            ..StepInfo::default()
+        };
+        let mut reward = rewards.step;
+
+        if self.done || action >= self.action_count() {
+            info.invalid = true;
+        } else {
+            let index = action % cells;
+            let pos = Position {
+                row: index / size.width,
+                col: index % size.width,
+            };
+            let before = self.board.get_revealed_count();
+            let result = if action < cells {
+                self.board.left_click(pos)
+            } else {
+                reward += rewards.flag;
+                self.board.right_click(pos)
+            };
+            info.revealed = self.board.get_revealed_count().saturating_sub(before);
+            reward += rewards.per_cell * info.revealed as f32;
+            match result {
+                ClickResult::Invalid => info.invalid = true,
+                ClickResult::GameOver => {
+                    reward += rewards.loss;
+                    self.done = true;
+                }
+                ClickResult::Victory => {
+                    reward += rewards.win;
+                    info.won = true;
+                    self.done = true;
+                }
+                ClickResult::Continue => {}
+            }
+        }
+
+        if info.invalid {
+            reward += rewards.invalid;
+            if self.options.end_on_invalid {
+                self.done = true;
+            }
+        }
+
+        Step {
+            observation: self.observation(),
+            reward,
+            done: self.done,
+            info,
+        }
+    }
+}
+
+/// 批量环境：所有环境使用同一配置，在多个线程上同时推进
+///
+/// 第 i 个环境依次使用种子 base_seed + i、base_seed + i + n、……（n 为环境数），
+/// 某局结束时自动重开，返回的观测是新局的初始观测，结束时的观测放在 `terminal` 中。
+pub struct VecEnv {
+    config: BoardConfig,
+    envs: Vec<MinesweeperEnv>,
+    next_seeds: Vec<u64>,
+}
+
+/// 批量环境中一个环境的一步
+#[derive(Clone, Debug, PartialEq)]
+pub struct VecStep {
+    pub step: Step,
+    /// 本局结束时的观测；自动重开后 `step.observation` 已是新局
+    pub terminal: Option<Observation>,
+}
+
+impl VecEnv {
+    pub fn new(count: usize, options: EnvOptions, base_seed: u64, config: BoardConfig) -> Self {
+        let envs = (0..count)
+            .map(|i| MinesweeperEnv::new(options, base_seed.wrapping_add(i as u64), config))
+            .collect();
+        let next_seeds = (0..count)
+            .map(|i| base_seed.wrapping_add((i + count) as u64))
+            .collect();
+        VecEnv {
+            config,
+            envs,
+            next_seeds,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.envs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.envs.is_empty()
+    }
+
+    pub fn envs(&self) -> &[MinesweeperEnv] {
+        &self.envs
+    }
+
+    pub fn observations(&self) -> Vec<Observation> {
+        self.envs.iter().map(MinesweeperEnv::observation).collect()
+    }
+
+    pub fn action_masks(&self) -> Vec<Vec<bool>> {
+        self.envs.iter().map(MinesweeperEnv::action_mask).collect()
+    }
+
+    /// 每个环境执行对应的动作，actions 的长度必须等于环境数
+    pub fn step(&mut self, actions: &[usize]) -> Vec<VecStep> {
+        assert_eq!(actions.len(), self.envs.len(), "动作数必须等于环境数");
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk = self.envs.len().div_ceil(threads).max(MIN_ENVS_PER_THREAD);
+        let stride = self.envs.len() as u64;
+        let config = self.config;
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .envs
+                .chunks_mut(chunk)
+                .zip(self.next_seeds.chunks_mut(chunk))
+                .zip(actions.chunks(chunk))
+                .map(|((envs, seeds), actions)| {
+                    scope.spawn(move || {
+                        envs.iter_mut()
+                            .zip(seeds.iter_mut())
+                            .zip(actions)
+                            .map(|((env, seed), &action)| {
+                                let mut step = env.step(action);
+                                let mut terminal = None;
+                                if step.done {
+                                    let observation = env.reset(*seed, config);
+                                    *seed = seed.wrapping_add(stride);
+                                    terminal =
+                                        Some(std::mem::replace(&mut step.observation, observation));
+                                }
+                                VecStep { step, terminal }
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("环境线程异常退出"))
+                .collect()
+        })
+    }
+}
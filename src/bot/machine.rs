@@ -0,0 +1,292 @@
+//! JSON-lines 机器协议
+//!
+//! 每行输入是一条 JSON 命令，每条命令对应一行 JSON 响应，供任意语言编写的程序驱动引擎。
+//!
+//! 命令（`cmd` 字段）：
+//! - `new`：开始新局，可指定 `difficulty`（easy / medium / hard），或 `width`、`height`、`mines`；
+//!   `seed` 可选，相同种子和相同的首次点击得到相同的棋盘
+//! - `reveal` / `flag` / `chord`：对 `row`、`col` 翻开、插旗/拔旗或双击数字
+//! - `state`：只返回当前局面
+//!
+//! 响应中 `ok` 表示命令是否成功；失败时 `error` 为固定的错误代码，`message` 为当前语言的说明。
+//! 有对局时响应总是带上 `status`（playing / won / lost）、计数和 `board`：
+//! 每行一个字符串，`#` 隐藏、`F` 旗子、`*` 地雷、`0`-`8` 数字。
+//! 改动棋盘的命令还带有 `changed`，按翻开（展开）的顺序列出可见内容发生变化的格子。
+//! 命令中的 `id` 字段会原样出现在响应里，便于对应请求和响应。
+
+use crate::bot::json::Json;
+use crate::config::{Difficulty, difficulty_to_board_config};
+use crate::core::{Board, BoardConfig, BoardSize, ClickResult, Position, VisibleCell};
+use crate::net::encode_view;
+use crate::net::protocol::view_char;
+use crate::t;
+use std::io::{self, BufRead, Write};
+
+/// 自定义棋盘的最大边长
+pub const MAX_SIDE: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameStatus {
+    Playing,
+    Won,
+    Lost,
+}
+
+impl GameStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            GameStatus::Playing => "playing",
+            GameStatus::Won => "won",
+            GameStatus::Lost => "lost",
+        }
+    }
+}
+
+// 命令失败的原因：错误代码和说明
+struct Failure {
+    code: &'static str,
+    message: String,
+}
+
+fn fail(code: &'static str, message: impl Into<String>) -> Failure {
+    Failure {
+        code,
+        message: message.into(),
+    }
+}
+
+/// 一个机器协议会话，同一时间最多进行一局
+#[derive(Default)]
+pub struct BotSession {
+    game: Option<(Board, GameStatus)>,
+}
+
+impl BotSession {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 处理一行命令，返回响应
+    pub fn handle_line(&mut self, line: &str) -> Json {
+        let request = Json::parse(line);
+        let id = request
+            .as_ref()
+            .ok()
+            .and_then(|request| request.get("id"))
+            .cloned();
+
+        let result = match &request {
+            Ok(request) => self.execute(request),
+            Err(err) => Err(fail("invalid_json", err.to_string())),
+        };
+
+        let mut fields = Vec::new();
+        if let Some(id) = id {
+            fields.push(("id", id));
+        }
+        match result {
+            Ok(changed) => {
+                fields.push(("ok", Json::Bool(true)));
+                fields.extend(self.state_fields());
+                if let Some(changed) = changed {
+                    fields.push(("changed", Json::Array(changed)));
+                }
+            }
+            Err(failure) => {
+                fields.push(("ok", Json::Bool(false)));
+                fields.push(("error", failure.code.into()));
+                fields.push(("message", failure.message.into()));
+                fields.extend(self.state_fields());
+            }
+        }
+        Json::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+
+    /// 逐行读取命令并写出响应，直到输入结束
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            writeln!(output, "{}", self.handle_line(&line))?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+
+    // 执行命令；改动棋盘的命令返回变化的格子
+    fn execute(&mut self, request: &Json) -> Result<Option<Vec<Json>>, Failure> {
+        let command = request
+            .get("cmd")
+            .and_then(Json::as_str)
+            .ok_or_else(|| fail("missing_field", t!("bot.missing_field", "cmd")))?;
+
+        match command {
+            "new" => {
+                let config = new_game_config(request)?;
+                let board = match request.get("seed") {
+                    Some(seed) => Board::with_seed(
+                        config,
+                        seed.as_u64()
+                            .ok_or_else(|| fail("invalid_field", t!("bot.not_integer", "seed")))?,
+                    ),
+                    None => Board::new(config),
+                };
+                self.game = Some((board, GameStatus::Playing));
+                Ok(None)
+            }
+            "state" => Ok(None),
+            "reveal" | "flag" | "chord" => {
+                let (board, status) = self
+                    .game
+                    .as_mut()
+                    .ok_or_else(|| fail("no_game", t!("bot.no_game")))?;
+                if *status != GameStatus::Playing {
+                    return Err(fail("game_over", t!("bot.game_over")));
+                }
+                let pos = position(request, board.get_board_config().board_size)?;
+
+                let details = match command {
+                    "reveal" => board.left_click_detailed(pos),
+                    "flag" => board.right_click_detailed(pos),
+                    _ => board.chord_detailed(pos),
+                };
+                match details.result {
+                    ClickResult::Invalid => {
+                        return Err(fail("invalid_move", invalid_move_message(command)));
+                    }
+                    ClickResult::GameOver => *status = GameStatus::Lost,
+                    ClickResult::Victory => *status = GameStatus::Won,
+                    ClickResult::Continue => {}
+                }
+                let changed = details
+                    .revealed
+                    .iter()
+                    .map(|&(pos, _)| pos)
+                    .chain(details.flags.iter().map(|change| change.pos));
+                Ok(Some(changed_cells(changed, board)))
+            }
+            other => Err(fail("unknown_command", t!("bot.unknown_command", other))),
+        }
+    }
+
+    fn state_fields(&self) -> Vec<(&'static str, Json)> {
+        let Some((board, status)) = &self.game else {
+            return Vec::new();
+        };
+        let config = board.get_board_config();
+        let size = config.board_size;
+        let view = encode_view(board);
+        let rows: Vec<Json> = view
+            .as_bytes()
+            .chunks(size.width)
+            .map(|row| Json::String(String::from_utf8_lossy(row).into_owned()))
+            .collect();
+        let flags = view.chars().filter(|&ch| ch == 'F').count();
+
+        vec![
+            ("status", status.label().into()),
+            ("width", size.width.into()),
+            ("height", size.height.into()),
+            ("mines", config.mine_count.into()),
+            ("flags", flags.into()),
+            ("revealed", board.get_revealed_count().into()),
+            ("board", Json::Array(rows)),
+        ]
+    }
+}
+
+fn invalid_move_message(command: &str) -> &'static str {
+    match command {
+        "reveal" => t!("bot.invalid_reveal"),
+        "flag" => t!("bot.invalid_flag"),
+        _ => t!("bot.invalid_chord"),
+    }
+}
+
+fn field(request: &Json, name: &str) -> Result<usize, Failure> {
+    let value = request
+        .get(name)
+        .ok_or_else(|| fail("missing_field", t!("bot.missing_field", name)))?;
+    value
+        .as_u64()
+        .and_then(|value| usize::try_from(value).ok())
+        .ok_or_else(|| fail("invalid_field", t!("bot.not_integer", name)))
+}
+
+fn position(request: &Json, size: BoardSize) -> Result<Position, Failure> {
+    let pos = Position {
+        row: field(request, "row")?,
+        col: field(request, "col")?,
+    };
+    if pos.row >= size.height || pos.col >= size.width {
+        return Err(fail(
+            "out_of_bounds",
+            t!("bot.out_of_bounds", size.height - 1, size.width - 1),
+        ));
+    }
+    Ok(pos)
+}
+
+// 按难度或自定义尺寸生成经典规则的棋盘配置
+fn new_game_config(request: &Json) -> Result<BoardConfig, Failure> {
+    if let Some(difficulty) = request.get("difficulty") {
+        let difficulty = match difficulty.as_str() {
+            Some("easy") => Difficulty::Easy,
+            Some("medium") => Difficulty::Medium,
+            Some("hard") => Difficulty::Hard,
+            _ => {
+                return Err(fail("invalid_field", t!("bot.bad_difficulty")));
+            }
+        };
+        return Ok(difficulty_to_board_config(difficulty));
+    }
+    if request.get("width").is_none() {
+        return Ok(difficulty_to_board_config(Difficulty::Easy));
+    }
+
+    let width = field(request, "width")?;
+    let height = field(request, "height")?;
+    let mines = field(request, "mines")?;
+    if !(1..=MAX_SIDE).contains(&width) || !(1..=MAX_SIDE).contains(&height) {
+        return Err(fail("invalid_config", t!("bot.bad_size", MAX_SIDE)));
+    }
+    // 首次点击的格子及其周围不布雷
+    if mines > (width * height).saturating_sub(9) {
+        return Err(fail("invalid_config", t!("bot.too_many_mines")));
+    }
+    Ok(BoardConfig {
+        board_size: BoardSize { width, height },
+        mine_count: mines,
+        ..difficulty_to_board_config(Difficulty::Easy)
+    })
+}
+
+// 列出变化的格子及其当前的可见内容
+fn changed_cells(changed: impl Iterator<Item = Position>, board: &Board) -> Vec<Json> {
+    changed
+        .map(|pos| {
+            Json::object([
+                ("row", pos.row.into()),
+                ("col", pos.col.into()),
+                (
+                    "cell",
+                    view_char(VisibleCell::of(board, pos)).to_string().into(),
+                ),
+            ])
+        })
+        .collect()
+}
+
+/// 以标准输入输出运行机器协议
+pub fn run_stdio() -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    BotSession::new().run(stdin.lock(), stdout.lock())
+}
@@ -0,0 +1,21 @@
+//! 供程序驱动引擎的接口
+//!
+//! - env: 强化学习用的 gym 风格环境
+//! - json: 机器协议使用的最小 JSON 实现
+//! - machine: 标准输入输出上的 JSON-lines 协议
+//! - player: 机器人接口和内置机器人
+//! - tournament: 无界面的批量对局和比较
+
+pub mod env;
+pub mod json;
+pub mod machine;
+pub mod player;
+pub mod tournament;
+
+pub use env::{
+    EnvOptions, MinesweeperEnv, Observation, PLANES, Rewards, Step, StepInfo, VecEnv, VecStep,
+};
+pub use json::{Json, JsonError};
+pub use machine::{BotSession, GameStatus, run_stdio};
+pub use player::{Bot, BotAction, RandomBot, SolverBot};
+pub use tournament::{BotStats, GameRecord, Tournament, play_game, print_report, three_bv};
@@ -0,0 +1,93 @@
+//! 机器人接口和内置机器人
+//!
+//! 机器人只拿到 VisibleBoard（玩家可见的信息），每次返回一个操作。
+
+use crate::core::{Position, VisibleBoard, analyze_visible};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+/// 机器人的一次操作
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BotAction {
+    Reveal(Position),
+    Flag(Position),
+    /// 双击已翻开的数字
+    Chord(Position),
+}
+
+pub trait Bot {
+    /// 在报告中显示的名字
+    fn name(&self) -> &str;
+
+    /// 每局开始前调用，seed 为该局的种子，需要随机性的机器人可以用它保证可复现
+    fn reset(&mut self, _seed: u64) {}
+
+    /// 根据可见的棋盘选择下一步操作
+    fn next_action(&mut self, view: &VisibleBoard) -> BotAction;
+}
+
+/// 依据推理的机器人：有必然安全的格子就翻开，否则翻开最不可能是地雷的格子
+#[derive(Default)]
+pub struct SolverBot;
+
+impl SolverBot {
+    pub fn new() -> Self {
+        SolverBot
+    }
+}
+
+impl Bot for SolverBot {
+    fn name(&self) -> &str {
+        "solver"
+    }
+
+    fn next_action(&mut self, view: &VisibleBoard) -> BotAction {
+        let analysis = analyze_visible(view);
+        let pos = analysis
+            .known_safe()
+            .first()
+            .copied()
+            .or_else(|| analysis.safest())
+            .unwrap_or(Position { row: 0, col: 0 });
+        BotAction::Reveal(pos)
+    }
+}
+
+/// 随机翻开隐藏格子的机器人，作为比较的基准
+pub struct RandomBot {
+    rng: SmallRng,
+}
+
+impl RandomBot {
+    pub fn new() -> Self {
+        RandomBot {
+            rng: SmallRng::seed_from_u64(0),
+        }
+    }
+}
+
+impl Default for RandomBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Bot for RandomBot {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn reset(&mut self, seed: u64) {
+        self.rng = SmallRng::seed_from_u64(seed);
+    }
+
+    fn next_action(&mut self, view: &VisibleBoard) -> BotAction {
+        let hidden: Vec<Position> = view.hidden().collect();
+        let pos = if hidden.is_empty() {
+            Position { row: 0, col: 0 }
+        } else {
+            hidden[self.rng.random_range(0..hidden.len())]
+        };
+        BotAction::Reveal(pos)
+    }
+}
@@ -0,0 +1,243 @@
+//! 无界面的机器人对局和比较
+//!
+//! 每局使用固定种子，并由裁判先点开棋盘中心，因此同一种子对所有机器人是同一块棋盘，
+//! 多个机器人可以在相同的种子集合上直接比较。
+//!
+//! 没有界面也就没有真实用时，3BV/s 按每次操作 1 秒折算：
+//! 获胜局的 3BV 总和除以这些局的操作总数（含裁判代为点击的第一下）。
+//! 翻开一个推理不能确定安全的隐藏格子记为一次猜测。
+
+use crate::bot::player::{Bot, BotAction};
+use crate::config::{Difficulty, difficulty_to_board_config};
+use crate::core::{
+    Board, BoardConfig, CellContent, ClickResult, Position, VisibleBoard, analyze_visible,
+};
+use crate::t;
+
+/// 单局结果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameRecord {
+    pub won: bool,
+    /// 开局后棋盘的 3BV
+    pub bbbv: usize,
+    /// 操作次数，含开局的第一下
+    pub actions: usize,
+    pub guesses: usize,
+    /// 无效的操作（例如翻开已翻开的格子）
+    pub invalid_actions: usize,
+}
+
+/// 一个机器人在一种难度下的汇总
+#[derive(Clone, Debug, PartialEq)]
+pub struct BotStats {
+    pub bot: String,
+    pub difficulty: Difficulty,
+    pub games: usize,
+    pub wins: usize,
+    pub guesses: usize,
+    pub invalid_actions: usize,
+    won_bbbv: usize,
+    won_actions: usize,
+}
+
+impl BotStats {
+    fn new(bot: &str, difficulty: Difficulty) -> Self {
+        BotStats {
+            bot: bot.to_string(),
+            difficulty,
+            games: 0,
+            wins: 0,
+            guesses: 0,
+            invalid_actions: 0,
+            won_bbbv: 0,
+            won_actions: 0,
+        }
+    }
+
+    fn add(&mut self, record: &GameRecord) {
+        self.games += 1;
+        self.guesses += record.guesses;
+        self.invalid_actions += record.invalid_actions;
+        if record.won {
+            self.wins += 1;
+            self.won_bbbv += record.bbbv;
+            self.won_actions += record.actions;
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        ratio(self.wins, self.games)
+    }
+
+    /// 获胜局按每次操作 1 秒折算的 3BV/s
+    pub fn bbbv_per_second(&self) -> f64 {
+        ratio(self.won_bbbv, self.won_actions)
+    }
+
+    pub fn average_guesses(&self) -> f64 {
+        ratio(self.guesses, self.games)
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// 比赛设置：每种难度下第 n 局使用种子 base_seed + n
+#[derive(Clone, Debug)]
+pub struct Tournament {
+    pub difficulties: Vec<Difficulty>,
+    pub games: usize,
+    pub base_seed: u64,
+}
+
+impl Tournament {
+    /// 在所有难度下各进行 games 局
+    pub fn new(games: usize, base_seed: u64) -> Self {
+        Tournament {
+            difficulties: Difficulty::ALL.to_vec(),
+            games,
+            base_seed,
+        }
+    }
+
+    /// 让每个机器人在相同的种子集合上对局，按难度、再按机器人的顺序返回汇总
+    pub fn run(&self, bots: &mut [Box<dyn Bot>]) -> Vec<BotStats> {
+        let mut results = Vec::new();
+        for &difficulty in &self.difficulties {
+            let config = difficulty_to_board_config(difficulty);
+            for bot in bots.iter_mut() {
+                let mut stats = BotStats::new(bot.name(), difficulty);
+                for game in 0..self.games {
+                    let seed = self.base_seed.wrapping_add(game as u64);
+                    stats.add(&play_game(bot.as_mut(), config, seed));
+                }
+                results.push(stats);
+            }
+        }
+        results
+    }
+}
+
+/// 进行一局：裁判先点开中心，之后由机器人操作，直到分出胜负或操作次数达到上限
+pub fn play_game(bot: &mut dyn Bot, config: BoardConfig, seed: u64) -> GameRecord {
+    let size = config.board_size;
+    let mut board = Board::with_seed(config, seed);
+    let start = Position {
+        row: size.height / 2,
+        col: size.width / 2,
+    };
+    let mut result = board.left_click(start);
+    let mut record = GameRecord {
+        won: false,
+        bbbv: three_bv(&board),
+        actions: 1,
+        guesses: 0,
+        invalid_actions: 0,
+    };
+
+    // 每个格子最多翻开、插旗各一次，超过上限说明机器人在原地打转
+    let max_actions = size.cell_count() * 2 + 1;
+    bot.reset(seed);
+    while matches!(result, ClickResult::Continue | ClickResult::Invalid)
+        && record.actions < max_actions
+    {
+        let view = VisibleBoard::of(&board);
+        let action = bot.next_action(&view);
+        record.actions += 1;
+        result = match action {
+            BotAction::Reveal(pos) => {
+                let hidden = view.hidden().any(|hidden| hidden == pos);
+                if hidden && !analyze_visible(&view).known_safe().contains(&pos) {
+                    record.guesses += 1;
+                }
+                board.left_click(pos)
+            }
+            BotAction::Flag(pos) => board.right_click(pos),
+            BotAction::Chord(pos) => board.chord(pos),
+        };
+        if matches!(result, ClickResult::Invalid) {
+            record.invalid_actions += 1;
+        }
+    }
+
+    record.won = matches!(result, ClickResult::Victory);
+    record
+}
+
+/// 棋盘的 3BV：不借助展开时清空棋盘所需的最少点击数，
+/// 即空白区域（数字为 0 的连通块）的个数加上不与空白相邻的数字格子个数。
+/// 需要地雷已经布好
+pub fn three_bv(board: &Board) -> usize {
+    let config = board.get_board_config();
+    let width = config.board_size.width;
+    let count = config.board_size.cell_count();
+    let to_pos = |index: usize| Position {
+        row: index / width,
+        col: index % width,
+    };
+    let spoiler = board.spoiler();
+    let is_zero = |pos: Position| matches!(spoiler.content(pos), CellContent::Number(0));
+
+    let mut counted = vec![false; count];
+    let mut clicks = 0;
+
+    // 每个空白连通块点一下，连同它边上的数字一起翻开
+    for index in 0..count {
+        if counted[index] || !is_zero(to_pos(index)) {
+            continue;
+        }
+        clicks += 1;
+        counted[index] = true;
+        let mut stack = vec![index];
+        while let Some(index) = stack.pop() {
+            for adj in config.neighbours(to_pos(index), true) {
+                let adj_index = adj.row * width + adj.col;
+                if counted[adj_index] {
+                    continue;
+                }
+                counted[adj_index] = true;
+                if is_zero(adj) {
+                    stack.push(adj_index);
+                }
+            }
+        }
+    }
+
+    // 剩下的数字格子各需要一下
+    clicks
+        + (0..count)
+            .filter(|&index| {
+                !counted[index] && matches!(spoiler.content(to_pos(index)), CellContent::Number(_))
+            })
+            .count()
+}
+
+/// 打印比较表，按难度分组
+pub fn print_report(results: &[BotStats]) {
+    println!(
+        "{:<6} {:<10} {:>6} {:>8} {:>10} {:>10}",
+        t!("tournament.difficulty"),
+        t!("tournament.bot"),
+        t!("tournament.games"),
+        t!("tournament.win_rate"),
+        "3BV/s*",
+        t!("tournament.guesses")
+    );
+    for stats in results {
+        println!(
+            "{:<6} {:<10} {:>6} {:>7.1}% {:>10.3} {:>10.2}",
+            stats.difficulty.label(),
+            stats.bot,
+            stats.games,
+            stats.win_rate() * 100.0,
+            stats.bbbv_per_second(),
+            stats.average_guesses()
+        );
+    }
+    println!("{}", t!("tournament.footnote"));
+}
@@ -38,72 +38,290 @@ pub mod hard {
     pub const TOTAL_CELLS: usize = WIDTH * HEIGHT; // 480
 }
 
-/// 游戏界面文本常量
-pub mod ui_text {
-    use super::*;
+/// 三维模式配置
+pub mod cube {
+    /// 每层宽度
+    pub const WIDTH: usize = 5;
+    /// 每层高度
+    pub const HEIGHT: usize = 5;
+    /// 层数
+    pub const DEPTH: usize = 5;
+    /// 地雷数量
+    pub const MINES: usize = 12;
+    /// 总格子数
+    pub const TOTAL_CELLS: usize = WIDTH * HEIGHT * DEPTH; // 125
+}
+
+/// 无限模式配置
+pub mod infinite {
+    /// 区块边长（每个区块 CHUNK_SIZE x CHUNK_SIZE 个格子）
+    pub const CHUNK_SIZE: usize = 16;
+    /// 默认地雷密度
+    pub const DEFAULT_DENSITY: f64 = 0.16;
+    /// 视口宽度
+    pub const VIEWPORT_WIDTH: usize = 20;
+    /// 视口高度
+    pub const VIEWPORT_HEIGHT: usize = 12;
+    /// 单次点击最多自动展开的格子数
+    pub const MAX_FLOOD_FILL_CELLS: usize = 100_000;
+    /// 世界坐标的绝对值上限，超出的坐标不能点击，防止坐标运算溢出
+    pub const MAX_COORDINATE: i64 = 1_000_000_000_000;
+}
+
+/// 图结构模式配置
+pub mod graph {
+    /// 默认加载的图文件
+    pub const DEFAULT_FILE: &str = "graphs/dodecahedron.graph";
+    /// 文件未指定地雷数时使用的地雷密度
+    pub const DEFAULT_DENSITY: f64 = 0.15;
+    /// 显示坐标的最大行号和列号，网格按两位的行列号打印
+    pub const MAX_DISPLAY_COORDINATE: usize = 99;
+}
+
+/// 多雷格子模式配置（使用中等难度的棋盘尺寸）
+pub mod multi_mine {
+    /// 每个格子最多的地雷数
+    pub const MINES_PER_CELL: u8 = 3;
+    /// 地雷总数
+    pub const MINES: usize = 60;
+}
 
-    /// 游戏标题
-    pub const GAME_TITLE: &str = "🎮 扫雷游戏启动！";
+/// 负雷模式配置（使用中等难度的棋盘尺寸）
+pub mod negative_mine {
+    /// 地雷总数（含负雷）
+    pub const MINES: usize = 40;
+    /// 其中负雷的数量
+    pub const NEGATIVE_MINES: usize = 12;
+}
+
+/// 生命模式配置（使用简单难度的棋盘）
+pub mod lives {
+    /// 默认生命数
+    pub const DEFAULT_LIVES: u32 = 3;
+    /// 可选择的最多生命数
+    pub const MAX_LIVES: u32 = 99;
+}
 
-    /// 难度选择提示
-    pub const DIFFICULTY_PROMPT: &str = "请选择难度：";
+/// 计时模式配置
+pub mod timed {
+    /// 倒计时模式的初始时间（秒，中等难度棋盘）
+    pub const COUNTDOWN_SECONDS: u64 = 120;
+    /// 倒计时模式每展开一片空白区域奖励的时间（秒）
+    pub const BONUS_SECONDS_PER_OPENING: u64 = 5;
+    /// 限时挑战的总时间（秒，连续的简单难度棋盘）
+    pub const TIME_ATTACK_SECONDS: u64 = 180;
+    /// 成绩文件
+    pub const RECORDS_FILE: &str = "timed_records.txt";
+}
 
-    /// 演示模式描述
-    pub const DEMO_DESC: &str = "4. 演示模式 (查看核心功能演示)";
+/// 双人对战（Minesweeper Flags）配置，地雷数为奇数以避免平局
+pub mod flags {
+    /// 棋盘宽度
+    pub const WIDTH: usize = 16;
+    /// 棋盘高度
+    pub const HEIGHT: usize = 16;
+    /// 地雷数量
+    pub const MINES: usize = 51;
+}
 
-    /// 输入提示
-    pub const INPUT_PROMPT: &str = "请输入选择 (1-4): ";
+/// 联机对战配置（中等难度棋盘）
+pub mod versus {
+    /// 默认端口
+    pub const DEFAULT_PORT: u16 = 7878;
+    /// 每位玩家的生命数
+    pub const LIVES: u32 = 3;
+}
 
-    /// 输入错误提示
-    pub const INPUT_ERROR: &str = "❌ 输入错误，请重试";
+/// 合作模式配置（中等难度棋盘）
+pub mod coop {
+    /// 默认端口
+    pub const DEFAULT_PORT: u16 = 7879;
+    /// 默认玩家人数
+    pub const DEFAULT_PLAYERS: usize = 2;
+}
 
-    /// 无效选择提示
-    pub const INVALID_CHOICE: &str = "❌ 无效选择，请输入 1-4";
+/// 联机通用配置（对战和合作模式共用）
+pub mod network {
+    /// 客户端一行消息的最大字节数（含换行），超出即断开连接
+    pub const MAX_LINE_BYTES: usize = 1024;
+    /// 玩家名字的最大字符数，超出部分截掉
+    pub const MAX_NAME_CHARS: usize = 32;
+}
 
-    /// 游戏启动提示
-    pub const GAME_START: &str = "\n🚀 启动游戏...\n";
+/// 机器人比赛配置
+pub mod tournament {
+    /// 每种难度的默认局数
+    pub const DEFAULT_GAMES: usize = 1000;
+    /// 第一局的种子，之后每局加一
+    pub const BASE_SEED: u64 = 0;
+}
 
-    /// 演示模式启动提示
-    pub const DEMO_START: &str = "\n🔍 演示模式启动！";
+/// 界面设置
+pub mod ui {
+    /// 设置文件，位于当前目录，每行一个 `键 = 值`
+    pub const SETTINGS_FILE: &str = "minesweeper.conf";
+}
 
-    /// 演示完成提示
-    pub const DEMO_COMPLETE: &str = "\n✅ 核心功能演示完成！";
+/// 大棋盘的视口和小地图
+pub mod viewport {
+    /// 无法从 `COLUMNS` 获取终端宽度时视口的宽度（格子数），足以完整显示困难难度
+    pub const DEFAULT_WIDTH: usize = 60;
+    /// 无法从 `LINES` 获取终端高度时视口的高度（格子数）
+    pub const DEFAULT_HEIGHT: usize = 30;
+    /// 视口最小的宽度和高度
+    pub const MIN_SIZE: usize = 5;
+    /// 终端中留给行号和边框的列数
+    pub const RESERVED_COLUMNS: usize = 8;
+    /// 终端中留给表头、图例、状态和输入的行数
+    pub const RESERVED_LINES: usize = 14;
+    /// 小地图最多的列数
+    pub const MINIMAP_WIDTH: usize = 40;
+    /// 小地图最多的行数
+    pub const MINIMAP_HEIGHT: usize = 12;
+}
 
-    /// 游戏就绪提示
-    pub const GAME_READY: &str = "🚀 游戏逻辑已就绪，可以接入GUI界面了！";
+/// 图片导出
+pub mod export {
+    /// 默认每个格子的边长（像素）
+    pub const CELL_SIZE: u32 = 24;
+    /// 每个格子最小的边长（像素），再小数字就无法辨认
+    pub const MIN_CELL_SIZE: u32 = 12;
+}
 
-    /// 测试提示
-    pub const TEST_HINT: &str = "📋 运行 'cargo test' 查看详细测试";
+/// 带有配置数值的界面文本，文本本身来自当前语言的消息表
+pub mod ui_text {
+    use super::*;
+    use crate::t;
 
     /// 动态生成简单难度描述
     pub fn easy_desc() -> String {
-        format!(
-            "1. 简单 ({}x{}, {}个地雷)",
-            easy::WIDTH,
-            easy::HEIGHT,
-            easy::MINES
-        )
+        t!("menu.easy", easy::WIDTH, easy::HEIGHT, easy::MINES)
     }
 
     /// 动态生成中等难度描述
     pub fn medium_desc() -> String {
-        format!(
-            "2. 中等 ({}x{}, {}个地雷)",
+        t!("menu.medium", medium::WIDTH, medium::HEIGHT, medium::MINES)
+    }
+
+    /// 动态生成困难难度描述
+    pub fn hard_desc() -> String {
+        t!("menu.hard", hard::WIDTH, hard::HEIGHT, hard::MINES)
+    }
+
+    /// 动态生成六边形模式描述
+    pub fn hex_desc() -> String {
+        t!("menu.hex", easy::WIDTH, easy::HEIGHT, easy::MINES)
+    }
+
+    /// 动态生成三维模式描述
+    pub fn cube_desc() -> String {
+        t!(
+            "menu.cube",
+            cube::WIDTH,
+            cube::HEIGHT,
+            cube::DEPTH,
+            cube::MINES
+        )
+    }
+
+    /// 动态生成无限模式描述
+    pub fn infinite_desc() -> String {
+        t!(
+            "menu.infinite",
+            format!("{:.0}", infinite::DEFAULT_DENSITY * 100.0)
+        )
+    }
+
+    /// 动态生成图结构模式描述
+    pub fn graph_desc() -> String {
+        t!("menu.graph", graph::DEFAULT_FILE)
+    }
+
+    /// 动态生成多雷格子模式描述
+    pub fn multi_mine_desc() -> String {
+        t!(
+            "menu.multi_mine",
             medium::WIDTH,
             medium::HEIGHT,
-            medium::MINES
+            multi_mine::MINES,
+            multi_mine::MINES_PER_CELL
         )
     }
 
-    /// 动态生成困难难度描述
-    pub fn hard_desc() -> String {
-        format!(
-            "3. 困难 ({}x{}, {}个地雷)",
-            hard::WIDTH,
-            hard::HEIGHT,
-            hard::MINES
+    /// 动态生成负雷模式描述
+    pub fn negative_mine_desc() -> String {
+        t!(
+            "menu.negative_mine",
+            medium::WIDTH,
+            medium::HEIGHT,
+            negative_mine::MINES,
+            negative_mine::NEGATIVE_MINES
+        )
+    }
+
+    /// 动态生成生命模式描述
+    pub fn lives_desc() -> String {
+        t!(
+            "menu.lives",
+            easy::WIDTH,
+            easy::HEIGHT,
+            easy::MINES,
+            lives::DEFAULT_LIVES
+        )
+    }
+
+    /// 动态生成生命数输入提示
+    pub fn lives_prompt() -> String {
+        t!("prompt.lives", lives::MAX_LIVES, lives::DEFAULT_LIVES)
+    }
+
+    /// 动态生成计时模式描述
+    pub fn timed_desc() -> String {
+        t!(
+            "menu.timed",
+            timed::COUNTDOWN_SECONDS,
+            timed::TIME_ATTACK_SECONDS
+        )
+    }
+
+    /// 动态生成双人对战描述
+    pub fn flags_desc() -> String {
+        t!(
+            "menu.flags",
+            flags::WIDTH,
+            flags::HEIGHT,
+            flags::MINES,
+            flags::MINES / 2 + 1
+        )
+    }
+
+    /// 动态生成联机对战描述
+    pub fn versus_desc() -> String {
+        t!("menu.versus", versus::LIVES, versus::DEFAULT_PORT)
+    }
+
+    /// 动态生成合作模式描述
+    pub fn coop_desc() -> String {
+        t!(
+            "menu.coop",
+            crate::core::coop::MAX_PLAYERS,
+            coop::DEFAULT_PORT
+        )
+    }
+
+    /// 合作模式人数提示
+    pub fn coop_players_prompt() -> String {
+        t!(
+            "prompt.coop_players",
+            crate::core::coop::MAX_PLAYERS,
+            coop::DEFAULT_PLAYERS
         )
     }
+
+    /// 服务器地址提示
+    pub fn address_prompt(port: u16) -> String {
+        t!("prompt.address", port)
+    }
 }
 
 /// 测试用常量
@@ -128,9 +346,18 @@ pub mod test {
 
 /// 游戏机制常量
 pub mod game {
-    /// 最大相邻地雷数（一个格子周围最多8个地雷）
+    /// 默认 8 邻域的最大相邻地雷数；其他相邻规则见 BoardConfig::max_adjacent_mines
     pub const MAX_ADJACENT_MINES: u8 = 8;
 
+    /// 自定义相邻规则最多的偏移数（对应 7x7 方块去掉中心）
+    pub const MAX_CUSTOM_NEIGHBOURS: usize = 48;
+
+    /// 每个格子最多的地雷数上限（48 个邻居 x 5 颗地雷仍不超过 u8）
+    pub const MAX_MINES_PER_CELL: u8 = 5;
+
+    /// 三维棋盘的最大相邻地雷数（3x3x3 立方体去掉中心）
+    pub const MAX_ADJACENT_MINES_3D: u8 = 26;
+
     /// 空白格子的数字值
     pub const EMPTY_CELL_VALUE: u8 = 0;
 }
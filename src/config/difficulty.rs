@@ -1,5 +1,12 @@
-use crate::config::{easy, hard, medium};
-use crate::core::{BoardConfig, BoardSize};
+use crate::config::{
+    cube, easy, flags, graph, hard, infinite, medium, multi_mine, negative_mine, timed, versus,
+};
+use crate::core::{
+    BoardConfig, BoardConfig3D, BoardGraph, BoardShape, BoardSize, BoardSize3D,
+    InfiniteBoardConfig, Neighbourhood, TimedConfig, TimedMode,
+};
+use crate::net::{CoopConfig, VersusConfig};
+use std::time::Duration;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Difficulty {
@@ -8,6 +15,18 @@ pub enum Difficulty {
     Hard,
 }
 
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => crate::i18n::text("difficulty.easy"),
+            Difficulty::Medium => crate::i18n::text("difficulty.medium"),
+            Difficulty::Hard => crate::i18n::text("difficulty.hard"),
+        }
+    }
+}
+
 pub fn difficulty_to_board_config(difficulty: Difficulty) -> BoardConfig {
     match difficulty {
         Difficulty::Easy => BoardConfig {
@@ -16,6 +35,10 @@ pub fn difficulty_to_board_config(difficulty: Difficulty) -> BoardConfig {
                 height: easy::HEIGHT,
             },
             mine_count: easy::MINES,
+            shape: BoardShape::Square,
+            neighbourhood: Neighbourhood::Moore,
+            mines_per_cell: 1,
+            negative_mines: 0,
         },
         Difficulty::Medium => BoardConfig {
             board_size: BoardSize {
@@ -23,6 +46,10 @@ pub fn difficulty_to_board_config(difficulty: Difficulty) -> BoardConfig {
                 height: medium::HEIGHT,
             },
             mine_count: medium::MINES,
+            shape: BoardShape::Square,
+            neighbourhood: Neighbourhood::Moore,
+            mines_per_cell: 1,
+            negative_mines: 0,
         },
         Difficulty::Hard => BoardConfig {
             board_size: BoardSize {
@@ -30,10 +57,131 @@ pub fn difficulty_to_board_config(difficulty: Difficulty) -> BoardConfig {
                 height: hard::HEIGHT,
             },
             mine_count: hard::MINES,
+            shape: BoardShape::Square,
+            neighbourhood: Neighbourhood::Moore,
+            mines_per_cell: 1,
+            negative_mines: 0,
         },
     }
 }
 
+/// 六边形棋盘使用与方格相同的尺寸和地雷数，只替换相邻规则
+pub fn difficulty_to_hex_board_config(difficulty: Difficulty) -> BoardConfig {
+    BoardConfig {
+        shape: BoardShape::Hex,
+        ..difficulty_to_board_config(difficulty)
+    }
+}
+
+/// 使用指定相邻规则的方格棋盘，尺寸和地雷数与对应难度相同
+pub fn difficulty_to_neighbourhood_board_config(
+    difficulty: Difficulty,
+    neighbourhood: Neighbourhood,
+) -> BoardConfig {
+    BoardConfig {
+        neighbourhood,
+        ..difficulty_to_board_config(difficulty)
+    }
+}
+
+/// 多雷格子模式：中等难度的棋盘，每个格子可以有多颗地雷
+pub fn multi_mine_board_config() -> BoardConfig {
+    BoardConfig {
+        mine_count: multi_mine::MINES,
+        mines_per_cell: multi_mine::MINES_PER_CELL,
+        ..difficulty_to_board_config(Difficulty::Medium)
+    }
+}
+
+/// 负雷模式：中等难度的棋盘，部分地雷会让周围数字减一
+pub fn negative_mine_board_config() -> BoardConfig {
+    BoardConfig {
+        mine_count: negative_mine::MINES,
+        negative_mines: negative_mine::NEGATIVE_MINES,
+        ..difficulty_to_board_config(Difficulty::Medium)
+    }
+}
+
+/// 倒计时模式：中等难度的棋盘，展开空白区域可以加时
+pub fn countdown_config(seed: u64) -> TimedConfig {
+    TimedConfig {
+        mode: TimedMode::Countdown,
+        board_config: difficulty_to_board_config(Difficulty::Medium),
+        time_limit: Duration::from_secs(timed::COUNTDOWN_SECONDS),
+        bonus_per_opening: Duration::from_secs(timed::BONUS_SECONDS_PER_OPENING),
+        seed,
+    }
+}
+
+/// 限时挑战：连续的简单难度棋盘，不加时
+pub fn time_attack_config(seed: u64) -> TimedConfig {
+    TimedConfig {
+        mode: TimedMode::TimeAttack,
+        board_config: difficulty_to_board_config(Difficulty::Easy),
+        time_limit: Duration::from_secs(timed::TIME_ATTACK_SECONDS),
+        bonus_per_opening: Duration::ZERO,
+        seed,
+    }
+}
+
+/// 双人对战的棋盘
+pub fn flags_board_config() -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize {
+            width: flags::WIDTH,
+            height: flags::HEIGHT,
+        },
+        mine_count: flags::MINES,
+        ..difficulty_to_board_config(Difficulty::Medium)
+    }
+}
+
+/// 联机对战：双方使用相同种子的中等难度棋盘
+pub fn versus_config(seed: u64) -> VersusConfig {
+    VersusConfig {
+        board_config: difficulty_to_board_config(Difficulty::Medium),
+        seed,
+        lives: versus::LIVES,
+    }
+}
+
+/// 合作模式：所有玩家共享一块中等难度棋盘
+pub fn coop_config(seed: u64, players: usize) -> CoopConfig {
+    CoopConfig {
+        board_config: difficulty_to_board_config(Difficulty::Medium),
+        seed,
+        players,
+    }
+}
+
+/// 三维模式的默认配置
+pub fn cube_board_config() -> BoardConfig3D {
+    BoardConfig3D {
+        board_size: BoardSize3D {
+            width: cube::WIDTH,
+            height: cube::HEIGHT,
+            depth: cube::DEPTH,
+        },
+        mine_count: cube::MINES,
+    }
+}
+
+/// 无限模式的默认配置
+pub fn infinite_board_config(seed: u64) -> InfiniteBoardConfig {
+    InfiniteBoardConfig {
+        seed,
+        density: infinite::DEFAULT_DENSITY,
+    }
+}
+
+/// 图结构模式的地雷数：优先使用文件中的设置，否则按默认密度计算
+pub fn graph_mine_count(graph: &BoardGraph) -> usize {
+    graph.mine_count().unwrap_or_else(|| {
+        let count = (graph.node_count() as f64 * graph::DEFAULT_DENSITY).round() as usize;
+        count.clamp(1, graph.node_count().saturating_sub(1))
+    })
+}
+
 pub struct Minesweeper {
     // board: Board,
     // game_state: GameState,
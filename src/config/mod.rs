@@ -3,9 +3,17 @@
 //! 包含游戏的配置相关组件：
 //! - constants: 全局常量定义
 //! - difficulty: 难度配置和转换
+//! - settings: 设置文件
 
 pub mod constants;
 pub mod difficulty;
+pub mod settings;
 
 pub use constants::*;
-pub use difficulty::{Difficulty, difficulty_to_board_config};
+pub use difficulty::{
+    Difficulty, coop_config, countdown_config, cube_board_config, difficulty_to_board_config,
+    difficulty_to_hex_board_config, difficulty_to_neighbourhood_board_config, flags_board_config,
+    graph_mine_count, infinite_board_config, multi_mine_board_config, negative_mine_board_config,
+    time_attack_config, versus_config,
+};
+pub use settings::Settings;
@@ -0,0 +1,72 @@
+//! 设置文件
+//!
+//! 每行一个 `键 = 值`，`#` 开头的行是注释，空行忽略。目前支持的键：
+//! - lang: 界面语言，例如 `zh-CN`、`en`
+//! - render: 棋盘输出方式，`ansi`、`ascii` 或 `compact`
+//! - theme: 配色，`classic`、`high-contrast`、`colorblind` 或 `monochrome`
+//! - glyphs: 图标，`unicode`、`emoji` 或 `ascii`
+//! - colors: 颜色深度，`16`、`256` 或 `truecolor`
+
+use crate::i18n::Locale;
+use crate::render::{ColorDepth, GlyphSet, Palette, RenderMode};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Settings {
+    pub lang: Option<Locale>,
+    pub render: Option<RenderMode>,
+    pub theme: Option<Palette>,
+    pub glyphs: Option<GlyphSet>,
+    pub colors: Option<ColorDepth>,
+}
+
+impl Settings {
+    /// 读取设置文件；文件不存在或无法读取时返回默认设置。
+    /// 同时返回无法识别的行（行号从 1 开始）
+    pub fn load(path: &str) -> (Settings, Vec<(usize, String)>) {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Settings::parse(&text),
+            Err(_) => (Settings::default(), Vec::new()),
+        }
+    }
+
+    /// 解析设置文本，返回设置和无法识别的行
+    pub fn parse(text: &str) -> (Settings, Vec<(usize, String)>) {
+        let mut settings = Settings::default();
+        let mut unrecognised = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let recognised = match line.split_once('=') {
+                Some((key, value)) => settings.set(key.trim(), value.trim()),
+                None => false,
+            };
+            if !recognised {
+                unrecognised.push((index + 1, line.to_string()));
+            }
+        }
+        (settings, unrecognised)
+    }
+
+    // 设置一个键，键未知或值无效时保持原值并返回 false
+    fn set(&mut self, key: &str, value: &str) -> bool {
+        match key {
+            "lang" => set_parsed(&mut self.lang, Locale::parse(value)),
+            "render" => set_parsed(&mut self.render, RenderMode::parse(value)),
+            "theme" => set_parsed(&mut self.theme, Palette::parse(value)),
+            "glyphs" => set_parsed(&mut self.glyphs, GlyphSet::parse(value)),
+            "colors" => set_parsed(&mut self.colors, ColorDepth::parse(value)),
+            _ => false,
+        }
+    }
+}
+
+// 值有效时写入设置
+fn set_parsed<T>(field: &mut Option<T>, value: Option<T>) -> bool {
+    let valid = value.is_some();
+    if valid {
+        *field = value;
+    }
+    valid
+}
@@ -1,5 +1,11 @@
+use crate::config::game::{MAX_CUSTOM_NEIGHBOURS, MAX_MINES_PER_CELL};
+use crate::core::events::{BoardEvent, BoardObserver, ObserverId, Observers};
+use crate::core::packed_cell::PackedCell;
 use crate::core::{Cell, CellContent, CellState};
-use rand::seq::SliceRandom;
+use crate::render::{self, BoardView};
+use rand::rngs::SmallRng;
+use rand::seq::index::sample;
+use rand::{Rng, SeedableRng};
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Position {
@@ -7,7 +13,7 @@ pub struct Position {
     pub col: usize,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum ClickResult {
     Continue, // 继续游戏
     GameOver, // 踩到地雷
@@ -15,7 +21,27 @@ pub enum ClickResult {
     Invalid,  // 无效操作
 }
 
-#[derive(Debug, Clone, Copy)]
+/// 旗子数的一次改变
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlagChange {
+    pub pos: Position,
+    pub from: i8,
+    pub to: i8,
+}
+
+/// 一次操作的完整结果：新状态和可见内容发生变化的格子，
+/// 用于增量绘制、展开动画和精确撤销
+#[derive(Clone, Debug)]
+pub struct ClickDetails {
+    pub result: ClickResult,
+    /// 这次操作是否触发了布雷（首次点击）
+    pub mines_placed: bool,
+    /// 按翻开顺序排列：先是点击的格子，然后按展开的顺序排列其余格子
+    pub revealed: Vec<(Position, CellContent)>,
+    pub flags: Vec<FlagChange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BoardSize {
     pub width: usize,
     pub height: usize,
@@ -27,207 +53,661 @@ impl BoardSize {
     }
 }
 
+/// 棋盘形状：决定格子的相邻关系和打印方式
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BoardShape {
+    #[default]
+    Square, // 方格，8 邻域
+    Hex, // 六边形（奇数行右移的偏移坐标），6 邻域
+}
+
+/// 方格棋盘的相邻规则：决定数字统计、自动展开和首次点击安全区的范围
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Neighbourhood {
+    /// 周围 8 格
+    #[default]
+    Moore,
+    /// 上下左右 4 格
+    Orthogonal,
+    /// 国际象棋马步可达的 8 格
+    Knight,
+    /// 5x5 方块去掉中心，24 格
+    Radius2,
+    /// 自定义偏移列表
+    Custom(CustomOffsets),
+}
+
+/// 自定义相邻偏移，定长存储以保持 BoardConfig 可复制
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomOffsets {
+    // 第 0 项固定为中心 (0, 0)，之后是去重后的相邻偏移
+    offsets: [(i8, i8); MAX_CUSTOM_NEIGHBOURS + 1],
+    len: usize,
+}
+
+impl CustomOffsets {
+    /// 由 (dr, dc) 列表构造，自动去掉中心和重复项；
+    /// 为空、超过容量或偏移超出 i8 范围时返回 None
+    pub fn new(offsets: &[(isize, isize)]) -> Option<Self> {
+        let mut custom = CustomOffsets {
+            offsets: [(0, 0); MAX_CUSTOM_NEIGHBOURS + 1],
+            len: 1,
+        };
+        for &(dr, dc) in offsets {
+            let offset = (i8::try_from(dr).ok()?, i8::try_from(dc).ok()?);
+            if offset == (0, 0) || custom.offsets[..custom.len].contains(&offset) {
+                continue;
+            }
+            if custom.len > MAX_CUSTOM_NEIGHBOURS {
+                return None;
+            }
+            custom.offsets[custom.len] = offset;
+            custom.len += 1;
+        }
+        (custom.len > 1).then_some(custom)
+    }
+
+    /// 相邻格子数（不含中心）
+    pub fn neighbour_count(&self) -> usize {
+        self.len - 1
+    }
+
+    /// 解析 "dr,dc dr,dc ..." 形式的偏移列表，例如 "-1,0 1,0 0,-2 0,2"
+    pub fn parse(text: &str) -> Option<Self> {
+        let offsets = text
+            .split_whitespace()
+            .map(|token| {
+                let (dr, dc) = token.split_once(',')?;
+                Some((dr.trim().parse().ok()?, dc.trim().parse().ok()?))
+            })
+            .collect::<Option<Vec<(isize, isize)>>>()?;
+        Self::new(&offsets)
+    }
+
+    fn get(&self, index: usize) -> Option<(isize, isize)> {
+        self.offsets[..self.len]
+            .get(index)
+            .map(|&(dr, dc)| (isize::from(dr), isize::from(dc)))
+    }
+
+    // 所有偏移取反：偏移不对称时，地雷影响的是"把它算作邻居"的那些格子
+    fn reversed(mut self) -> Self {
+        for offset in &mut self.offsets[..self.len] {
+            *offset = (-offset.0, -offset.1);
+        }
+        self
+    }
+}
+
+impl Neighbourhood {
+    /// 规则名称，用于界面显示
+    pub fn label(&self) -> &'static str {
+        match self {
+            Neighbourhood::Moore => crate::i18n::text("neighbourhood.moore"),
+            Neighbourhood::Orthogonal => crate::i18n::text("neighbourhood.orthogonal"),
+            Neighbourhood::Knight => crate::i18n::text("neighbourhood.knight"),
+            Neighbourhood::Radius2 => crate::i18n::text("neighbourhood.radius2"),
+            Neighbourhood::Custom(_) => crate::i18n::text("neighbourhood.custom"),
+        }
+    }
+
+    /// 一个格子最多的相邻格子数，也就是数字的最大值
+    pub fn max_neighbours(&self) -> usize {
+        match self {
+            Neighbourhood::Moore | Neighbourhood::Knight => 8,
+            Neighbourhood::Orthogonal => 4,
+            Neighbourhood::Radius2 => 24,
+            Neighbourhood::Custom(custom) => custom.neighbour_count(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BoardConfig {
     pub board_size: BoardSize,
     pub mine_count: usize,
+    pub shape: BoardShape,
+    /// 相邻规则，只对方格棋盘生效；六边形棋盘始终使用 6 邻域
+    pub neighbourhood: Neighbourhood,
+    /// 每个格子最多容纳的地雷数，经典规则为 1；mine_count 是地雷总数而不是雷格数
+    pub mines_per_cell: u8,
+    /// 负雷格子数：这些格子里的地雷让相邻数字减小，0 表示经典规则
+    pub negative_mines: usize,
 }
 
 pub struct Board {
     board_config: BoardConfig,
-    cells: Vec<Vec<Cell>>,
+    // 按行优先顺序排列的紧凑格子，下标为 row * width + col
+    cells: Vec<PackedCell>,
+    neighbour_table: NeighbourTable,
+    // 偏移取反的配置和偏移表：放置地雷时沿它更新数字，自定义偏移可以不对称
+    reverse_config: BoardConfig,
+    reverse_table: NeighbourTable,
     mines_placed: bool,
     revealed_count: usize,
+    // 含有地雷的格子数，多雷格子时小于地雷总数
+    mine_cell_count: usize,
+    // 布雷随机种子；None 时使用线程随机源
+    seed: Option<u64>,
+    observers: Observers,
+    // 详细结果的操作进行期间记录发生的事件
+    recording: Option<Vec<BoardEvent>>,
+}
+
+// 地雷数不超过可用格子的 1/SPARSE_MINE_RATIO 时使用拒绝采样，否则顺序抽样
+const SPARSE_MINE_RATIO: usize = 16;
+
+/// 预先计算的相邻下标偏移，远离边缘的格子可以直接加偏移而无需越界检查
+struct NeighbourTable {
+    // 偶数行和奇数行各自的下标偏移（方格棋盘两者相同）
+    deltas: [Vec<isize>; 2],
+    // 偏移在行、列方向上的最大跨度
+    reach: usize,
+}
+
+impl NeighbourTable {
+    fn new(config: &BoardConfig) -> Self {
+        let width = config.board_size.width as isize;
+        let mut reach = 0;
+        let deltas = [0, 1].map(|parity| {
+            // 用对应奇偶性的一行作为代表取偏移
+            config
+                .offsets(parity)
+                .iter()
+                .filter(|&(dr, dc)| (dr, dc) != (0, 0))
+                .map(|(dr, dc)| {
+                    reach = reach.max(dr.unsigned_abs()).max(dc.unsigned_abs());
+                    dr * width + dc
+                })
+                .collect()
+        });
+        NeighbourTable { deltas, reach }
+    }
 }
 
-fn get_adjacent_mines_count(cells: &[Vec<Cell>], pos: Position, board_size: BoardSize) -> u8 {
-    let mut mines_count = 0;
-    let adj_positions = get_adjacent_positions(pos, board_size, true);
-    for adj_pos in adj_positions {
-        if let CellContent::Mine = cells[adj_pos.row][adj_pos.col].content() {
-            mines_count += 1
+// 方格 8 邻域（含中心）的偏移 (dr, dc)，按行优先顺序
+const SQUARE_OFFSETS: [(isize, isize); 9] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 0),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+// 正交 4 邻域（含中心）
+const ORTHOGONAL_OFFSETS: [(isize, isize); 5] = [(-1, 0), (0, -1), (0, 0), (0, 1), (1, 0)];
+
+// 马步 8 邻域（含中心）
+const KNIGHT_OFFSETS: [(isize, isize); 9] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, -2),
+    (-1, 2),
+    (0, 0),
+    (1, -2),
+    (1, 2),
+    (2, -1),
+    (2, 1),
+];
+
+// 半径 2 的 5x5 方块（含中心），按行优先顺序
+const RADIUS2_OFFSETS: [(isize, isize); 25] = {
+    let mut offsets = [(0, 0); 25];
+    let mut i = 0;
+    while i < 25 {
+        offsets[i] = (i as isize / 5 - 2, i as isize % 5 - 2);
+        i += 1;
+    }
+    offsets
+};
+
+// 六边形棋盘的相邻偏移 (dr, dc)，偶数行和奇数行不同
+const HEX_EVEN_ROW_OFFSETS: [(isize, isize); 7] =
+    [(0, 0), (-1, -1), (-1, 0), (0, -1), (0, 1), (1, -1), (1, 0)];
+const HEX_ODD_ROW_OFFSETS: [(isize, isize); 7] =
+    [(0, 0), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, 0), (1, 1)];
+
+// 相邻偏移的来源：内置规则使用静态表，自定义规则携带自己的副本
+#[derive(Clone, Copy)]
+enum Offsets {
+    Static(&'static [(isize, isize)]),
+    Custom(CustomOffsets),
+}
+
+impl Offsets {
+    fn get(&self, index: usize) -> Option<(isize, isize)> {
+        match self {
+            Offsets::Static(offsets) => offsets.get(index).copied(),
+            Offsets::Custom(custom) => custom.get(index),
         }
     }
-    mines_count
+
+    fn iter(self) -> impl Iterator<Item = (isize, isize)> {
+        (0..).map_while(move |index| self.get(index))
+    }
 }
 
-fn is_valid_index(index: isize, limit: usize) -> bool {
-    index >= 0 && index < (limit as isize)
+/// 不分配内存的相邻格子迭代器，自动跳过越界的位置
+pub struct Neighbours {
+    center: Position,
+    size: BoardSize,
+    offsets: Offsets,
+    skip_center: bool,
+    next: usize,
 }
 
-pub fn get_adjacent_positions(pos: Position, size: BoardSize, skip_center: bool) -> Vec<Position> {
-    let mut positions: Vec<Position> = Vec::new();
-    for dr in -1..=1 {
-        for dc in -1..=1 {
-            if skip_center && dr == 0 && dc == 0 {
+impl Iterator for Neighbours {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Position> {
+        while let Some((dr, dc)) = self.offsets.get(self.next) {
+            self.next += 1;
+            if self.skip_center && dr == 0 && dc == 0 {
                 continue;
             }
 
-            let new_row = pos.row as isize + dr;
-            let new_col = pos.col as isize + dc;
-            let width = size.width;
-            let height = size.height;
-            if is_valid_index(new_row, height) && is_valid_index(new_col, width) {
-                positions.push(Position {
+            let new_row = self.center.row as isize + dr;
+            let new_col = self.center.col as isize + dc;
+            if is_valid_index(new_row, self.size.height) && is_valid_index(new_col, self.size.width)
+            {
+                return Some(Position {
                     row: new_row as usize,
                     col: new_col as usize,
                 });
             }
         }
+        None
+    }
+}
+
+fn is_valid_index(index: isize, limit: usize) -> bool {
+    index >= 0 && index < (limit as isize)
+}
+
+pub fn get_adjacent_positions(pos: Position, size: BoardSize, skip_center: bool) -> Vec<Position> {
+    Neighbours {
+        center: pos,
+        size,
+        offsets: Offsets::Static(&SQUARE_OFFSETS),
+        skip_center,
+        next: 0,
+    }
+    .collect()
+}
+
+pub fn get_hex_adjacent_positions(
+    pos: Position,
+    size: BoardSize,
+    skip_center: bool,
+) -> Vec<Position> {
+    Neighbours {
+        center: pos,
+        size,
+        offsets: Offsets::Static(hex_offsets(pos)),
+        skip_center,
+        next: 0,
+    }
+    .collect()
+}
+
+fn hex_offsets(pos: Position) -> &'static [(isize, isize)] {
+    if pos.row.is_multiple_of(2) {
+        &HEX_EVEN_ROW_OFFSETS
+    } else {
+        &HEX_ODD_ROW_OFFSETS
+    }
+}
+
+// 对每个相邻格子的下标调用 f：内部格子直接加偏移，边缘格子回退到带越界检查的 Neighbours。
+// 只借用配置和偏移表，调用方可以在 f 中同时修改格子
+fn for_each_neighbour_index(
+    config: &BoardConfig,
+    table: &NeighbourTable,
+    pos: Position,
+    index: usize,
+    mut f: impl FnMut(usize),
+) {
+    let size = config.board_size;
+    let reach = table.reach;
+    if pos.row >= reach
+        && pos.row + reach < size.height
+        && pos.col >= reach
+        && pos.col + reach < size.width
+    {
+        for &delta in &table.deltas[pos.row % 2] {
+            f(index.wrapping_add_signed(delta));
+        }
+    } else {
+        for adj_pos in config.neighbours(pos, true) {
+            f(adj_pos.row * size.width + adj_pos.col);
+        }
     }
-    positions
+}
+
+impl Position {
+    /// 转换为六边形轴向坐标 (q, r)，q 为斜列，r 为行
+    pub fn to_axial(self) -> (isize, isize) {
+        let row = self.row as isize;
+        let q = self.col as isize - (row - (row & 1)) / 2;
+        (q, row)
+    }
+
+    /// 从六边形轴向坐标 (q, r) 转换回偏移坐标，负行或负列返回 None
+    pub fn from_axial(q: isize, r: isize) -> Option<Position> {
+        if r < 0 {
+            return None;
+        }
+        let col = q + (r - (r & 1)) / 2;
+        if col < 0 {
+            return None;
+        }
+        Some(Position {
+            row: r as usize,
+            col: col as usize,
+        })
+    }
+}
+
+impl BoardConfig {
+    // 指定行使用的相邻偏移（含中心），六边形棋盘按行的奇偶性区分
+    fn offsets(&self, row: usize) -> Offsets {
+        match (self.shape, self.neighbourhood) {
+            (BoardShape::Hex, _) => Offsets::Static(hex_offsets(Position { row, col: 0 })),
+            (BoardShape::Square, Neighbourhood::Moore) => Offsets::Static(&SQUARE_OFFSETS),
+            (BoardShape::Square, Neighbourhood::Orthogonal) => Offsets::Static(&ORTHOGONAL_OFFSETS),
+            (BoardShape::Square, Neighbourhood::Knight) => Offsets::Static(&KNIGHT_OFFSETS),
+            (BoardShape::Square, Neighbourhood::Radius2) => Offsets::Static(&RADIUS2_OFFSETS),
+            (BoardShape::Square, Neighbourhood::Custom(custom)) => Offsets::Custom(custom),
+        }
+    }
+
+    // 偏移取反后的配置，用于把地雷计入以它为邻居的格子；对称的规则与原配置相同
+    fn reversed(&self) -> BoardConfig {
+        let neighbourhood = match self.neighbourhood {
+            Neighbourhood::Custom(custom) => Neighbourhood::Custom(custom.reversed()),
+            symmetric => symmetric,
+        };
+        BoardConfig {
+            neighbourhood,
+            ..*self
+        }
+    }
+
+    /// 按棋盘形状和相邻规则迭代相邻格子，不分配内存
+    pub fn neighbours(&self, pos: Position, skip_center: bool) -> Neighbours {
+        Neighbours {
+            center: pos,
+            size: self.board_size,
+            offsets: self.offsets(pos.row),
+            skip_center,
+            next: 0,
+        }
+    }
+
+    /// 一个格子最多的相邻地雷数
+    pub fn max_adjacent_mines(&self) -> usize {
+        let neighbours = match self.shape {
+            BoardShape::Square => self.neighbourhood.max_neighbours(),
+            BoardShape::Hex => 6,
+        };
+        neighbours * usize::from(self.mines_per_cell)
+    }
+
+    /// 按棋盘形状返回相邻格子
+    pub fn adjacent_positions(&self, pos: Position, skip_center: bool) -> Vec<Position> {
+        self.neighbours(pos, skip_center).collect()
+    }
+}
+
+// 打印带行列号和边框的方格棋盘，cell_at 按位置提供格子
+pub(crate) fn print_square_grid(size: BoardSize, cell_at: impl Fn(Position) -> Cell) {
+    render::print_grid(&BoardView::grid(size, cell_at));
+}
+
+// 同 print_square_grid，color_at 返回 Some 时覆盖该格子的颜色（例如双人模式的玩家颜色）
+pub(crate) fn print_square_grid_with_colors(
+    size: BoardSize,
+    cell_at: impl Fn(Position) -> Cell,
+    color_at: impl Fn(Position) -> Option<&'static str>,
+) {
+    render::print_grid(&BoardView::grid(size, cell_at).with_colors(color_at));
 }
 
 impl Board {
     pub fn new(board_config: BoardConfig) -> Self {
-        let width = board_config.board_size.width;
-        let height = board_config.board_size.height;
-        let cells = vec![vec![Cell::new(); width]; height];
+        Self::build(board_config, None)
+    }
+
+    /// 使用固定种子布雷：同样的种子和首次点击位置总是得到同样的棋盘
+    pub fn with_seed(board_config: BoardConfig, seed: u64) -> Self {
+        Self::build(board_config, Some(seed))
+    }
+
+    fn build(board_config: BoardConfig, seed: Option<u64>) -> Self {
+        assert!(
+            (1..=MAX_MINES_PER_CELL).contains(&board_config.mines_per_cell),
+            "mines per cell must be in 1..={}",
+            MAX_MINES_PER_CELL
+        );
+        assert!(
+            board_config.negative_mines <= board_config.mine_count,
+            "negative mines cannot exceed mine count"
+        );
+        let cells = vec![PackedCell::default(); board_config.board_size.cell_count()];
         Board {
+            neighbour_table: NeighbourTable::new(&board_config),
+            reverse_config: board_config.reversed(),
+            reverse_table: NeighbourTable::new(&board_config.reversed()),
             board_config,
             cells,
             mines_placed: false,
             revealed_count: 0,
+            mine_cell_count: 0,
+            seed,
+            observers: Observers::default(),
+            recording: None,
         }
     }
 
+    /// 订阅棋盘事件，返回的编号可用于取消订阅
+    pub fn subscribe(&mut self, observer: impl BoardObserver + 'static) -> ObserverId {
+        self.observers.add(Box::new(observer))
+    }
+
+    /// 取消订阅，编号不存在时返回 false
+    pub fn unsubscribe(&mut self, id: ObserverId) -> bool {
+        self.observers.remove(id)
+    }
+
+    // 有观察者或正在记录详细结果时才需要产生事件
+    fn listening(&self) -> bool {
+        self.recording.is_some() || !self.observers.is_empty()
+    }
+
+    fn emit(&mut self, event: BoardEvent) {
+        if let Some(recording) = &mut self.recording {
+            recording.push(event);
+        }
+        if !self.observers.is_empty() {
+            self.observers.emit(&event);
+        }
+    }
+
+    // 执行一次操作并收集其间的事件，整理成详细结果
+    fn detailed(&mut self, action: impl FnOnce(&mut Board) -> ClickResult) -> ClickDetails {
+        self.recording = Some(Vec::new());
+        let result = action(self);
+        let events = self.recording.take().unwrap_or_default();
+
+        let mut details = ClickDetails {
+            result,
+            mines_placed: false,
+            revealed: Vec::new(),
+            flags: Vec::new(),
+        };
+        for event in events {
+            match event {
+                BoardEvent::MinesPlaced { .. } => details.mines_placed = true,
+                BoardEvent::CellRevealed { pos, content } => details.revealed.push((pos, content)),
+                BoardEvent::FlagToggled {
+                    pos,
+                    flags,
+                    previous,
+                } => details.flags.push(FlagChange {
+                    pos,
+                    from: previous,
+                    to: flags,
+                }),
+                BoardEvent::MineTriggered { .. } | BoardEvent::GameWon => {}
+            }
+        }
+        details
+    }
+
+    /// 与 left_click 相同，另外返回翻开的格子
+    pub fn left_click_detailed(&mut self, pos: Position) -> ClickDetails {
+        self.detailed(|board| board.left_click(pos))
+    }
+
+    /// 与 right_click 相同，另外返回旗子的变化
+    pub fn right_click_detailed(&mut self, pos: Position) -> ClickDetails {
+        self.detailed(|board| board.right_click(pos))
+    }
+
+    /// 与 chord 相同，另外返回翻开的格子
+    pub fn chord_detailed(&mut self, pos: Position) -> ClickDetails {
+        self.detailed(|board| board.chord(pos))
+    }
+
+    fn index(&self, pos: Position) -> usize {
+        pos.row * self.board_config.board_size.width + pos.col
+    }
+
     fn ensure_mines_placed(&mut self, first_click_pos: Position) {
         if !self.mines_placed {
             self.place_mines_avoiding_first_click(first_click_pos);
-            self.calculate_numbers();
             self.mines_placed = true;
+            self.emit(BoardEvent::MinesPlaced {
+                first_click: first_click_pos,
+            });
         }
     }
 
     fn place_mines_avoiding_first_click(&mut self, first_click_pos: Position) {
-        let width = self.board_config.board_size.width;
-        let height = self.board_config.board_size.height;
+        let total = self.board_config.board_size.cell_count();
         let mine_count = self.board_config.mine_count;
-        let board_size = self.board_config.board_size;
 
-        let forbidden_area = get_adjacent_positions(first_click_pos, board_size, false);
+        let forbidden_area: Vec<usize> = self
+            .board_config
+            .neighbours(first_click_pos, false)
+            .map(|pos| self.index(pos))
+            .collect();
+        let available = total - forbidden_area.len();
+        let per_cell = usize::from(self.board_config.mines_per_cell.max(1));
 
-        // 所有坐标位置，排除禁区
-        let mut positions: Vec<(usize, usize)> = Vec::new();
-        for row in 0..height {
-            for col in 0..width {
-                if !forbidden_area.contains(&Position { row, col }) {
-                    positions.push((row, col));
-                }
-            }
-        }
-        if positions.len() < mine_count {
+        if available * per_cell < mine_count {
             panic!("No enough positions to place mines!")
         }
-        // 随机打乱位置rng
-        let mut rng = rand::rng();
-        positions.shuffle(&mut rng);
-        // 选择前mine_count个位置放置地雷
-        for &(row, col) in positions.iter().take(mine_count) {
-            self.cells[row][col].set_content(CellContent::Mine);
-        }
-    }
-
-    fn calculate_numbers(&mut self) {
-        // 计算每个单元格周围地雷数量的逻辑
-        let board_size = self.board_config.board_size;
-        let width = board_size.width;
-        let height = board_size.height;
-        for row in 0..height {
-            for col in 0..width {
-                if let CellContent::Mine = self.cells[row][col].content() {
-                    continue; // 如果是地雷，跳过
-                } else {
-                    let pos = Position { row, col };
-                    let mines_count = get_adjacent_mines_count(&self.cells, pos, board_size);
-                    self.cells[row][col].set_content(CellContent::Number(mines_count));
+
+        // 直接在格子上抽样，不为整个棋盘构造候选列表；
+        // 超大棋盘每个格子都要取随机数，使用从线程随机源（或固定种子）播种的快速生成器
+        let mut rng = match self.seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_rng(&mut rand::rng()),
+        };
+        if per_cell > 1 {
+            // 多雷格子：每个可用格子提供 per_cell 个槽位，从所有槽位中无放回地抽取地雷
+            let candidates: Vec<usize> = (0..total)
+                .filter(|index| !forbidden_area.contains(index))
+                .collect();
+            for slot in sample(&mut rng, candidates.len() * per_cell, mine_count) {
+                self.cells[candidates[slot / per_cell]].add_mine();
+            }
+        } else if mine_count <= available / SPARSE_MINE_RATIO {
+            // 地雷稀疏：随机抽位置，已有地雷或在禁区内则重抽
+            let mut placed = 0;
+            while placed < mine_count {
+                let index = rng.random_range(0..total);
+                if !self.cells[index].is_mine() && !forbidden_area.contains(&index) {
+                    self.cells[index].add_mine();
+                    placed += 1;
                 }
             }
-        }
-    }
-
-    pub fn print_debug(&self) {
-        let width = self.board_config.board_size.width;
-        let height = self.board_config.board_size.height;
-
-        // ANSI 颜色常量
-        const RESET: &str = "\x1b[0m";
-        const DIM: &str = "\x1b[90m"; // 暗色（列/行号、隐藏）
-        const RED: &str = "\x1b[31m"; // 红色（地雷、旗子）
-        const BLUE: &str = "\x1b[34m"; // 1
-        const GREEN: &str = "\x1b[32m"; // 2
-        const YELLOW: &str = "\x1b[33m"; // 5（传统中为棕色，使用黄代替）
-        const MAGENTA: &str = "\x1b[35m"; // 4
-        const CYAN: &str = "\x1b[36m"; // 6
-        const WHITE: &str = "\x1b[37m"; // 7
-        const BRIGHT_BLACK: &str = "\x1b[90m"; // 8（浅灰）
-
-        fn color_for_number(n: u8) -> &'static str {
-            match n {
-                1 => BLUE,
-                2 => GREEN,
-                3 => RED,
-                4 => MAGENTA,
-                5 => YELLOW,
-                6 => CYAN,
-                7 => WHITE,
-                8 => BRIGHT_BLACK,
-                _ => RESET,
+        } else {
+            // 地雷较密：顺序选择抽样，按顺序访问内存，每个格子以 剩余地雷/剩余格子 的概率布雷
+            let mut needed = mine_count;
+            let mut remaining = available;
+            let forbidden_start = forbidden_area.iter().copied().min().unwrap_or(0);
+            let forbidden_end = forbidden_area.iter().copied().max().unwrap_or(0);
+            for index in 0..total {
+                if needed == 0 {
+                    break;
+                }
+                if (forbidden_start..=forbidden_end).contains(&index)
+                    && forbidden_area.contains(&index)
+                {
+                    continue;
+                }
+                if rng.random_range(0..remaining) < needed {
+                    self.cells[index].add_mine();
+                    needed -= 1;
+                }
+                remaining -= 1;
             }
         }
 
-        // 列号表头（使用暗色）
-        print!("    ");
-        for col in 0..width {
-            print!("{}{:>2} {}", DIM, col, RESET);
-        }
-        println!();
-
-        // 顶部边框
-        print!("   +");
-        for _ in 0..width {
-            print!("---");
-        }
-        println!("+");
-
-        // 行内容
-        for row in 0..height {
-            // 行号（暗色）
-            print!("{}{:>2}{} |", DIM, row, RESET);
-            for col in 0..width {
-                let cell = &self.cells[row][col];
-                let (glyph, color): (char, &str) = match cell.state() {
-                    // 隐藏：白框图标（暗色）
-                    CellState::Hidden => ('□', DIM),
-                    // 旗子：红色小旗
-                    CellState::Flagged => ('⚑', RED),
-                    // 已翻开：数字按经典扫雷配色；0 显示为空格；地雷为红色图标
-                    CellState::Revealed => match cell.content() {
-                        CellContent::Mine => ('✹', RED),
-                        CellContent::Number(0) => (' ', RESET),
-                        CellContent::Number(n) => (char::from(b'0' + n), color_for_number(n)),
-                    },
-                };
-                // 固定宽度打印，颜色包裹不影响对齐
-                print!(" {}{}{} ", color, glyph, RESET);
+        // 负雷：从已布雷的格子中随机挑选
+        let negative_mines = self.board_config.negative_mines;
+        if negative_mines > 0 {
+            let mine_cells: Vec<usize> = (0..total)
+                .filter(|&index| self.cells[index].is_mine())
+                .collect();
+            let amount = negative_mines.min(mine_cells.len());
+            for chosen in sample(&mut rng, mine_cells.len(), amount) {
+                self.cells[mine_cells[chosen]].set_negative();
             }
-            println!("|");
         }
 
-        // 底部边框
-        print!("   +");
-        for _ in 0..width {
-            print!("---");
+        // 每个雷格只给自己的邻居加上它的（带符号的）地雷数，不必为每个格子重新数一遍
+        let size = self.board_config.board_size;
+        for row in 0..size.height {
+            for col in 0..size.width {
+                let index = row * size.width + col;
+                let count = self.cells[index].mine_count();
+                if count != 0 {
+                    self.mine_cell_count += 1;
+                    self.add_mine_to_neighbours(Position { row, col }, index, count);
+                }
+            }
         }
-        println!("+");
+    }
 
-        // 图例（暗色）
-        println!(
-            "{}Legend:{} □ hidden, {}⚑{} flag, {}✹{} mine, colored numbers show adjacent mines",
-            DIM, RESET, RED, RESET, RED, RESET
+    // 给所有把 pos 算作邻居的格子加上 count，对称规则下就是 pos 自己的邻居
+    fn add_mine_to_neighbours(&mut self, pos: Position, index: usize, count: i8) {
+        let cells = &mut self.cells;
+        for_each_neighbour_index(
+            &self.reverse_config,
+            &self.reverse_table,
+            pos,
+            index,
+            |adj| cells[adj].add_adjacent_mines(count),
         );
     }
 
+    /// 用当前的输出方式（见 `render` 模块）把棋盘和图例打印到标准输出
+    pub fn print_debug(&self) {
+        render::print(&BoardView::of(self));
+    }
+
+    // 展开为普通的 Cell，供其他模块打印
+    pub(crate) fn cell_at(&self, pos: Position) -> Cell {
+        self.cells[self.index(pos)].to_cell()
+    }
+
     // 左键点击处理
     pub fn left_click(&mut self, pos: Position) -> ClickResult {
         if !self.is_valid_position(pos) {
@@ -235,24 +715,33 @@ impl Board {
         }
 
         self.ensure_mines_placed(pos);
-        let cell = &mut self.cells[pos.row][pos.col];
+        let index = self.index(pos);
+        let cell = &mut self.cells[index];
         match cell.state() {
             CellState::Revealed => ClickResult::Invalid,
             CellState::Flagged => ClickResult::Invalid,
             CellState::Hidden => {
                 cell.set_state(CellState::Revealed);
-                match cell.content() {
-                    CellContent::Mine => ClickResult::GameOver,
-                    CellContent::Number(number) => {
+                let content = cell.content();
+                let near_mine = cell.is_near_mine();
+                self.emit(BoardEvent::CellRevealed { pos, content });
+                match content {
+                    CellContent::Mine(_) => {
+                        self.emit(BoardEvent::MineTriggered { pos });
+                        ClickResult::GameOver
+                    }
+                    CellContent::Number(_) => {
                         self.revealed_count += 1;
 
-                        // 如果是空白格子，自动展开相邻区域
-                        if number == 0 {
+                        // 如果周围没有任何地雷，自动展开相邻区域；
+                        // 负雷模式下正负抵消的 0 不会展开
+                        if !near_mine {
                             self.auto_reveal_flood_fill(pos);
                         }
 
                         // 统一检查胜利条件
                         if self.check_victory() {
+                            self.emit(BoardEvent::GameWon);
                             ClickResult::Victory
                         } else {
                             ClickResult::Continue
@@ -263,64 +752,169 @@ impl Board {
         }
     }
 
-    // 使用队列实现的洪水填充算法，避免递归栈溢出
-    fn auto_reveal_flood_fill(&mut self, start_pos: Position) {
-        use std::collections::VecDeque;
+    /// 双击已翻开的数字：周围的旗子数与数字相等时，翻开周围所有未标记的格子。
+    /// 条件不满足或没有可翻开的格子时返回 Invalid；翻开的格子中有地雷时返回 GameOver
+    pub fn chord(&mut self, pos: Position) -> ClickResult {
+        if !self.is_valid_position(pos) {
+            return ClickResult::Invalid;
+        }
+        let cell = self.cells[self.index(pos)];
+        let CellContent::Number(number) = cell.content() else {
+            return ClickResult::Invalid;
+        };
+        if cell.state() != CellState::Revealed {
+            return ClickResult::Invalid;
+        }
 
-        let mut queue = VecDeque::new();
-        queue.push_back(start_pos);
+        let neighbours: Vec<Position> = self.board_config.neighbours(pos, true).collect();
+        let flags: i16 = neighbours
+            .iter()
+            .map(|&adj| i16::from(self.cells[self.index(adj)].flag_count()))
+            .sum();
+        let hidden: Vec<Position> = neighbours
+            .into_iter()
+            .filter(|&adj| self.cells[self.index(adj)].state() == CellState::Hidden)
+            .collect();
+        if flags != number || hidden.is_empty() {
+            return ClickResult::Invalid;
+        }
 
-        while let Some(pos) = queue.pop_front() {
-            let adj_positions = get_adjacent_positions(pos, self.board_config.board_size, true);
+        let mut result = ClickResult::Continue;
+        for adj in hidden {
+            match self.left_click(adj) {
+                ClickResult::GameOver => result = ClickResult::GameOver,
+                ClickResult::Victory if !matches!(result, ClickResult::GameOver) => {
+                    result = ClickResult::Victory
+                }
+                _ => {}
+            }
+        }
+        result
+    }
 
-            for adj_pos in adj_positions {
-                let cell = &mut self.cells[adj_pos.row][adj_pos.col];
+    // 使用显式栈实现的洪水填充算法，避免递归栈溢出；栈中保存格子下标
+    fn auto_reveal_flood_fill(&mut self, start_pos: Position) {
+        // 有观察者或正在记录详细结果时记下展开的格子，展开结束后再逐个通知
+        let record = self.listening();
+        let mut stack = vec![self.index(start_pos)];
+        let cells = &mut self.cells;
+        let revealed_count = &mut self.revealed_count;
+        let mut revealed = Vec::new();
 
-                // 显式跳过已标记的格子，避免被自动展开覆盖
-                if matches!(cell.state(), CellState::Flagged) {
-                    continue;
-                }
+        let width = self.board_config.board_size.width;
 
-                // 只处理隐藏状态且非地雷的格子
-                if matches!(cell.state(), CellState::Hidden)
-                    && !matches!(cell.content(), CellContent::Mine)
-                {
-                    cell.set_state(CellState::Revealed);
-                    self.revealed_count += 1;
+        while let Some(index) = stack.pop() {
+            let pos = Position {
+                row: index / width,
+                col: index % width,
+            };
+            for_each_neighbour_index(
+                &self.board_config,
+                &self.neighbour_table,
+                pos,
+                index,
+                |adj| {
+                    let cell = &mut cells[adj];
+
+                    // 只处理隐藏状态且非地雷的格子，已标记的格子不会被自动展开覆盖
+                    if cell.is_hidden_safe() {
+                        cell.set_state(CellState::Revealed);
+                        *revealed_count += 1;
+                        if record {
+                            revealed.push(adj);
+                        }
 
-                    // 如果相邻格子也是空白格子，加入队列继续展开
-                    if let CellContent::Number(0) = cell.content() {
-                        queue.push_back(adj_pos);
+                        // 如果相邻格子周围也没有地雷，继续展开
+                        if !cell.is_near_mine() {
+                            stack.push(adj);
+                        }
                     }
-                }
-            }
+                },
+            );
+        }
+
+        for index in revealed {
+            self.emit_revealed(index);
         }
     }
 
+    fn emit_revealed(&mut self, index: usize) {
+        let width = self.board_config.board_size.width;
+        let pos = Position {
+            row: index / width,
+            col: index % width,
+        };
+        let content = self.cells[index].content();
+        self.emit(BoardEvent::CellRevealed { pos, content });
+    }
+
     pub fn reveal_all_mines(&mut self) {
-        for row in &mut self.cells {
-            for cell in row {
-                if cell.is_mine() {
-                    cell.set_state(CellState::Revealed);
+        let mut revealed = Vec::new();
+        for (index, cell) in self.cells.iter_mut().enumerate() {
+            if cell.is_mine() {
+                if cell.state() != CellState::Revealed {
+                    revealed.push(index);
                 }
+                cell.set_state(CellState::Revealed);
+            }
+        }
+        if self.listening() {
+            for index in revealed {
+                self.emit_revealed(index);
             }
         }
     }
 
+    /// 生命模式下踩中地雷后调用：把刚翻开的地雷改为插上与地雷数相符的旗子，
+    /// 此后这面旗子不能再拔掉，避免同一颗地雷重复扣除生命
+    pub fn flag_triggered_mine(&mut self, pos: Position) {
+        if !self.is_valid_position(pos) {
+            return;
+        }
+        let index = self.index(pos);
+        let cell = &mut self.cells[index];
+        if cell.is_mine() && cell.state() == CellState::Revealed {
+            let previous = cell.flag_count();
+            let flags = cell.mine_count();
+            cell.set_flag_count(flags);
+            cell.set_triggered();
+            self.emit(BoardEvent::FlagToggled {
+                pos,
+                flags,
+                previous,
+            });
+        }
+    }
+
     // 右键点击处理
     pub fn right_click(&mut self, pos: Position) -> ClickResult {
         if !self.is_valid_position(pos) {
             ClickResult::Invalid
         } else {
-            let cell = &mut self.cells[pos.row][pos.col];
+            let index = self.index(pos);
+            let cell = &mut self.cells[index];
             match cell.state() {
                 CellState::Revealed => ClickResult::Invalid,
-                CellState::Flagged => {
-                    cell.set_state(CellState::Hidden);
-                    ClickResult::Continue
-                }
-                CellState::Hidden => {
-                    cell.set_state(CellState::Flagged);
+                // 踩中后自动标记的地雷保持锁定
+                CellState::Flagged if cell.is_triggered() => ClickResult::Invalid,
+                // 旗子数按 1..=上限、负雷模式下再按 -1..=-上限 循环，最后取消标记；
+                // 经典规则下就是插旗/拔旗
+                CellState::Flagged | CellState::Hidden => {
+                    let max = self.board_config.mines_per_cell as i8;
+                    let negative = self.board_config.negative_mines > 0;
+                    let previous = cell.flag_count();
+                    let next = match previous {
+                        flags if flags >= 0 && flags < max => flags + 1,
+                        flags if flags >= 0 && negative => -1,
+                        flags if flags < 0 && flags > -max => flags - 1,
+                        _ => 0,
+                    };
+                    cell.set_flag_count(next);
+                    self.emit(BoardEvent::FlagToggled {
+                        pos,
+                        flags: next,
+                        previous,
+                    });
                     ClickResult::Continue
                 }
             }
@@ -333,19 +927,31 @@ impl Board {
         pos.row < height && pos.col < width
     }
 
+    // 所有不含地雷的格子都已翻开
     pub fn check_victory(&self) -> bool {
-        let mine_count = self.board_config.mine_count;
         let board_size = &self.board_config.board_size;
-        self.revealed_count + mine_count == board_size.cell_count()
+        self.revealed_count + self.mine_cell_count == board_size.cell_count()
     }
 
-    // 为游戏引擎提供的公开方法
+    /// 格子的真实内容，不论是否翻开；库外通过 spoiler() 读取，只需要可见信息时使用 get_visible_cell 或 cells
+    pub(crate) fn cell_content(&self, pos: Position) -> CellContent {
+        self.cells[self.index(pos)].content()
+    }
+
+    // 为游戏引擎提供的公开方法，保留以兼容旧代码
+    /// 格子的真实内容，不论是否翻开
+    #[deprecated(note = "会暴露隐藏的地雷；需要真实布局时使用 spoiler().content()")]
     pub fn get_cell_content(&self, pos: Position) -> CellContent {
-        self.cells[pos.row][pos.col].content()
+        self.spoiler().content(pos)
     }
 
     pub fn get_cell_state(&self, pos: Position) -> CellState {
-        self.cells[pos.row][pos.col].state()
+        self.cells[self.index(pos)].state()
+    }
+
+    /// 格子上的旗子数，未标记时为 0，负雷旗为负数
+    pub fn get_flag_count(&self, pos: Position) -> i8 {
+        self.cells[self.index(pos)].flag_count()
     }
 
     // 公共访问方法
@@ -358,18 +964,45 @@ impl Board {
         self.mines_placed
     }
 
-    // 为测试提供的公开方法
+    pub fn get_revealed_count(&self) -> usize {
+        self.revealed_count
+    }
+
+    // 为测试提供的公开方法：在指定位置布雷并计算数字，跳过随机生成
+    // 地雷数量以传入的位置为准，保证胜利判定与布局一致；重复的位置在同一格叠加地雷
     #[cfg(test)]
-    pub fn count_mines(&self) -> usize {
-        self.cells
-            .iter()
-            .flat_map(|row| row.iter())
-            .filter(|cell| matches!(cell.content(), CellContent::Mine))
-            .count()
+    pub fn place_mines_for_test(&mut self, mines: &[Position]) {
+        self.board_config.mine_count = mines.len();
+        for &pos in mines {
+            let index = self.index(pos);
+            if !self.cells[index].is_mine() {
+                self.mine_cell_count += 1;
+            }
+            self.cells[index].add_mine();
+            self.add_mine_to_neighbours(pos, index, 1);
+        }
+        self.mines_placed = true;
     }
 
+    // 为测试提供的公开方法：在指定位置放置负雷，需在 place_mines_for_test 之后或单独调用
     #[cfg(test)]
-    pub fn get_revealed_count(&self) -> usize {
-        self.revealed_count
+    pub fn place_negative_mines_for_test(&mut self, mines: &[Position]) {
+        for &pos in mines {
+            let index = self.index(pos);
+            if !self.cells[index].is_mine() {
+                self.mine_cell_count += 1;
+            }
+            self.cells[index].add_mine();
+            self.cells[index].set_negative();
+            self.add_mine_to_neighbours(pos, index, -1);
+        }
+        self.board_config.mine_count += mines.len();
+        self.board_config.negative_mines += mines.len();
+        self.mines_placed = true;
+    }
+
+    #[cfg(test)]
+    pub fn count_mines(&self) -> usize {
+        self.cells.iter().filter(|cell| cell.is_mine()).count()
     }
 }
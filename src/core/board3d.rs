@@ -0,0 +1,295 @@
+use crate::core::{BoardSize, Cell, CellContent, CellState, ClickResult, Position};
+use crate::render::{self, BoardView};
+use crate::t;
+use rand::seq::SliceRandom;
+
+/// 三维棋盘上的位置：层、行、列
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Position3D {
+    pub layer: usize,
+    pub row: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoardSize3D {
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+}
+
+impl BoardSize3D {
+    pub fn cell_count(&self) -> usize {
+        self.width * self.height * self.depth
+    }
+
+    /// 单层的平面尺寸
+    pub fn layer_size(&self) -> BoardSize {
+        BoardSize {
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoardConfig3D {
+    pub board_size: BoardSize3D,
+    pub mine_count: usize,
+}
+
+/// 分层的三维扫雷棋盘，每个格子最多有 26 个邻居
+pub struct Board3D {
+    board_config: BoardConfig3D,
+    cells: Vec<Vec<Vec<Cell>>>,
+    mines_placed: bool,
+    revealed_count: usize,
+}
+
+fn is_valid_index(index: isize, limit: usize) -> bool {
+    index >= 0 && index < (limit as isize)
+}
+
+pub fn get_adjacent_positions_3d(
+    pos: Position3D,
+    size: BoardSize3D,
+    skip_center: bool,
+) -> Vec<Position3D> {
+    let mut positions: Vec<Position3D> = Vec::new();
+    for dl in -1..=1 {
+        for dr in -1..=1 {
+            for dc in -1..=1 {
+                if skip_center && dl == 0 && dr == 0 && dc == 0 {
+                    continue;
+                }
+
+                let new_layer = pos.layer as isize + dl;
+                let new_row = pos.row as isize + dr;
+                let new_col = pos.col as isize + dc;
+                if is_valid_index(new_layer, size.depth)
+                    && is_valid_index(new_row, size.height)
+                    && is_valid_index(new_col, size.width)
+                {
+                    positions.push(Position3D {
+                        layer: new_layer as usize,
+                        row: new_row as usize,
+                        col: new_col as usize,
+                    });
+                }
+            }
+        }
+    }
+    positions
+}
+
+impl Board3D {
+    pub fn new(board_config: BoardConfig3D) -> Self {
+        let size = board_config.board_size;
+        let cells = vec![vec![vec![Cell::new(); size.width]; size.height]; size.depth];
+        Board3D {
+            board_config,
+            cells,
+            mines_placed: false,
+            revealed_count: 0,
+        }
+    }
+
+    fn cell(&self, pos: Position3D) -> &Cell {
+        &self.cells[pos.layer][pos.row][pos.col]
+    }
+
+    fn cell_mut(&mut self, pos: Position3D) -> &mut Cell {
+        &mut self.cells[pos.layer][pos.row][pos.col]
+    }
+
+    fn ensure_mines_placed(&mut self, first_click_pos: Position3D) {
+        if !self.mines_placed {
+            self.place_mines_avoiding_first_click(first_click_pos);
+            self.calculate_numbers();
+            self.mines_placed = true;
+        }
+    }
+
+    fn place_mines_avoiding_first_click(&mut self, first_click_pos: Position3D) {
+        let size = self.board_config.board_size;
+        let mine_count = self.board_config.mine_count;
+
+        let forbidden_area = get_adjacent_positions_3d(first_click_pos, size, false);
+
+        // 所有坐标位置，排除禁区
+        let mut positions: Vec<Position3D> = Vec::new();
+        for layer in 0..size.depth {
+            for row in 0..size.height {
+                for col in 0..size.width {
+                    let pos = Position3D { layer, row, col };
+                    if !forbidden_area.contains(&pos) {
+                        positions.push(pos);
+                    }
+                }
+            }
+        }
+        if positions.len() < mine_count {
+            panic!("No enough positions to place mines!")
+        }
+        let mut rng = rand::rng();
+        positions.shuffle(&mut rng);
+        for &pos in positions.iter().take(mine_count) {
+            self.cell_mut(pos).set_content(CellContent::Mine(1));
+        }
+    }
+
+    fn calculate_numbers(&mut self) {
+        let size = self.board_config.board_size;
+        for layer in 0..size.depth {
+            for row in 0..size.height {
+                for col in 0..size.width {
+                    let pos = Position3D { layer, row, col };
+                    if self.cell(pos).is_mine() {
+                        continue;
+                    }
+                    let mines_count = get_adjacent_positions_3d(pos, size, true)
+                        .into_iter()
+                        .filter(|&adj| self.cell(adj).is_mine())
+                        .count() as i16;
+                    self.cell_mut(pos)
+                        .set_content(CellContent::Number(mines_count));
+                }
+            }
+        }
+    }
+
+    /// 打印单层棋盘，与二维棋盘使用相同的格式
+    pub fn print_layer(&self, layer: usize) {
+        let size = self.board_config.board_size;
+        println!("{}", t!("cube.layer", layer, size.depth - 1));
+        render::print(&BoardView::grid(size.layer_size(), |pos: Position| {
+            self.cells[layer][pos.row][pos.col].clone()
+        }));
+    }
+
+    // 左键点击处理
+    pub fn left_click(&mut self, pos: Position3D) -> ClickResult {
+        if !self.is_valid_position(pos) {
+            return ClickResult::Invalid;
+        }
+
+        self.ensure_mines_placed(pos);
+        let cell = self.cell_mut(pos);
+        match cell.state() {
+            CellState::Revealed | CellState::Flagged => ClickResult::Invalid,
+            CellState::Hidden => {
+                cell.set_state(CellState::Revealed);
+                match cell.content() {
+                    CellContent::Mine(_) => ClickResult::GameOver,
+                    CellContent::Number(number) => {
+                        self.revealed_count += 1;
+
+                        if number == 0 {
+                            self.auto_reveal_flood_fill(pos);
+                        }
+
+                        if self.check_victory() {
+                            ClickResult::Victory
+                        } else {
+                            ClickResult::Continue
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // 与二维棋盘相同的队列洪水填充，跨层展开
+    fn auto_reveal_flood_fill(&mut self, start_pos: Position3D) {
+        use std::collections::VecDeque;
+
+        let size = self.board_config.board_size;
+        let mut queue = VecDeque::new();
+        queue.push_back(start_pos);
+
+        while let Some(pos) = queue.pop_front() {
+            for adj_pos in get_adjacent_positions_3d(pos, size, true) {
+                let cell = self.cell_mut(adj_pos);
+
+                if matches!(cell.state(), CellState::Hidden) && !cell.is_mine() {
+                    cell.set_state(CellState::Revealed);
+                    self.revealed_count += 1;
+
+                    if let CellContent::Number(0) = self.cell(adj_pos).content() {
+                        queue.push_back(adj_pos);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn reveal_all_mines(&mut self) {
+        for cell in self.cells.iter_mut().flatten().flatten() {
+            if cell.is_mine() {
+                cell.set_state(CellState::Revealed);
+            }
+        }
+    }
+
+    // 右键点击处理
+    pub fn right_click(&mut self, pos: Position3D) -> ClickResult {
+        if !self.is_valid_position(pos) {
+            return ClickResult::Invalid;
+        }
+        let cell = self.cell_mut(pos);
+        match cell.state() {
+            CellState::Revealed => ClickResult::Invalid,
+            CellState::Flagged => {
+                cell.set_state(CellState::Hidden);
+                ClickResult::Continue
+            }
+            CellState::Hidden => {
+                cell.set_state(CellState::Flagged);
+                ClickResult::Continue
+            }
+        }
+    }
+
+    pub fn is_valid_position(&self, pos: Position3D) -> bool {
+        let size = self.board_config.board_size;
+        pos.layer < size.depth && pos.row < size.height && pos.col < size.width
+    }
+
+    pub fn check_victory(&self) -> bool {
+        self.revealed_count + self.board_config.mine_count
+            == self.board_config.board_size.cell_count()
+    }
+
+    /// 格子的真实内容；库外通过 spoiler() 读取
+    pub(crate) fn get_cell_content(&self, pos: Position3D) -> CellContent {
+        self.cell(pos).content()
+    }
+
+    pub fn get_cell_state(&self, pos: Position3D) -> CellState {
+        self.cell(pos).state()
+    }
+
+    pub fn get_board_config(&self) -> &BoardConfig3D {
+        &self.board_config
+    }
+
+    pub fn are_mines_placed(&self) -> bool {
+        self.mines_placed
+    }
+
+    // 为测试提供的公开方法：在指定位置布雷并计算数字，跳过随机生成
+    #[cfg(test)]
+    pub fn place_mines_for_test(&mut self, mines: &[Position3D]) {
+        self.board_config.mine_count = mines.len();
+        for &pos in mines {
+            self.cell_mut(pos).set_content(CellContent::Mine(1));
+        }
+        self.calculate_numbers();
+        self.mines_placed = true;
+    }
+
+    #[cfg(test)]
+    pub fn get_revealed_count(&self) -> usize {
+        self.revealed_count
+    }
+}
@@ -1,7 +1,7 @@
 #[derive(Clone, Copy, Debug)]
 pub enum CellContent {
-    Mine,
-    Number(u8), // 周围地雷的数量， 0-8
+    Mine(i8),    // 格子中的地雷数量，经典规则下总是 1，负雷为负数
+    Number(i16), // 周围地雷的代数和，范围取决于相邻规则、每格地雷上限和负雷（默认 0-8）
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -15,6 +15,7 @@ pub enum CellState {
 pub struct Cell {
     content: CellContent,
     state: CellState,
+    flag_count: i8, // 标记状态下插的旗子数，负雷旗为负数，其他状态为 0
 }
 
 impl Cell {
@@ -22,6 +23,7 @@ impl Cell {
         Cell {
             content: CellContent::Number(0), // 默认内容为数字0
             state: CellState::Hidden,        // 默认状态为隐藏
+            flag_count: 0,
         }
     }
 
@@ -39,10 +41,38 @@ impl Cell {
 
     pub fn set_state(&mut self, state: CellState) {
         self.state = state;
+        self.flag_count = match state {
+            CellState::Flagged if self.flag_count == 0 => 1,
+            CellState::Flagged => self.flag_count,
+            _ => 0,
+        };
+    }
+
+    /// 旗子数量：未标记时为 0，普通标记为 1，负雷旗为负数
+    pub fn flag_count(&self) -> i8 {
+        self.flag_count
+    }
+
+    /// 设置旗子数量，0 表示取消标记
+    pub fn set_flag_count(&mut self, count: i8) {
+        if count == 0 {
+            self.set_state(CellState::Hidden);
+        } else {
+            self.state = CellState::Flagged;
+            self.flag_count = count;
+        }
+    }
+
+    /// 格子中的地雷数量，负雷为负数，不是地雷时为 0
+    pub fn mine_count(&self) -> i8 {
+        match self.content {
+            CellContent::Mine(count) => count,
+            CellContent::Number(_) => 0,
+        }
     }
 
     pub fn is_mine(&self) -> bool {
-        matches!(self.content, CellContent::Mine)
+        matches!(self.content, CellContent::Mine(_))
     }
 }
 
@@ -0,0 +1,60 @@
+//! 游戏引擎使用的时钟
+//!
+//! 计时模式由引擎自己读取时钟判断剩余时间，而不是依赖命令行循环计数；
+//! 测试中使用可以手动拨动的时钟。
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// 从时钟创建时刻起经过的时间
+pub trait Clock {
+    fn elapsed(&self) -> Duration;
+}
+
+/// 基于系统单调时钟的实现
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        SystemClock {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// 手动拨动的时钟；克隆出的副本共享同一个时间，交给引擎后仍可在外部推进
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    elapsed: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed.set(self.elapsed.get() + duration);
+    }
+}
+
+impl Clock for ManualClock {
+    fn elapsed(&self) -> Duration {
+        self.elapsed.get()
+    }
+}
@@ -0,0 +1,226 @@
+//! 多人合作模式的共享棋盘
+//!
+//! 多名玩家同时操作同一块棋盘：翻开和标记对所有人可见，记录每个格子由谁翻开或标记，
+//! 任何人踩雷都是全队失败。
+//!
+//! 并发冲突用版本号处理：每次修改棋盘都会让版本号加一，并记下被改动格子的版本号。
+//! 玩家提交操作时附带自己最后看到的版本号；如果目标格子在那之后被其他玩家改动过，
+//! 这次操作就作为冲突被拒绝，而不是在对方的结果上再操作一次（例如把刚插上的旗拔掉）。
+
+use crate::core::{Board, BoardConfig, CellState, ClickResult, Position};
+
+/// 最多的玩家人数
+pub const MAX_PLAYERS: usize = 4;
+
+/// 每位玩家翻开和标记的格子使用的颜色（亮红、亮蓝、亮绿、亮黄）
+pub const PLAYER_COLORS: [&str; MAX_PLAYERS] = ["\x1b[91m", "\x1b[94m", "\x1b[92m", "\x1b[93m"];
+
+/// 玩家的操作
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoopAction {
+    /// 只移动光标，不改动棋盘
+    Cursor(Position),
+    Reveal(Position),
+    Flag(Position),
+}
+
+impl CoopAction {
+    pub fn position(self) -> Position {
+        match self {
+            CoopAction::Cursor(pos) | CoopAction::Reveal(pos) | CoopAction::Flag(pos) => pos,
+        }
+    }
+}
+
+/// 一次操作的结果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoopEvent {
+    Moved,
+    /// 翻开了若干格子
+    Revealed {
+        cells: usize,
+    },
+    Flagged,
+    Unflagged,
+    /// 目标格子在玩家看到的版本之后被 by 改动过，操作未执行
+    Conflict {
+        by: usize,
+    },
+    /// 该玩家踩雷，全队失败
+    Lost {
+        player: usize,
+    },
+    Won,
+    Invalid,
+}
+
+/// 比赛结果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoopOutcome {
+    Won,
+    Lost { player: usize },
+}
+
+// 格子最后一次被改动的记录
+#[derive(Clone, Copy)]
+struct Change {
+    player: usize,
+    revision: u64,
+}
+
+pub struct CoopSession {
+    board: Board,
+    cursors: Vec<Option<Position>>,
+    // 下标为 row * width + col
+    changes: Vec<Option<Change>>,
+    revision: u64,
+    outcome: Option<CoopOutcome>,
+}
+
+impl CoopSession {
+    pub fn new(config: BoardConfig, players: usize) -> Self {
+        Self::from_board(Board::new(config), players)
+    }
+
+    pub fn with_seed(config: BoardConfig, players: usize, seed: u64) -> Self {
+        Self::from_board(Board::with_seed(config, seed), players)
+    }
+
+    fn from_board(board: Board, players: usize) -> Self {
+        assert!(
+            (1..=MAX_PLAYERS).contains(&players),
+            "cooperative mode supports 1 to {} players",
+            MAX_PLAYERS
+        );
+        CoopSession {
+            changes: vec![None; board.get_board_config().board_size.cell_count()],
+            board,
+            cursors: vec![None; players],
+            revision: 0,
+            outcome: None,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn player_count(&self) -> usize {
+        self.cursors.len()
+    }
+
+    /// 当前版本号，每次改动棋盘后加一
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    pub fn cursor(&self, player: usize) -> Option<Position> {
+        self.cursors[player]
+    }
+
+    /// 翻开或标记该格子的玩家；隐藏的格子返回 None
+    pub fn actor(&self, pos: Position) -> Option<usize> {
+        if self.board.get_cell_state(pos) == CellState::Hidden {
+            return None;
+        }
+        self.changes[self.index(pos)].map(|change| change.player)
+    }
+
+    pub fn outcome(&self) -> Option<CoopOutcome> {
+        self.outcome
+    }
+
+    // 为测试提供的公开方法：在指定位置布雷
+    #[cfg(test)]
+    pub fn place_mines_for_test(&mut self, mines: &[Position]) {
+        self.board.place_mines_for_test(mines);
+    }
+
+    fn index(&self, pos: Position) -> usize {
+        pos.row * self.board.get_board_config().board_size.width + pos.col
+    }
+
+    /// 执行玩家的操作；seen 是玩家提交操作时看到的版本号
+    pub fn apply(&mut self, player: usize, action: CoopAction, seen: u64) -> CoopEvent {
+        let pos = action.position();
+        let size = self.board.get_board_config().board_size;
+        if self.outcome.is_some() || pos.row >= size.height || pos.col >= size.width {
+            return CoopEvent::Invalid;
+        }
+        self.cursors[player] = Some(pos);
+        if action == CoopAction::Cursor(pos) {
+            return CoopEvent::Moved;
+        }
+
+        if let Some(change) = self.changes[self.index(pos)]
+            && change.revision > seen
+            && change.player != player
+        {
+            return CoopEvent::Conflict { by: change.player };
+        }
+
+        if action == CoopAction::Reveal(pos) {
+            return self.reveal(player, pos);
+        }
+        if matches!(self.board.right_click(pos), ClickResult::Invalid) {
+            return CoopEvent::Invalid;
+        }
+        self.revision += 1;
+        let index = self.index(pos);
+        self.changes[index] = Some(Change {
+            player,
+            revision: self.revision,
+        });
+        match self.board.get_cell_state(pos) {
+            CellState::Flagged => CoopEvent::Flagged,
+            _ => CoopEvent::Unflagged,
+        }
+    }
+
+    fn reveal(&mut self, player: usize, pos: Position) -> CoopEvent {
+        let size = self.board.get_board_config().board_size;
+        let hidden_before: Vec<bool> = (0..size.cell_count())
+            .map(|index| {
+                let pos = Position {
+                    row: index / size.width,
+                    col: index % size.width,
+                };
+                self.board.get_cell_state(pos) != CellState::Revealed
+            })
+            .collect();
+
+        let result = self.board.left_click(pos);
+        if matches!(result, ClickResult::Invalid) {
+            return CoopEvent::Invalid;
+        }
+
+        // 这次翻开的格子都记在该玩家名下
+        self.revision += 1;
+        let mut cells = 0;
+        for (index, was_hidden) in hidden_before.into_iter().enumerate() {
+            let pos = Position {
+                row: index / size.width,
+                col: index % size.width,
+            };
+            if was_hidden && self.board.get_cell_state(pos) == CellState::Revealed {
+                self.changes[index] = Some(Change {
+                    player,
+                    revision: self.revision,
+                });
+                cells += 1;
+            }
+        }
+
+        match result {
+            ClickResult::GameOver => {
+                self.outcome = Some(CoopOutcome::Lost { player });
+                CoopEvent::Lost { player }
+            }
+            ClickResult::Victory => {
+                self.outcome = Some(CoopOutcome::Won);
+                CoopEvent::Won
+            }
+            ClickResult::Continue | ClickResult::Invalid => CoopEvent::Revealed { cells },
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! 棋盘事件和观察者
+//!
+//! 棋盘在状态改变时依次通知所有订阅的观察者，界面、录像、统计和提示音等可以各自订阅，
+//! 不必在每次点击后比较整个棋盘。一次点击产生的事件按发生顺序发出：
+//! 首次点击先发 `MinesPlaced`，然后是每个翻开的格子，最后是 `MineTriggered` 或 `GameWon`。
+//!
+//! 观察者需要是 `Send`，以便棋盘可以在线程间移动（例如批量环境）。
+
+use crate::core::{CellContent, Position};
+
+#[derive(Clone, Copy, Debug)]
+pub enum BoardEvent {
+    /// 首次点击后布好了地雷，首次点击的格子及其周围没有地雷
+    MinesPlaced { first_click: Position },
+    /// 格子被翻开，包括展开的格子和结束时显示的地雷
+    CellRevealed { pos: Position, content: CellContent },
+    /// 旗子数改变，flags 为新的旗子数，0 表示取消标记；previous 为改变前的旗子数
+    FlagToggled {
+        pos: Position,
+        flags: i8,
+        previous: i8,
+    },
+    /// 翻开了地雷
+    MineTriggered { pos: Position },
+    /// 所有安全格子都已翻开
+    GameWon,
+}
+
+pub trait BoardObserver: Send {
+    fn on_event(&mut self, event: &BoardEvent);
+}
+
+impl<F: FnMut(&BoardEvent) + Send> BoardObserver for F {
+    fn on_event(&mut self, event: &BoardEvent) {
+        self(event)
+    }
+}
+
+/// 订阅的编号，用于取消订阅
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObserverId(usize);
+
+/// 按订阅顺序保存的观察者
+#[derive(Default)]
+pub(crate) struct Observers {
+    next_id: usize,
+    observers: Vec<(ObserverId, Box<dyn BoardObserver>)>,
+}
+
+impl Observers {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+
+    pub(crate) fn add(&mut self, observer: Box<dyn BoardObserver>) -> ObserverId {
+        let id = ObserverId(self.next_id);
+        self.next_id += 1;
+        self.observers.push((id, observer));
+        id
+    }
+
+    pub(crate) fn remove(&mut self, id: ObserverId) -> bool {
+        let before = self.observers.len();
+        self.observers.retain(|(observer_id, _)| *observer_id != id);
+        self.observers.len() != before
+    }
+
+    pub(crate) fn emit(&mut self, event: &BoardEvent) {
+        for (_, observer) in &mut self.observers {
+            observer.on_event(event);
+        }
+    }
+}
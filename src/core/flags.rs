@@ -0,0 +1,182 @@
+//! 双人对战的 Minesweeper Flags 规则
+//!
+//! 两名玩家轮流点击同一块棋盘：点中地雷即认领它得一分并继续行动，
+//! 点中安全格子则照常翻开并交换回合；先认领过半数地雷的玩家获胜。
+
+use crate::core::board::print_square_grid_with_colors;
+use crate::core::{Board, BoardConfig, ClickResult, Position, analyze};
+use crate::render::RESET;
+use crate::t;
+
+/// 玩家人数
+pub const PLAYER_COUNT: usize = 2;
+
+/// 每位玩家认领的地雷使用的颜色（亮红、亮蓝）
+pub const PLAYER_COLORS: [&str; PLAYER_COUNT] = ["\x1b[91m", "\x1b[94m"];
+
+/// 一次点击的结果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlagsEvent {
+    /// 当前玩家认领了一颗地雷，并继续行动
+    Claimed {
+        player: usize,
+        score: u32,
+    },
+    /// 翻开了安全格子，轮到下一位玩家
+    Revealed {
+        next: usize,
+    },
+    Won {
+        player: usize,
+    },
+    /// 地雷被平分完毕（只在地雷数为偶数时可能出现）
+    Draw,
+    Invalid,
+}
+
+pub struct FlagsMatch {
+    board: Board,
+    current: usize,
+    scores: [u32; PLAYER_COUNT],
+    // 每个格子由哪位玩家认领，下标为 row * width + col
+    owners: Vec<Option<usize>>,
+    finished: bool,
+}
+
+impl FlagsMatch {
+    pub fn new(config: BoardConfig) -> Self {
+        Self::from_board(Board::new(config))
+    }
+
+    pub fn with_seed(config: BoardConfig, seed: u64) -> Self {
+        Self::from_board(Board::with_seed(config, seed))
+    }
+
+    fn from_board(board: Board) -> Self {
+        let config = board.get_board_config();
+        assert!(
+            config.mines_per_cell == 1 && config.negative_mines == 0,
+            "Minesweeper Flags uses classic single mines"
+        );
+        FlagsMatch {
+            owners: vec![None; config.board_size.cell_count()],
+            board,
+            current: 0,
+            scores: [0; PLAYER_COUNT],
+            finished: false,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn current_player(&self) -> usize {
+        self.current
+    }
+
+    pub fn scores(&self) -> [u32; PLAYER_COUNT] {
+        self.scores
+    }
+
+    /// 获胜所需的地雷数：超过总数的一半
+    pub fn majority(&self) -> u32 {
+        self.board.get_board_config().mine_count as u32 / 2 + 1
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn owner(&self, pos: Position) -> Option<usize> {
+        self.owners[self.index(pos)]
+    }
+
+    // 为测试提供的公开方法：在指定位置布雷
+    #[cfg(test)]
+    pub fn place_mines_for_test(&mut self, mines: &[Position]) {
+        self.board.place_mines_for_test(mines);
+    }
+
+    fn index(&self, pos: Position) -> usize {
+        pos.row * self.board.get_board_config().board_size.width + pos.col
+    }
+
+    pub fn click(&mut self, pos: Position) -> FlagsEvent {
+        if self.finished {
+            return FlagsEvent::Invalid;
+        }
+
+        match self.board.left_click(pos) {
+            ClickResult::Invalid => FlagsEvent::Invalid,
+            ClickResult::GameOver => {
+                let player = self.current;
+                let index = self.index(pos);
+                self.owners[index] = Some(player);
+                self.scores[player] += 1;
+
+                let claimed: u32 = self.scores.iter().sum();
+                if self.scores[player] >= self.majority() {
+                    self.finished = true;
+                    FlagsEvent::Won { player }
+                } else if claimed as usize == self.board.get_board_config().mine_count {
+                    self.finished = true;
+                    FlagsEvent::Draw
+                } else {
+                    FlagsEvent::Claimed {
+                        player,
+                        score: self.scores[player],
+                    }
+                }
+            }
+            // 安全格子全部翻开后剩下的都是地雷，比赛仍然继续
+            ClickResult::Continue | ClickResult::Victory => {
+                self.current = (self.current + 1) % PLAYER_COUNT;
+                FlagsEvent::Revealed { next: self.current }
+            }
+        }
+    }
+
+    /// 电脑玩家的选择：只看可见信息，优先点必然是地雷的格子，否则点最可能是地雷的格子
+    pub fn computer_move(&self) -> Option<Position> {
+        let analysis = analyze(&self.board);
+        analysis
+            .known_mines()
+            .first()
+            .copied()
+            .or_else(|| analysis.most_likely_mine())
+    }
+
+    /// 打印棋盘，认领的地雷使用所属玩家的颜色
+    pub fn print_board(&self) {
+        let size = self.board.get_board_config().board_size;
+        print_square_grid_with_colors(
+            size,
+            |pos| self.board.cell_at(pos),
+            |pos| self.owner(pos).map(|player| PLAYER_COLORS[player]),
+        );
+    }
+
+    /// 打印记分牌，当前行动的玩家以 ▶ 标出
+    pub fn print_scoreboard(&self, names: &[String; PLAYER_COUNT]) {
+        println!("{}", t!("flags.scoreboard", self.majority()));
+        for (player, name) in names.iter().enumerate() {
+            let marker = if player == self.current && !self.finished {
+                '▶'
+            } else {
+                ' '
+            };
+            println!(
+                "{}",
+                t!(
+                    "flags.score_line",
+                    marker,
+                    PLAYER_COLORS[player],
+                    name,
+                    RESET,
+                    self.scores[player]
+                )
+            );
+        }
+    }
+}
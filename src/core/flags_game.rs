@@ -0,0 +1,207 @@
+use crate::core::flags::{PLAYER_COLORS, PLAYER_COUNT};
+use crate::core::{BoardConfig, FlagsEvent, FlagsMatch, Position};
+use crate::render::RESET;
+use crate::t;
+use std::io::{self, Write};
+
+/// Minesweeper Flags 的命令行控制器，两名玩家在同一个终端轮流输入
+pub struct FlagsGame {
+    flags: FlagsMatch,
+    names: [String; PLAYER_COUNT],
+    // 由电脑控制的玩家
+    computer: Option<usize>,
+}
+
+impl FlagsGame {
+    /// 两名玩家都由人操作
+    pub fn new(config: BoardConfig) -> Self {
+        FlagsGame {
+            flags: FlagsMatch::new(config),
+            names: [
+                t!("default.player_number", 1),
+                t!("default.player_number", 2),
+            ],
+            computer: None,
+        }
+    }
+
+    /// 玩家 2 由电脑操作
+    pub fn against_computer(config: BoardConfig) -> Self {
+        FlagsGame {
+            flags: FlagsMatch::new(config),
+            names: [
+                t!("default.player_name").to_string(),
+                t!("flags.computer").to_string(),
+            ],
+            computer: Some(1),
+        }
+    }
+
+    pub fn run(&mut self) {
+        self.print_welcome();
+        self.print_help();
+
+        while !self.flags.is_finished() {
+            self.print_board();
+
+            let player = self.flags.current_player();
+            if self.computer == Some(player) {
+                self.play_computer_turn();
+                continue;
+            }
+
+            match self.get_user_input() {
+                Some(command) => {
+                    if !self.process_command(&command) {
+                        break; // 用户选择退出
+                    }
+                }
+                None => {
+                    println!("{}", t!("game.invalid_input"));
+                    continue;
+                }
+            }
+        }
+
+        self.print_game_over();
+    }
+
+    fn print_welcome(&self) {
+        println!("{}", t!("flags.welcome"));
+        let config = self.flags.board().get_board_config();
+        println!(
+            "{}",
+            t!(
+                "game.config",
+                config.board_size.width,
+                config.board_size.height,
+                config.mine_count
+            )
+        );
+        println!("{}", t!("flags.goal_hint"));
+        println!("{}", t!("flags.majority_hint", self.flags.majority()));
+        println!();
+    }
+
+    fn print_help(&self) {
+        println!("{}", t!("help.title"));
+        println!("{}", t!("flags.help_click"));
+        println!("{}", t!("help.help"));
+        println!("{}", t!("help.quit"));
+        println!("{}", t!("help.coordinates"));
+        println!();
+    }
+
+    fn print_board(&self) {
+        println!("{}", t!("game.board_title"));
+        self.flags.print_board();
+        self.flags.print_scoreboard(&self.names);
+        println!();
+    }
+
+    fn player_name(&self, player: usize) -> String {
+        format!("{}{}{}", PLAYER_COLORS[player], self.names[player], RESET)
+    }
+
+    fn get_user_input(&self) -> Option<String> {
+        print!(
+            "{} {}",
+            self.player_name(self.flags.current_player()),
+            t!("game.input_prompt")
+        );
+        io::stdout().flush().ok()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).ok()?;
+
+        Some(input.trim().to_lowercase())
+    }
+
+    fn process_command(&mut self, command: &str) -> bool {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+
+        if parts.is_empty() {
+            return true;
+        }
+
+        match parts[0] {
+            "help" => {
+                self.print_help();
+            }
+            "quit" | "exit" => {
+                println!("{}", t!("game.bye"));
+                return false;
+            }
+            "click" => match self.parse_coordinates(&parts[1..]) {
+                Some(pos) => self.handle_click(pos),
+                None => println!("{}", t!("game.usage_click")),
+            },
+            _ => {
+                println!("{}", t!("game.unknown_command", parts[0]));
+            }
+        }
+
+        true
+    }
+
+    fn parse_coordinates(&self, coords: &[&str]) -> Option<Position> {
+        let [row, col] = coords else {
+            return None;
+        };
+        let pos = Position {
+            row: row.parse().ok()?,
+            col: col.parse().ok()?,
+        };
+
+        let size = self.flags.board().get_board_config().board_size;
+        if pos.row >= size.height || pos.col >= size.width {
+            println!(
+                "{}",
+                t!("game.out_of_range", size.height - 1, size.width - 1)
+            );
+            return None;
+        }
+
+        Some(pos)
+    }
+
+    fn play_computer_turn(&mut self) {
+        let Some(pos) = self.flags.computer_move() else {
+            return;
+        };
+        println!("{}", t!("flags.computer_click", pos.row, pos.col));
+        self.handle_click(pos);
+    }
+
+    fn handle_click(&mut self, pos: Position) {
+        match self.flags.click(pos) {
+            FlagsEvent::Claimed { player, score } => {
+                println!("{}", t!("flags.claimed", self.player_name(player), score));
+            }
+            FlagsEvent::Revealed { next } => {
+                println!("{}", t!("flags.revealed", self.player_name(next)));
+            }
+            // 结束信息统一在 print_game_over 中显示
+            FlagsEvent::Won { .. } | FlagsEvent::Draw => {}
+            FlagsEvent::Invalid => {
+                println!("{}", t!("flags.invalid"));
+            }
+        }
+    }
+
+    fn print_game_over(&self) {
+        self.print_board();
+
+        let [first, second] = self.flags.scores();
+        if !self.flags.is_finished() {
+            println!("{}", t!("flags.aborted"));
+        } else if first == second {
+            println!("{}", t!("over.draw"));
+        } else {
+            let winner = if first > second { 0 } else { 1 };
+            println!("{}", t!("flags.winner", self.player_name(winner)));
+        }
+
+        println!("{}", t!("over.thanks"));
+    }
+}
@@ -1,24 +1,88 @@
 use crate::config::{Difficulty, difficulty_to_board_config};
-use crate::core::{Board, ClickResult, Position};
+use crate::core::{
+    Board, BoardConfig, BoardObserver, BoardShape, BoardSize, ClickDetails, ClickResult,
+    Neighbourhood, ObserverId, Position,
+};
+use crate::export::{self, ExportOptions};
+use crate::render::{self, BoardView, ColorDepth, GlyphSet, Palette, RenderMode, Viewport};
+use crate::t;
 use std::io::{self, Write};
 
+/// 生命计数：踩雷时扣除一条生命，生命耗尽才算失败
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lives {
+    total: u32,
+    spent: u32,
+}
+
+impl Lives {
+    /// 经典规则相当于只有一条生命
+    pub fn new(total: u32) -> Self {
+        assert!(total > 0, "生命数至少为 1");
+        Lives { total, spent: 0 }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    pub fn spent(&self) -> u32 {
+        self.spent
+    }
+
+    pub fn remaining(&self) -> u32 {
+        self.total - self.spent
+    }
+
+    /// 扣除一条生命，返回是否还活着
+    pub fn lose_one(&mut self) -> bool {
+        self.spent = (self.spent + 1).min(self.total);
+        self.remaining() > 0
+    }
+}
+
 pub struct Game {
     board: Board,
+    lives: Lives,
     game_over: bool,
     victory: bool,
+    viewport: Viewport,
+    // 操作视口外的格子时是否自动移动视口
+    follow: bool,
 }
 
 impl Game {
     pub fn new(difficulty: Difficulty) -> Self {
-        let config = difficulty_to_board_config(difficulty);
+        Self::with_config(difficulty_to_board_config(difficulty))
+    }
+
+    /// 使用自定义棋盘配置（例如六边形棋盘）创建游戏
+    pub fn with_config(config: BoardConfig) -> Self {
+        Self::with_lives(config, 1)
+    }
+
+    /// 生命模式：踩到地雷会自动标记并扣除一条生命，生命耗尽才结束游戏
+    pub fn with_lives(config: BoardConfig, lives: u32) -> Self {
         let board = Board::new(config);
         Game {
             board,
+            lives: Lives::new(lives),
             game_over: false,
             victory: false,
+            viewport: Viewport::detect(config.shape),
+            follow: true,
         }
     }
 
+    pub fn lives(&self) -> Lives {
+        self.lives
+    }
+
+    /// 订阅棋盘事件，例如用于录像、统计或提示音
+    pub fn subscribe(&mut self, observer: impl BoardObserver + 'static) -> ObserverId {
+        self.board.subscribe(observer)
+    }
+
     pub fn run(&mut self) {
         self.print_welcome();
         self.print_help();
@@ -34,7 +98,7 @@ impl Game {
                     }
                 }
                 None => {
-                    println!("❌ 无效输入，请重试");
+                    println!("{}", t!("game.invalid_input"));
                     continue;
                 }
             }
@@ -44,54 +108,152 @@ impl Game {
     }
 
     fn print_welcome(&self) {
-        println!("🎮 欢迎来到命令行扫雷游戏！");
+        println!("{}", t!("game.welcome"));
         let config = self.board.get_board_config();
         println!(
-            "📏 游戏配置: {}x{}, {} 个地雷",
-            config.board_size.width, config.board_size.height, config.mine_count
+            "{}",
+            t!(
+                "game.config",
+                config.board_size.width,
+                config.board_size.height,
+                config.mine_count
+            )
         );
+        if config.shape == BoardShape::Square && config.neighbourhood != Neighbourhood::Moore {
+            println!(
+                "{}",
+                t!(
+                    "game.neighbourhood_hint",
+                    config.neighbourhood.label(),
+                    config.max_adjacent_mines()
+                )
+            );
+        }
+        if config.mines_per_cell > 1 {
+            println!(
+                "{}",
+                t!(
+                    "game.multi_mine_hint",
+                    config.mines_per_cell,
+                    config.max_adjacent_mines()
+                )
+            );
+        }
+        if config.negative_mines > 0 {
+            println!("{}", t!("game.negative_mine_hint", config.negative_mines));
+        }
+        if config.max_adjacent_mines() >= 10 {
+            println!("{}", t!("game.letters_hint"));
+        }
+        if self.lives.total() > 1 {
+            println!("{}", t!("game.lives_hint", self.lives.total()));
+        }
         println!();
     }
 
     fn print_help(&self) {
-        println!("📋 游戏指令：");
-        println!("  click <行> <列>   - 左键点击格子 (例: click 3 5)");
-        println!("  flag <行> <列>    - 右键标记/取消标记 (例: flag 2 4)");
-        println!("  help              - 显示帮助信息");
-        println!("  quit              - 退出游戏");
-        println!("  💡 坐标从0开始计算");
+        println!("{}", t!("help.title"));
+        println!("{}", t!("help.click"));
+        println!("{}", t!("help.flag"));
+        if self.board.get_board_config().shape == BoardShape::Hex {
+            println!("{}", t!("help.hex_click"));
+            println!("{}", t!("help.hex_flag"));
+        }
+        let mines_per_cell = self.board.get_board_config().mines_per_cell;
+        if mines_per_cell > 1 {
+            println!("{}", t!("help.multi_flag", mines_per_cell));
+        }
+        if self.board.get_board_config().negative_mines > 0 {
+            println!("{}", t!("help.negative_flag"));
+        }
+        println!("{}", t!("help.pan"));
+        println!("{}", t!("help.goto"));
+        println!("{}", t!("help.follow"));
+        println!("{}", t!("help.view"));
+        println!("{}", t!("help.read"));
+        println!("{}", t!("help.screenshot"));
+        println!("{}", t!("help.theme"));
+        println!("{}", t!("help.help"));
+        println!("{}", t!("help.quit"));
+        println!("{}", t!("help.coordinates"));
         println!();
     }
 
     fn print_board(&self) {
-        println!("🗺️ 当前棋盘状态：");
-        // 复用 Board 的统一人类友好打印
-        self.board.print_debug();
+        // 无障碍模式下每回合只朗读统计，需要时用 row、column、cell 查看棋盘
+        if accessible() && !self.game_over {
+            println!("{}", render::summary(&self.board));
+            return;
+        }
+        println!("{}", t!("game.board_title"));
+        let size = self.board.get_board_config().board_size;
+        if self.viewport.covers(size) {
+            // 复用 Board 的统一人类友好打印
+            self.board.print_debug();
+        } else {
+            self.print_viewport(size);
+        }
         println!();
     }
 
+    // 棋盘放不进视口时只打印视口内的格子，并用小地图显示视口在整个棋盘上的位置
+    fn print_viewport(&self, size: BoardSize) {
+        let (rows, cols) = (self.viewport.rows(size), self.viewport.cols(size));
+        println!(
+            "{}",
+            t!(
+                "viewport.title",
+                rows.start,
+                rows.end - 1,
+                cols.start,
+                cols.end - 1,
+                size.height,
+                size.width
+            )
+        );
+        render::print(&BoardView::of(&self.board).with_viewport(&self.viewport));
+
+        let (lines, block_width, block_height) = self.viewport.minimap(&BoardView::of(&self.board));
+        println!("{}", t!("viewport.minimap", block_width, block_height));
+        for line in lines {
+            println!("  {}", line);
+        }
+    }
+
     fn print_status(&self) {
         if self.victory {
-            println!("🎉 恭喜你！游戏胜利！");
+            println!("{}", t!("status.won"));
         } else if self.game_over {
-            println!("💥 游戏结束！你踩到了地雷！");
+            println!("{}", t!("status.lost"));
+        } else if self.lives.total() > 1 {
+            println!(
+                "{}",
+                t!(
+                    "status.playing_lives",
+                    self.board.remaining_mines(),
+                    self.lives.remaining(),
+                    self.lives.total()
+                )
+            );
         } else {
-            println!("🎯 继续游戏...");
+            println!("{}", t!("status.playing", self.board.remaining_mines()));
         }
     }
 
     fn get_user_input(&self) -> Option<String> {
-        print!("请输入指令: ");
+        print!("{}", t!("game.input_prompt"));
         io::stdout().flush().ok()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input).ok()?;
 
-        Some(input.trim().to_lowercase())
+        Some(input.trim().to_string())
     }
 
     fn process_command(&mut self, command: &str) -> bool {
-        let parts: Vec<&str> = command.split_whitespace().collect();
+        // 指令不区分大小写，文件名保持原样
+        let lowered = command.to_lowercase();
+        let parts: Vec<&str> = lowered.split_whitespace().collect();
 
         if parts.is_empty() {
             return true;
@@ -102,57 +264,257 @@ impl Game {
                 self.print_help();
             }
             "quit" | "exit" => {
-                println!("👋 再见！");
+                println!("{}", t!("game.bye"));
                 return false;
             }
+            "screenshot" => {
+                let original: Vec<&str> = command.split_whitespace().collect();
+                self.handle_screenshot(&original[1..]);
+            }
+            "theme" => {
+                self.handle_theme(&parts[1..]);
+            }
+            "row" | "column" | "col" | "cell" => {
+                self.handle_read(parts[0], &parts[1..]);
+            }
+            "w" | "a" | "s" | "d" => {
+                self.handle_pan(parts[0], parts.get(1));
+            }
+            "goto" => match self.parse_coordinates(&parts[1..]) {
+                Some(pos) => {
+                    let size = self.board.get_board_config().board_size;
+                    self.viewport.center_on(pos, size);
+                }
+                None => println!("{}", t!("game.usage_goto")),
+            },
+            "follow" => {
+                self.handle_follow(&parts[1..]);
+            }
+            "view" => match parts[1..] {
+                [width, height] => match (width.parse(), height.parse()) {
+                    (Ok(width), Ok(height)) => {
+                        let size = self.board.get_board_config().board_size;
+                        self.viewport.resize(width, height, size);
+                    }
+                    _ => println!("{}", t!("game.usage_view")),
+                },
+                _ => println!("{}", t!("game.usage_view")),
+            },
+            "summary" => {
+                println!("{}", render::summary(&self.board));
+            }
             "click" => {
-                if parts.len() != 3 {
-                    println!("❌ 用法: click <行> <列>");
+                if !(2..=3).contains(&parts.len()) {
+                    println!("{}", t!("game.usage_click"));
                 } else {
                     self.handle_click(&parts[1..]);
                 }
             }
             "flag" => {
-                if parts.len() != 3 {
-                    println!("❌ 用法: flag <行> <列>");
+                if !(2..=3).contains(&parts.len()) {
+                    println!("{}", t!("game.usage_flag"));
                 } else {
                     self.handle_flag(&parts[1..]);
                 }
             }
             _ => {
-                println!("❌ 未知指令: {}. 输入 'help' 查看帮助", parts[0]);
+                println!("{}", t!("game.unknown_command", parts[0]));
             }
         }
 
         true
     }
 
+    // 按扩展名导出 SVG 或 PNG，其余参数选择是否显示地雷、标出插错的旗子和叠加概率
+    fn handle_screenshot(&self, args: &[&str]) {
+        let Some((file, flags)) = args.split_first() else {
+            println!("{}", t!("game.usage_screenshot"));
+            return;
+        };
+        let mut options = ExportOptions::default();
+        for flag in flags {
+            match flag.to_lowercase().as_str() {
+                "mines" => options.reveal_mines = true,
+                "wrong" => options.mark_wrong_flags = true,
+                "odds" => options.probabilities = true,
+                _ => {
+                    println!("{}", t!("game.usage_screenshot"));
+                    return;
+                }
+            }
+        }
+        match export::save(&self.board, file, &options) {
+            Ok(()) => println!("{}", t!("screenshot.saved", file)),
+            Err(err) => println!("{}", t!("screenshot.failed", err)),
+        }
+    }
+
+    // 不带参数时显示当前主题和可选项，否则依次应用每个名字
+    fn handle_theme(&self, names: &[&str]) {
+        let mut theme = render::theme();
+        if let Some(name) = names.iter().find(|name| !theme.apply(name)) {
+            println!("{}", t!("theme.unknown", name));
+            return;
+        }
+        if names.is_empty() {
+            println!(
+                "{}",
+                t!(
+                    "theme.options",
+                    Palette::ALL.map(Palette::name).join(", "),
+                    GlyphSet::ALL.map(GlyphSet::name).join(", "),
+                    ColorDepth::ALL.map(ColorDepth::name).join(", ")
+                )
+            );
+        } else {
+            render::set_theme(theme);
+        }
+        println!(
+            "{}",
+            t!(
+                "theme.current",
+                theme.palette.name(),
+                theme.glyphs.name(),
+                theme.depth.name()
+            )
+        );
+    }
+
+    // 按方向滚动视口，默认滚动半个视口
+    fn handle_pan(&mut self, direction: &str, step: Option<&&str>) {
+        let step = match step {
+            Some(step) => match step.parse::<isize>() {
+                Ok(step) => step,
+                Err(_) => {
+                    println!("{}", t!("viewport.bad_step"));
+                    return;
+                }
+            },
+            None if matches!(direction, "w" | "s") => (self.viewport.height / 2) as isize,
+            None => (self.viewport.width / 2) as isize,
+        };
+        let (rows, cols) = match direction {
+            "w" => (-step, 0),
+            "s" => (step, 0),
+            "a" => (0, -step),
+            _ => (0, step),
+        };
+        let size = self.board.get_board_config().board_size;
+        self.viewport.pan(rows, cols, size);
+    }
+
+    fn handle_follow(&mut self, args: &[&str]) {
+        self.follow = match args {
+            [] => !self.follow,
+            ["on"] => true,
+            ["off"] => false,
+            _ => {
+                println!("{}", t!("game.usage_follow"));
+                return;
+            }
+        };
+        if self.follow {
+            println!("{}", t!("viewport.follow_on"));
+        } else {
+            println!("{}", t!("viewport.follow_off"));
+        }
+    }
+
+    // 自动跟随时让刚操作的格子出现在视口中
+    fn follow(&mut self, pos: Position) {
+        if self.follow {
+            let size = self.board.get_board_config().board_size;
+            self.viewport.follow(pos, size);
+        }
+    }
+
+    // 朗读一行、一列或一个格子及其相邻格子
+    fn handle_read(&self, what: &str, args: &[&str]) {
+        let view = BoardView::of(&self.board);
+        let size = view.size;
+        let line = match (what, args) {
+            ("cell", [_, _] | [_]) => Some(match self.parse_coordinates(args) {
+                Some(pos) => render::describe_neighbourhood(&self.board, pos),
+                None => t!("game.bad_coordinates").to_string(),
+            }),
+            ("row", [row]) => self
+                .parse_line(row, size.height)
+                .map(|row| render::describe_row(&view, row)),
+            ("column" | "col", [col]) => self
+                .parse_line(col, size.width)
+                .map(|col| render::describe_column(&view, col)),
+            _ => Some(t!("game.usage_read").to_string()),
+        };
+        if let Some(line) = line {
+            println!("{}", line);
+        }
+    }
+
+    // 行号或列号，超出范围时与坐标使用相同的提示
+    fn parse_line(&self, text: &str, limit: usize) -> Option<usize> {
+        let Ok(index) = text.parse::<usize>() else {
+            println!("{}", t!("game.bad_coordinates"));
+            return None;
+        };
+        if index >= limit {
+            let size = self.board.get_board_config().board_size;
+            println!(
+                "{}",
+                t!("game.out_of_range", size.height - 1, size.width - 1)
+            );
+            return None;
+        }
+        Some(index)
+    }
+
+    // 无障碍模式下播报操作改变了什么
+    fn announce(details: &ClickDetails) {
+        if accessible()
+            && let Some(announcement) = render::announce(details)
+        {
+            println!("{}", announcement);
+        }
+    }
+
     fn handle_click(&mut self, coords: &[&str]) {
         match self.parse_coordinates(coords) {
             Some(pos) => {
-                let result = self.board.left_click(pos);
-                match result {
+                let details = self.board.left_click_detailed(pos);
+                Self::announce(&details);
+                self.follow(pos);
+                match details.result {
                     ClickResult::Continue => {
-                        println!("✅ 点击成功");
+                        println!("{}", t!("click.ok"));
                     }
                     ClickResult::Victory => {
-                        println!("🎉 恭喜！你赢了！");
+                        println!("{}", t!("click.victory"));
                         self.victory = true;
                         self.game_over = true;
                     }
+                    ClickResult::GameOver if self.lives.lose_one() => {
+                        self.board.flag_triggered_mine(pos);
+                        println!(
+                            "{}",
+                            t!(
+                                "click.life_lost",
+                                self.lives.remaining(),
+                                self.lives.total()
+                            )
+                        );
+                    }
                     ClickResult::GameOver => {
-                        println!("💥 糟糕！你踩到了地雷！");
+                        println!("{}", t!("click.mine"));
                         self.game_over = true;
                         // 游戏结束时自动翻开所有格子
                         self.board.reveal_all_mines();
                     }
                     ClickResult::Invalid => {
-                        println!("❌ 无效操作（格子已翻开或已标记）");
+                        println!("{}", t!("click.invalid"));
                     }
                 }
             }
             None => {
-                println!("❌ 坐标格式错误");
+                println!("{}", t!("game.bad_coordinates"));
             }
         }
     }
@@ -160,37 +522,53 @@ impl Game {
     fn handle_flag(&mut self, coords: &[&str]) {
         match self.parse_coordinates(coords) {
             Some(pos) => {
-                let result = self.board.right_click(pos);
-                match result {
+                let details = self.board.right_click_detailed(pos);
+                Self::announce(&details);
+                self.follow(pos);
+                match details.result {
                     ClickResult::Continue => {
-                        println!("🚩 标记操作成功");
+                        let config = self.board.get_board_config();
+                        if config.mines_per_cell > 1 || config.negative_mines > 0 {
+                            println!("{}", t!("flag.count", self.board.get_flag_count(pos)));
+                        } else {
+                            println!("{}", t!("flag.ok"));
+                        }
                     }
                     ClickResult::Invalid => {
-                        println!("❌ 无法标记已翻开的格子");
+                        println!("{}", t!("flag.invalid"));
                     }
                     _ => {} // 标记操作不会导致游戏结束
                 }
             }
             None => {
-                println!("❌ 坐标格式错误");
+                println!("{}", t!("game.bad_coordinates"));
             }
         }
     }
 
     fn parse_coordinates(&self, coords: &[&str]) -> Option<Position> {
-        if coords.len() != 2 {
-            return None;
-        }
-
-        let row = coords[0].parse::<usize>().ok()?;
-        let col = coords[1].parse::<usize>().ok()?;
+        let (row, col) = match coords {
+            [row, col] => (row.parse::<usize>().ok()?, col.parse::<usize>().ok()?),
+            // 六边形棋盘支持 "q,r" 轴向坐标
+            [axial] if self.board.get_board_config().shape == BoardShape::Hex => {
+                let (q, r) = axial.split_once(',')?;
+                let q = q.trim().parse::<isize>().ok()?;
+                let r = r.trim().parse::<isize>().ok()?;
+                let pos = Position::from_axial(q, r)?;
+                (pos.row, pos.col)
+            }
+            _ => return None,
+        };
 
         let config = self.board.get_board_config();
         if row >= config.board_size.height || col >= config.board_size.width {
             println!(
-                "❌ 坐标超出范围! 有效范围: 行 0-{}, 列 0-{}",
-                config.board_size.height - 1,
-                config.board_size.width - 1
+                "{}",
+                t!(
+                    "game.out_of_range",
+                    config.board_size.height - 1,
+                    config.board_size.width - 1
+                )
             );
             return None;
         }
@@ -202,13 +580,46 @@ impl Game {
         self.print_board();
 
         if self.victory {
-            println!("🎊🎊🎊 游戏胜利！🎊🎊🎊");
-            println!("🏆 你成功找到了所有地雷！");
+            println!("{}", t!("over.won_banner"));
+            println!("{}", t!("over.won"));
         } else {
-            println!("💀💀💀 游戏结束！💀💀💀");
-            println!("💣 不要灰心，再试一次吧！");
+            println!("{}", t!("over.lost_banner"));
+            println!("{}", t!("over.lost"));
         }
+        if self.lives.total() > 1 {
+            println!(
+                "{}",
+                t!("over.lives_spent", self.lives.spent(), self.lives.total())
+            );
+        }
+
+        println!("{}", t!("over.thanks"));
+    }
+
+    // 为测试提供的公开方法：在指定位置布雷
+    #[cfg(test)]
+    pub fn place_mines_for_test(&mut self, mines: &[Position]) {
+        self.board.place_mines_for_test(mines);
+    }
 
-        println!("感谢游玩！");
+    // 为测试提供的公开方法：执行一条命令，返回 false 表示退出
+    #[cfg(test)]
+    pub fn command_for_test(&mut self, command: &str) -> bool {
+        self.process_command(command)
     }
+
+    #[cfg(test)]
+    pub fn board_for_test(&self) -> &Board {
+        &self.board
+    }
+
+    #[cfg(test)]
+    pub fn is_over_for_test(&self) -> bool {
+        self.game_over
+    }
+}
+
+// 是否使用供读屏软件的线性文本输出
+fn accessible() -> bool {
+    render::render_mode() == RenderMode::Accessible
 }
@@ -0,0 +1,243 @@
+use crate::core::{Board3D, BoardConfig3D, ClickResult, Position3D};
+use crate::t;
+use std::io::{self, Write};
+
+/// 三维扫雷的命令行控制器，一次只显示一层
+pub struct Game3D {
+    board: Board3D,
+    current_layer: usize,
+    game_over: bool,
+    victory: bool,
+}
+
+impl Game3D {
+    pub fn new(config: BoardConfig3D) -> Self {
+        Game3D {
+            board: Board3D::new(config),
+            current_layer: 0,
+            game_over: false,
+            victory: false,
+        }
+    }
+
+    pub fn run(&mut self) {
+        self.print_welcome();
+        self.print_help();
+
+        while !self.game_over {
+            self.print_board();
+
+            match self.get_user_input() {
+                Some(command) => {
+                    if !self.process_command(&command) {
+                        break; // 用户选择退出
+                    }
+                }
+                None => {
+                    println!("{}", t!("game.invalid_input"));
+                    continue;
+                }
+            }
+        }
+
+        self.print_game_over();
+    }
+
+    fn print_welcome(&self) {
+        println!("{}", t!("cube.welcome"));
+        let config = self.board.get_board_config();
+        println!(
+            "{}",
+            t!(
+                "cube.config",
+                config.board_size.width,
+                config.board_size.height,
+                config.board_size.depth,
+                config.board_size.depth,
+                config.mine_count
+            )
+        );
+        println!("{}", t!("cube.hint"));
+        println!();
+    }
+
+    fn print_help(&self) {
+        println!("{}", t!("help.title"));
+        println!("{}", t!("cube.help_click"));
+        println!("{}", t!("cube.help_flag"));
+        println!("{}", t!("cube.help_layer"));
+        println!("{}", t!("cube.help_up_down"));
+        println!("{}", t!("cube.help_help"));
+        println!("{}", t!("cube.help_quit"));
+        println!("{}", t!("cube.help_coordinates"));
+        println!();
+    }
+
+    fn print_board(&self) {
+        println!("{}", t!("game.board_title"));
+        self.board.print_layer(self.current_layer);
+        println!();
+    }
+
+    fn get_user_input(&self) -> Option<String> {
+        print!("{}", t!("cube.input_prompt", self.current_layer));
+        io::stdout().flush().ok()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).ok()?;
+
+        Some(input.trim().to_lowercase())
+    }
+
+    fn process_command(&mut self, command: &str) -> bool {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+
+        if parts.is_empty() {
+            return true;
+        }
+
+        match parts[0] {
+            "help" => {
+                self.print_help();
+            }
+            "quit" | "exit" => {
+                println!("{}", t!("game.bye"));
+                return false;
+            }
+            "click" => {
+                if !(3..=4).contains(&parts.len()) {
+                    println!("{}", t!("cube.usage_click"));
+                } else {
+                    self.handle_click(&parts[1..]);
+                }
+            }
+            "flag" => {
+                if !(3..=4).contains(&parts.len()) {
+                    println!("{}", t!("cube.usage_flag"));
+                } else {
+                    self.handle_flag(&parts[1..]);
+                }
+            }
+            "layer" => match parts.get(1).and_then(|s| s.parse::<usize>().ok()) {
+                Some(layer) => self.switch_layer(layer),
+                None => println!("{}", t!("cube.usage_layer")),
+            },
+            "up" => {
+                if self.current_layer == 0 {
+                    println!("{}", t!("cube.top_layer"));
+                } else {
+                    self.switch_layer(self.current_layer - 1);
+                }
+            }
+            "down" => self.switch_layer(self.current_layer + 1),
+            _ => {
+                println!("{}", t!("game.unknown_command", parts[0]));
+            }
+        }
+
+        true
+    }
+
+    fn switch_layer(&mut self, layer: usize) {
+        let depth = self.board.get_board_config().board_size.depth;
+        if layer >= depth {
+            println!("{}", t!("cube.layer_out_of_range", depth - 1));
+        } else {
+            self.current_layer = layer;
+        }
+    }
+
+    fn handle_click(&mut self, coords: &[&str]) {
+        match self.parse_coordinates(coords) {
+            Some(pos) => {
+                // 点击其他层时自动切换显示，方便查看结果
+                self.current_layer = pos.layer;
+                match self.board.left_click(pos) {
+                    ClickResult::Continue => {
+                        println!("{}", t!("click.ok"));
+                    }
+                    ClickResult::Victory => {
+                        println!("{}", t!("click.victory"));
+                        self.victory = true;
+                        self.game_over = true;
+                    }
+                    ClickResult::GameOver => {
+                        println!("{}", t!("click.mine"));
+                        self.game_over = true;
+                        self.board.reveal_all_mines();
+                    }
+                    ClickResult::Invalid => {
+                        println!("{}", t!("click.invalid"));
+                    }
+                }
+            }
+            None => {
+                println!("{}", t!("game.bad_coordinates"));
+            }
+        }
+    }
+
+    fn handle_flag(&mut self, coords: &[&str]) {
+        match self.parse_coordinates(coords) {
+            Some(pos) => match self.board.right_click(pos) {
+                ClickResult::Continue => {
+                    println!("{}", t!("flag.ok"));
+                }
+                ClickResult::Invalid => {
+                    println!("{}", t!("flag.invalid"));
+                }
+                _ => {} // 标记操作不会导致游戏结束
+            },
+            None => {
+                println!("{}", t!("game.bad_coordinates"));
+            }
+        }
+    }
+
+    fn parse_coordinates(&self, coords: &[&str]) -> Option<Position3D> {
+        let (row, col, layer) = match coords {
+            [row, col] => (
+                row.parse::<usize>().ok()?,
+                col.parse::<usize>().ok()?,
+                self.current_layer,
+            ),
+            [row, col, layer] => (
+                row.parse::<usize>().ok()?,
+                col.parse::<usize>().ok()?,
+                layer.parse::<usize>().ok()?,
+            ),
+            _ => return None,
+        };
+
+        let pos = Position3D { layer, row, col };
+        if !self.board.is_valid_position(pos) {
+            let size = self.board.get_board_config().board_size;
+            println!(
+                "{}",
+                t!(
+                    "cube.out_of_range",
+                    size.height - 1,
+                    size.width - 1,
+                    size.depth - 1
+                )
+            );
+            return None;
+        }
+
+        Some(pos)
+    }
+
+    fn print_game_over(&self) {
+        self.print_board();
+
+        if self.victory {
+            println!("{}", t!("over.won_banner"));
+            println!("{}", t!("over.won"));
+        } else {
+            println!("{}", t!("over.lost_banner"));
+            println!("{}", t!("over.lost"));
+        }
+
+        println!("{}", t!("over.thanks"));
+    }
+}
@@ -0,0 +1,452 @@
+use crate::config::graph::MAX_DISPLAY_COORDINATE;
+use crate::core::{Cell, CellContent, CellState, ClickResult, Position};
+use crate::render::{DIM, RESET, cell_glyph, print_legend};
+use crate::t;
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// 图中的一个节点
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    /// 可选的显示坐标，所有节点都有坐标时按网格打印
+    pub display: Option<Position>,
+}
+
+/// 任意拓扑的棋盘结构：节点列表和无向边
+///
+/// 文件格式（每行一条，`#` 之后为注释）：
+///
+/// ```text
+/// node <id> [<行> <列>]   # 节点编号必须从 0 开始连续，行列最大为 99
+/// edge <a> <b>           # 无向边
+/// mines <数量>            # 可选，默认地雷数
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoardGraph {
+    nodes: Vec<GraphNode>,
+    adjacency: Vec<Vec<usize>>,
+    mine_count: Option<usize>,
+    // 显示坐标（行, 列）到节点编号的索引
+    display_index: HashMap<(usize, usize), usize>,
+}
+
+#[derive(Debug)]
+pub enum GraphLoadError {
+    Io(std::io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for GraphLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphLoadError::Io(err) => write!(f, "{}", t!("graph.error_io", err)),
+            GraphLoadError::Parse { line, message } => {
+                write!(f, "{}", t!("error.line", line, message))
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphLoadError {}
+
+impl From<std::io::Error> for GraphLoadError {
+    fn from(err: std::io::Error) -> Self {
+        GraphLoadError::Io(err)
+    }
+}
+
+fn parse_error(line: usize, message: impl Into<String>) -> GraphLoadError {
+    GraphLoadError::Parse {
+        line,
+        message: message.into(),
+    }
+}
+
+fn parse_number(line: usize, token: &str) -> Result<usize, GraphLoadError> {
+    token
+        .parse::<usize>()
+        .map_err(|_| parse_error(line, t!("error.number", token)))
+}
+
+impl BoardGraph {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, GraphLoadError> {
+        let text = fs::read_to_string(path)?;
+        Self::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, GraphLoadError> {
+        let mut nodes: Vec<GraphNode> = Vec::new();
+        let mut adjacency: Vec<Vec<usize>> = Vec::new();
+        let mut mine_count = None;
+        let mut display_index = HashMap::new();
+
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = index + 1;
+            let content = raw_line.split('#').next().unwrap_or("");
+            let parts: Vec<&str> = content.split_whitespace().collect();
+
+            match parts.as_slice() {
+                [] => {}
+                ["node", id, rest @ ..] => {
+                    let id = parse_number(line, id)?;
+                    if id != nodes.len() {
+                        return Err(parse_error(
+                            line,
+                            t!("graph.error_node_id", nodes.len(), id),
+                        ));
+                    }
+                    let display = match rest {
+                        [] => None,
+                        [row, col] => Some(Position {
+                            row: parse_number(line, row)?,
+                            col: parse_number(line, col)?,
+                        }),
+                        _ => return Err(parse_error(line, t!("graph.error_node_usage"))),
+                    };
+                    if let Some(pos) = display {
+                        if pos.row > MAX_DISPLAY_COORDINATE || pos.col > MAX_DISPLAY_COORDINATE {
+                            return Err(parse_error(
+                                line,
+                                t!("graph.error_display_range", MAX_DISPLAY_COORDINATE),
+                            ));
+                        }
+                        if let Some(other) = display_index.insert((pos.row, pos.col), id) {
+                            return Err(parse_error(
+                                line,
+                                t!("graph.error_duplicate_display", pos.row, pos.col, other),
+                            ));
+                        }
+                    }
+                    nodes.push(GraphNode { display });
+                    adjacency.push(Vec::new());
+                }
+                ["edge", a, b] => {
+                    let a = parse_number(line, a)?;
+                    let b = parse_number(line, b)?;
+                    if a >= nodes.len() || b >= nodes.len() {
+                        return Err(parse_error(line, t!("graph.error_unknown_node")));
+                    }
+                    if a == b {
+                        return Err(parse_error(line, t!("graph.error_self_loop")));
+                    }
+                    // 重复的边只记录一次
+                    if !adjacency[a].contains(&b) {
+                        adjacency[a].push(b);
+                        adjacency[b].push(a);
+                    }
+                }
+                ["mines", count] => {
+                    mine_count = Some(parse_number(line, count)?);
+                }
+                _ => {
+                    return Err(parse_error(
+                        line,
+                        t!("graph.error_unrecognised", content.trim()),
+                    ));
+                }
+            }
+        }
+
+        // 至少要有一个地雷和一个安全的节点
+        if nodes.len() < 2 {
+            return Err(parse_error(0, t!("graph.error_too_few_nodes")));
+        }
+        if let Some(count) = mine_count
+            && count >= nodes.len()
+        {
+            return Err(parse_error(0, t!("graph.error_too_many_mines")));
+        }
+        // 数字以 i16 保存，度数不能超过它的上限
+        if adjacency.iter().any(|adj| adj.len() > i16::MAX as usize) {
+            return Err(parse_error(0, t!("graph.error_degree", i16::MAX)));
+        }
+
+        // 坐标要么全部给出，要么全部省略
+        let with_display = nodes.iter().filter(|node| node.display.is_some()).count();
+        if with_display != 0 && with_display != nodes.len() {
+            return Err(parse_error(0, t!("graph.error_partial_display")));
+        }
+
+        Ok(BoardGraph {
+            nodes,
+            adjacency,
+            mine_count,
+            display_index,
+        })
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn nodes(&self) -> &[GraphNode] {
+        &self.nodes
+    }
+
+    pub fn neighbours(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    /// 文件中声明的地雷数
+    pub fn mine_count(&self) -> Option<usize> {
+        self.mine_count
+    }
+
+    /// 所有节点都有显示坐标时返回 true
+    pub fn has_display(&self) -> bool {
+        self.nodes.iter().all(|node| node.display.is_some())
+    }
+
+    /// 按显示坐标查找节点
+    pub fn node_at(&self, pos: Position) -> Option<usize> {
+        self.display_index.get(&(pos.row, pos.col)).copied()
+    }
+}
+
+/// 由图驱动的扫雷棋盘：数字、自动展开、首次点击安全和胜利判定都基于图的邻接关系
+pub struct GraphBoard {
+    graph: BoardGraph,
+    mine_count: usize,
+    cells: Vec<Cell>,
+    mines_placed: bool,
+    revealed_count: usize,
+}
+
+impl GraphBoard {
+    pub fn new(graph: BoardGraph, mine_count: usize) -> Self {
+        assert!(
+            mine_count < graph.node_count(),
+            "mine count must be smaller than node count"
+        );
+        let cells = vec![Cell::new(); graph.node_count()];
+        GraphBoard {
+            graph,
+            mine_count,
+            cells,
+            mines_placed: false,
+            revealed_count: 0,
+        }
+    }
+
+    fn ensure_mines_placed(&mut self, first_click: usize) {
+        if !self.mines_placed {
+            self.place_mines_avoiding_first_click(first_click);
+            self.calculate_numbers();
+            self.mines_placed = true;
+        }
+    }
+
+    fn place_mines_avoiding_first_click(&mut self, first_click: usize) {
+        // 禁区为首次点击的节点及其邻居；小图放不下时只保护点击的节点本身
+        let mut forbidden = vec![first_click];
+        forbidden.extend_from_slice(self.graph.neighbours(first_click));
+        if self.graph.node_count() - forbidden.len() < self.mine_count {
+            forbidden.truncate(1);
+        }
+
+        let mut candidates: Vec<usize> = (0..self.graph.node_count())
+            .filter(|node| !forbidden.contains(node))
+            .collect();
+        let mut rng = rand::rng();
+        candidates.shuffle(&mut rng);
+        for &node in candidates.iter().take(self.mine_count) {
+            self.cells[node].set_content(CellContent::Mine(1));
+        }
+    }
+
+    fn calculate_numbers(&mut self) {
+        for node in 0..self.cells.len() {
+            if self.cells[node].is_mine() {
+                continue;
+            }
+            let count = self
+                .graph
+                .neighbours(node)
+                .iter()
+                .filter(|&&adj| self.cells[adj].is_mine())
+                .count();
+            self.cells[node].set_content(CellContent::Number(count as i16));
+        }
+    }
+
+    // 左键点击处理
+    pub fn left_click(&mut self, node: usize) -> ClickResult {
+        if node >= self.cells.len() {
+            return ClickResult::Invalid;
+        }
+
+        self.ensure_mines_placed(node);
+        let cell = &mut self.cells[node];
+        match cell.state() {
+            CellState::Revealed | CellState::Flagged => ClickResult::Invalid,
+            CellState::Hidden => {
+                cell.set_state(CellState::Revealed);
+                match cell.content() {
+                    CellContent::Mine(_) => ClickResult::GameOver,
+                    CellContent::Number(number) => {
+                        self.revealed_count += 1;
+
+                        if number == 0 {
+                            self.auto_reveal_flood_fill(node);
+                        }
+
+                        if self.check_victory() {
+                            ClickResult::Victory
+                        } else {
+                            ClickResult::Continue
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // 沿图的边做队列洪水填充
+    fn auto_reveal_flood_fill(&mut self, start: usize) {
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+
+        while let Some(node) = queue.pop_front() {
+            for &adj in self.graph.neighbours(node) {
+                let cell = &mut self.cells[adj];
+                if cell.state() == CellState::Hidden && !cell.is_mine() {
+                    cell.set_state(CellState::Revealed);
+                    self.revealed_count += 1;
+
+                    if let CellContent::Number(0) = cell.content() {
+                        queue.push_back(adj);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn reveal_all_mines(&mut self) {
+        for cell in &mut self.cells {
+            if cell.is_mine() {
+                cell.set_state(CellState::Revealed);
+            }
+        }
+    }
+
+    // 右键点击处理
+    pub fn right_click(&mut self, node: usize) -> ClickResult {
+        let Some(cell) = self.cells.get_mut(node) else {
+            return ClickResult::Invalid;
+        };
+        match cell.state() {
+            CellState::Revealed => ClickResult::Invalid,
+            CellState::Flagged => {
+                cell.set_state(CellState::Hidden);
+                ClickResult::Continue
+            }
+            CellState::Hidden => {
+                cell.set_state(CellState::Flagged);
+                ClickResult::Continue
+            }
+        }
+    }
+
+    pub fn check_victory(&self) -> bool {
+        self.revealed_count + self.mine_count == self.graph.node_count()
+    }
+
+    /// 节点的真实内容；库外通过 spoiler() 读取
+    pub(crate) fn get_cell_content(&self, node: usize) -> CellContent {
+        self.cells[node].content()
+    }
+
+    pub fn get_cell_state(&self, node: usize) -> CellState {
+        self.cells[node].state()
+    }
+
+    pub fn graph(&self) -> &BoardGraph {
+        &self.graph
+    }
+
+    pub fn mine_count(&self) -> usize {
+        self.mine_count
+    }
+
+    pub fn are_mines_placed(&self) -> bool {
+        self.mines_placed
+    }
+
+    /// 有显示坐标时按网格打印（空白处不是格子），否则逐个列出节点和邻居
+    pub fn print_debug(&self) {
+        if self.graph.has_display() {
+            self.print_grid();
+        } else {
+            self.print_list();
+        }
+        print_legend();
+    }
+
+    fn print_grid(&self) {
+        // 坐标在解析时已限制在 MAX_DISPLAY_COORDINATE 以内，网格大小有上限
+        let positions = self.graph.display_index.keys();
+        let height = positions.clone().map(|&(row, _)| row).max().unwrap_or(0) + 1;
+        let width = positions.map(|&(_, col)| col).max().unwrap_or(0) + 1;
+
+        print!("    ");
+        for col in 0..width {
+            print!("{}{:>2} {}", DIM, col, RESET);
+        }
+        println!();
+
+        for row in 0..height {
+            print!("{}{:>2}{}  ", DIM, row, RESET);
+            for col in 0..width {
+                match self.graph.node_at(Position { row, col }) {
+                    Some(node) => {
+                        let (glyph, color) = cell_glyph(&self.cells[node]);
+                        print!(" {}{}{} ", color, glyph, RESET);
+                    }
+                    None => print!("   "),
+                }
+            }
+            println!();
+        }
+    }
+
+    fn print_list(&self) {
+        for (node, cell) in self.cells.iter().enumerate() {
+            let (glyph, color) = cell_glyph(cell);
+            let neighbours: Vec<String> = self
+                .graph
+                .neighbours(node)
+                .iter()
+                .map(|adj| adj.to_string())
+                .collect();
+            println!(
+                "{}{:>3}{} [{}{}{}] -> {}",
+                DIM,
+                node,
+                RESET,
+                color,
+                glyph,
+                RESET,
+                neighbours.join(", ")
+            );
+        }
+    }
+
+    // 为测试提供的公开方法：在指定节点布雷并计算数字，跳过随机生成
+    #[cfg(test)]
+    pub fn place_mines_for_test(&mut self, mines: &[usize]) {
+        self.mine_count = mines.len();
+        for &node in mines {
+            self.cells[node].set_content(CellContent::Mine(1));
+        }
+        self.calculate_numbers();
+        self.mines_placed = true;
+    }
+
+    #[cfg(test)]
+    pub fn get_revealed_count(&self) -> usize {
+        self.revealed_count
+    }
+}
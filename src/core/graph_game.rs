@@ -0,0 +1,184 @@
+use crate::core::{BoardGraph, ClickResult, GraphBoard, Position};
+use crate::t;
+use std::io::{self, Write};
+
+/// 图结构棋盘的命令行控制器，按节点编号操作
+pub struct GraphGame {
+    board: GraphBoard,
+    game_over: bool,
+    victory: bool,
+}
+
+impl GraphGame {
+    pub fn new(graph: BoardGraph, mine_count: usize) -> Self {
+        GraphGame {
+            board: GraphBoard::new(graph, mine_count),
+            game_over: false,
+            victory: false,
+        }
+    }
+
+    pub fn run(&mut self) {
+        self.print_welcome();
+        self.print_help();
+
+        while !self.game_over {
+            self.print_board();
+
+            match self.get_user_input() {
+                Some(command) => {
+                    if !self.process_command(&command) {
+                        break; // 用户选择退出
+                    }
+                }
+                None => {
+                    println!("{}", t!("game.invalid_input"));
+                    continue;
+                }
+            }
+        }
+
+        self.print_game_over();
+    }
+
+    fn print_welcome(&self) {
+        println!("{}", t!("graph.welcome"));
+        println!(
+            "{}",
+            t!(
+                "graph.config",
+                self.board.graph().node_count(),
+                self.board.mine_count()
+            )
+        );
+        println!("{}", t!("graph.hint"));
+        println!();
+    }
+
+    fn print_help(&self) {
+        println!("{}", t!("help.title"));
+        println!("{}", t!("graph.help_click"));
+        println!("{}", t!("graph.help_flag"));
+        if self.board.graph().has_display() {
+            println!("{}", t!("graph.help_display"));
+        }
+        println!("{}", t!("graph.help_help"));
+        println!("{}", t!("graph.help_quit"));
+        println!("{}", t!("graph.help_nodes"));
+        println!();
+    }
+
+    fn print_board(&self) {
+        println!("{}", t!("game.board_title"));
+        self.board.print_debug();
+        println!();
+    }
+
+    fn get_user_input(&self) -> Option<String> {
+        print!("{}", t!("game.input_prompt"));
+        io::stdout().flush().ok()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).ok()?;
+
+        Some(input.trim().to_lowercase())
+    }
+
+    fn process_command(&mut self, command: &str) -> bool {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+
+        if parts.is_empty() {
+            return true;
+        }
+
+        match parts[0] {
+            "help" => {
+                self.print_help();
+            }
+            "quit" | "exit" => {
+                println!("{}", t!("game.bye"));
+                return false;
+            }
+            "click" => match self.parse_node(&parts[1..]) {
+                Some(node) => self.handle_click(node),
+                None => println!("{}", t!("graph.usage_click")),
+            },
+            "flag" => match self.parse_node(&parts[1..]) {
+                Some(node) => self.handle_flag(node),
+                None => println!("{}", t!("graph.usage_flag")),
+            },
+            _ => {
+                println!("{}", t!("game.unknown_command", parts[0]));
+            }
+        }
+
+        true
+    }
+
+    // 接受节点编号，或在有显示坐标时接受 <行> <列>
+    fn parse_node(&self, args: &[&str]) -> Option<usize> {
+        let graph = self.board.graph();
+        let node = match args {
+            [node] => node.parse::<usize>().ok()?,
+            [row, col] if graph.has_display() => graph.node_at(Position {
+                row: row.parse().ok()?,
+                col: col.parse().ok()?,
+            })?,
+            _ => return None,
+        };
+
+        if node >= graph.node_count() {
+            println!("{}", t!("graph.out_of_range", graph.node_count() - 1));
+            return None;
+        }
+
+        Some(node)
+    }
+
+    fn handle_click(&mut self, node: usize) {
+        match self.board.left_click(node) {
+            ClickResult::Continue => {
+                println!("{}", t!("click.ok"));
+            }
+            ClickResult::Victory => {
+                println!("{}", t!("click.victory"));
+                self.victory = true;
+                self.game_over = true;
+            }
+            ClickResult::GameOver => {
+                println!("{}", t!("click.mine"));
+                self.game_over = true;
+                self.board.reveal_all_mines();
+            }
+            ClickResult::Invalid => {
+                println!("{}", t!("graph.click_invalid"));
+            }
+        }
+    }
+
+    fn handle_flag(&mut self, node: usize) {
+        match self.board.right_click(node) {
+            ClickResult::Continue => {
+                println!("{}", t!("flag.ok"));
+            }
+            ClickResult::Invalid => {
+                println!("{}", t!("graph.flag_invalid"));
+            }
+            _ => {} // 标记操作不会导致游戏结束
+        }
+    }
+
+    fn print_game_over(&self) {
+        self.print_board();
+
+        if self.victory {
+            println!("{}", t!("over.won_banner"));
+            println!("{}", t!("over.won"));
+        } else {
+            println!("{}", t!("over.lost_banner"));
+            println!("{}", t!("over.lost"));
+        }
+
+        println!("{}", t!("over.thanks"));
+    }
+}
@@ -0,0 +1,388 @@
+use crate::config::infinite::{CHUNK_SIZE, MAX_COORDINATE, MAX_FLOOD_FILL_CELLS};
+use crate::core::{Cell, CellContent, CellState, ClickResult};
+use crate::render::{DIM, RESET, cell_glyph, print_legend};
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Write};
+
+/// 无限棋盘上的世界坐标，可以为负数
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct WorldPosition {
+    pub row: i64,
+    pub col: i64,
+}
+
+/// 区块坐标：世界坐标按 CHUNK_SIZE 向下取整
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ChunkCoord {
+    pub row: i64,
+    pub col: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InfiniteBoardConfig {
+    /// 世界种子，相同种子生成相同的地雷分布
+    pub seed: u64,
+    /// 每个格子是地雷的概率
+    pub density: f64,
+}
+
+impl WorldPosition {
+    pub fn chunk(self) -> ChunkCoord {
+        let size = CHUNK_SIZE as i64;
+        ChunkCoord {
+            row: self.row.div_euclid(size),
+            col: self.col.div_euclid(size),
+        }
+    }
+
+    // 在区块内的下标
+    fn index_in_chunk(self) -> usize {
+        let size = CHUNK_SIZE as i64;
+        (self.row.rem_euclid(size) * size + self.col.rem_euclid(size)) as usize
+    }
+
+    /// 坐标是否在世界范围内（绝对值不超过 MAX_COORDINATE）
+    pub fn in_bounds(self) -> bool {
+        (-MAX_COORDINATE..=MAX_COORDINATE).contains(&self.row)
+            && (-MAX_COORDINATE..=MAX_COORDINATE).contains(&self.col)
+    }
+
+    /// 移动 rows 行、cols 列，结果限制在世界范围内，任意步长都不会溢出
+    pub fn offset(self, rows: i64, cols: i64) -> WorldPosition {
+        WorldPosition {
+            row: self
+                .row
+                .saturating_add(rows)
+                .clamp(-MAX_COORDINATE, MAX_COORDINATE),
+            col: self
+                .col
+                .saturating_add(cols)
+                .clamp(-MAX_COORDINATE, MAX_COORDINATE),
+        }
+    }
+
+    /// 世界范围内的相邻格子
+    pub fn neighbours(self) -> impl Iterator<Item = WorldPosition> {
+        (-1..=1).flat_map(move |dr| {
+            (-1..=1).filter_map(move |dc| {
+                let adj = WorldPosition {
+                    row: self.row.checked_add(dr)?,
+                    col: self.col.checked_add(dc)?,
+                };
+                (adj != self && adj.in_bounds()).then_some(adj)
+            })
+        })
+    }
+
+    fn is_adjacent_or_same(self, other: WorldPosition) -> bool {
+        (self.row - other.row).abs() <= 1 && (self.col - other.col).abs() <= 1
+    }
+}
+
+// 一个区块：地雷分布和格子状态
+struct Chunk {
+    mines: Vec<bool>,
+    cells: Vec<Cell>,
+}
+
+// SplitMix64，把种子和世界坐标混合成一个均匀分布的 64 位值
+fn mix_seed(seed: u64, pos: WorldPosition) -> u64 {
+    let mut z = seed
+        ^ (pos.row as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (pos.col as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+// 每个格子是否有地雷只取决于种子和坐标，可以在任意区块单独计算
+fn seeded_mine(config: &InfiniteBoardConfig, pos: WorldPosition) -> bool {
+    // 取高 53 位映射到 [0, 1)
+    let sample = (mix_seed(config.seed, pos) >> 11) as f64 / (1u64 << 53) as f64;
+    sample < config.density
+}
+
+fn generate_chunk_mines(config: &InfiniteBoardConfig, coord: ChunkCoord) -> Vec<bool> {
+    let size = CHUNK_SIZE as i64;
+    (0..size * size)
+        .map(|index| {
+            seeded_mine(
+                config,
+                WorldPosition {
+                    row: coord.row * size + index / size,
+                    col: coord.col * size + index % size,
+                },
+            )
+        })
+        .collect()
+}
+
+/// 按需生成的无限扫雷棋盘
+///
+/// 世界被切分为 CHUNK_SIZE x CHUNK_SIZE 的区块，区块在玩家揭示到附近时才生成；
+/// 每个格子是否有地雷只由种子和坐标决定，因此区块的生成顺序不影响结果。
+pub struct InfiniteBoard {
+    config: InfiniteBoardConfig,
+    chunks: HashMap<ChunkCoord, Chunk>,
+    // 首次点击的位置，其 3x3 范围内不会有地雷
+    safe_center: Option<WorldPosition>,
+    revealed_count: usize,
+}
+
+impl InfiniteBoard {
+    pub fn new(config: InfiniteBoardConfig) -> Self {
+        assert!(
+            (0.0..1.0).contains(&config.density),
+            "density must be in [0, 1)"
+        );
+        InfiniteBoard {
+            config,
+            chunks: HashMap::new(),
+            safe_center: None,
+            revealed_count: 0,
+        }
+    }
+
+    fn ensure_chunk(&mut self, coord: ChunkCoord) -> &mut Chunk {
+        let config = self.config;
+        self.chunks.entry(coord).or_insert_with(|| Chunk {
+            mines: generate_chunk_mines(&config, coord),
+            cells: vec![Cell::new(); CHUNK_SIZE * CHUNK_SIZE],
+        })
+    }
+
+    fn in_safe_zone(&self, pos: WorldPosition) -> bool {
+        self.safe_center
+            .is_some_and(|center| center.is_adjacent_or_same(pos))
+    }
+
+    /// 某位置是否有地雷；未生成的区块按种子即时计算，不会修改棋盘。库外通过 spoiler() 读取
+    pub(crate) fn is_mine(&self, pos: WorldPosition) -> bool {
+        if self.in_safe_zone(pos) {
+            return false;
+        }
+        match self.chunks.get(&pos.chunk()) {
+            Some(chunk) => chunk.mines[pos.index_in_chunk()],
+            None => seeded_mine(&self.config, pos),
+        }
+    }
+
+    fn is_mine_generating(&mut self, pos: WorldPosition) -> bool {
+        if self.in_safe_zone(pos) {
+            return false;
+        }
+        self.ensure_chunk(pos.chunk()).mines[pos.index_in_chunk()]
+    }
+
+    fn cell_mut(&mut self, pos: WorldPosition) -> &mut Cell {
+        &mut self.ensure_chunk(pos.chunk()).cells[pos.index_in_chunk()]
+    }
+
+    pub fn get_cell_state(&self, pos: WorldPosition) -> CellState {
+        self.chunks
+            .get(&pos.chunk())
+            .map_or(CellState::Hidden, |chunk| {
+                chunk.cells[pos.index_in_chunk()].state()
+            })
+    }
+
+    pub(crate) fn get_cell_content(&self, pos: WorldPosition) -> CellContent {
+        if self.is_mine(pos) {
+            CellContent::Mine(1)
+        } else {
+            let count = pos.neighbours().filter(|&adj| self.is_mine(adj)).count();
+            CellContent::Number(count as i16)
+        }
+    }
+
+    // 揭示单个格子：按需生成相邻区块并写入数字
+    fn reveal_cell(&mut self, pos: WorldPosition) -> CellContent {
+        let content = if self.is_mine_generating(pos) {
+            CellContent::Mine(1)
+        } else {
+            let count = pos
+                .neighbours()
+                .filter(|&adj| self.is_mine_generating(adj))
+                .count();
+            CellContent::Number(count as i16)
+        };
+        let cell = self.cell_mut(pos);
+        cell.set_content(content);
+        cell.set_state(CellState::Revealed);
+        content
+    }
+
+    // 左键点击处理
+    pub fn left_click(&mut self, pos: WorldPosition) -> ClickResult {
+        if !pos.in_bounds() {
+            return ClickResult::Invalid;
+        }
+        if self.safe_center.is_none() {
+            self.safe_center = Some(pos);
+        }
+
+        if self.get_cell_state(pos) != CellState::Hidden {
+            return ClickResult::Invalid;
+        }
+
+        match self.reveal_cell(pos) {
+            CellContent::Mine(_) => ClickResult::GameOver,
+            CellContent::Number(number) => {
+                self.revealed_count += 1;
+                if number == 0 {
+                    self.auto_reveal_flood_fill(pos);
+                }
+                // 无限棋盘没有胜利，只能尽量多揭示
+                ClickResult::Continue
+            }
+        }
+    }
+
+    // 跨区块的队列洪水填充；单次展开有上限，防止极低密度下无止境地展开
+    fn auto_reveal_flood_fill(&mut self, start_pos: WorldPosition) {
+        let mut queue = VecDeque::new();
+        queue.push_back(start_pos);
+        let mut revealed_this_click = 0;
+
+        while let Some(pos) = queue.pop_front() {
+            for adj_pos in pos.neighbours() {
+                if revealed_this_click >= MAX_FLOOD_FILL_CELLS {
+                    return;
+                }
+                if self.get_cell_state(adj_pos) != CellState::Hidden
+                    || self.is_mine_generating(adj_pos)
+                {
+                    continue;
+                }
+
+                let content = self.reveal_cell(adj_pos);
+                self.revealed_count += 1;
+                revealed_this_click += 1;
+                if let CellContent::Number(0) = content {
+                    queue.push_back(adj_pos);
+                }
+            }
+        }
+    }
+
+    // 右键点击处理
+    pub fn right_click(&mut self, pos: WorldPosition) -> ClickResult {
+        if !pos.in_bounds() {
+            return ClickResult::Invalid;
+        }
+        let cell = self.cell_mut(pos);
+        match cell.state() {
+            CellState::Revealed => ClickResult::Invalid,
+            CellState::Flagged => {
+                cell.set_state(CellState::Hidden);
+                ClickResult::Continue
+            }
+            CellState::Hidden => {
+                cell.set_state(CellState::Flagged);
+                ClickResult::Continue
+            }
+        }
+    }
+
+    /// 揭示所有已生成区块中的地雷
+    pub fn reveal_all_mines(&mut self) {
+        let safe_center = self.safe_center;
+        let size = CHUNK_SIZE as i64;
+        for (coord, chunk) in self.chunks.iter_mut() {
+            for (index, cell) in chunk.cells.iter_mut().enumerate() {
+                let pos = WorldPosition {
+                    row: coord.row * size + index as i64 / size,
+                    col: coord.col * size + index as i64 % size,
+                };
+                let in_safe_zone = safe_center.is_some_and(|c| c.is_adjacent_or_same(pos));
+                if chunk.mines[index] && !in_safe_zone {
+                    cell.set_content(CellContent::Mine(1));
+                    cell.set_state(CellState::Revealed);
+                }
+            }
+        }
+    }
+
+    /// 得分：踩雷前揭示的安全格子数
+    pub fn score(&self) -> usize {
+        self.revealed_count
+    }
+
+    pub fn generated_chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn get_config(&self) -> &InfiniteBoardConfig {
+        &self.config
+    }
+
+    /// 打印以 top_left 为左上角、width x height 大小的视口
+    pub fn print_viewport(&self, top_left: WorldPosition, width: usize, height: usize) {
+        let _ = self.write_viewport(&mut io::stdout(), top_left, width, height);
+        print_legend();
+    }
+
+    /// 写出视口内的格子；行号宽度和列号行数按视口内最长的坐标（含负号）确定，保证格子对齐
+    pub(crate) fn write_viewport(
+        &self,
+        out: &mut dyn Write,
+        top_left: WorldPosition,
+        width: usize,
+        height: usize,
+    ) -> io::Result<()> {
+        let rows: Vec<i64> = (0..height as i64).map(|dr| top_left.row + dr).collect();
+        let cols: Vec<String> = (0..width as i64)
+            .map(|dc| (top_left.col + dc).to_string())
+            .collect();
+        // 至少保持原来 5 列宽的行号
+        let row_width = rows
+            .iter()
+            .map(|row| row.to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max(5);
+
+        // 列号表头：每格 4 列宽，三个字符以内写成一行，更长的坐标按字符竖排成多行，
+        // 最后一个字符总是与格子中的图标对齐
+        let col_digits = cols.iter().map(String::len).max().unwrap_or(0);
+        let indent = " ".repeat(row_width + 1);
+        if col_digits <= 3 {
+            write!(out, "{}", indent)?;
+            for col in &cols {
+                write!(out, "{}{:>4}{}", DIM, col, RESET)?;
+            }
+            writeln!(out)?;
+        } else {
+            for line in 0..col_digits {
+                write!(out, "{}", indent)?;
+                for col in &cols {
+                    let ch = (line + col.len())
+                        .checked_sub(col_digits)
+                        .and_then(|index| col[index..].chars().next())
+                        .unwrap_or(' ');
+                    write!(out, "{}   {}{}", DIM, ch, RESET)?;
+                }
+                writeln!(out)?;
+            }
+        }
+
+        let hidden = Cell::new();
+        for &row in &rows {
+            write!(out, "{}{:>row_width$}{} ", DIM, row, RESET)?;
+            for dc in 0..width as i64 {
+                let pos = WorldPosition {
+                    row,
+                    col: top_left.col + dc,
+                };
+                let cell = self
+                    .chunks
+                    .get(&pos.chunk())
+                    .map_or(&hidden, |chunk| &chunk.cells[pos.index_in_chunk()]);
+                let (glyph, color) = cell_glyph(cell);
+                write!(out, "   {}{}{}", color, glyph, RESET)?;
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use crate::config::infinite::{MAX_COORDINATE, VIEWPORT_HEIGHT, VIEWPORT_WIDTH};
+use crate::core::{ClickResult, InfiniteBoard, InfiniteBoardConfig, WorldPosition};
+use crate::t;
+use std::io::{self, Write};
+
+/// 无限模式的命令行控制器，通过可滚动的视口显示棋盘
+pub struct InfiniteGame {
+    board: InfiniteBoard,
+    // 视口左上角的世界坐标
+    viewport: WorldPosition,
+    game_over: bool,
+}
+
+impl InfiniteGame {
+    pub fn new(config: InfiniteBoardConfig) -> Self {
+        InfiniteGame {
+            board: InfiniteBoard::new(config),
+            // 让世界原点位于视口中央
+            viewport: WorldPosition {
+                row: -(VIEWPORT_HEIGHT as i64 / 2),
+                col: -(VIEWPORT_WIDTH as i64 / 2),
+            },
+            game_over: false,
+        }
+    }
+
+    pub fn run(&mut self) {
+        self.print_welcome();
+        self.print_help();
+
+        while !self.game_over {
+            self.print_board();
+
+            match self.get_user_input() {
+                Some(command) => {
+                    if !self.process_command(&command) {
+                        break; // 用户选择退出
+                    }
+                }
+                None => {
+                    println!("{}", t!("game.invalid_input"));
+                    continue;
+                }
+            }
+        }
+
+        self.print_game_over();
+    }
+
+    fn print_welcome(&self) {
+        println!("{}", t!("infinite.welcome"));
+        let config = self.board.get_config();
+        println!(
+            "{}",
+            t!(
+                "infinite.config",
+                config.seed,
+                format!("{:.0}", config.density * 100.0)
+            )
+        );
+        println!("{}", t!("infinite.hint"));
+        println!();
+    }
+
+    fn print_help(&self) {
+        println!("{}", t!("help.title"));
+        println!("{}", t!("infinite.help_click"));
+        println!("{}", t!("infinite.help_flag"));
+        println!("{}", t!("help.pan"));
+        println!("{}", t!("help.goto"));
+        println!("{}", t!("help.help"));
+        println!("{}", t!("help.quit"));
+        println!();
+    }
+
+    fn print_board(&self) {
+        println!(
+            "{}",
+            t!(
+                "infinite.title",
+                self.viewport.row,
+                self.viewport.row + VIEWPORT_HEIGHT as i64 - 1,
+                self.viewport.col,
+                self.viewport.col + VIEWPORT_WIDTH as i64 - 1,
+                self.board.score(),
+                self.board.generated_chunk_count()
+            )
+        );
+        self.board
+            .print_viewport(self.viewport, VIEWPORT_WIDTH, VIEWPORT_HEIGHT);
+        println!();
+    }
+
+    fn get_user_input(&self) -> Option<String> {
+        print!("{}", t!("game.input_prompt"));
+        io::stdout().flush().ok()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).ok()?;
+
+        Some(input.trim().to_lowercase())
+    }
+
+    fn process_command(&mut self, command: &str) -> bool {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+
+        if parts.is_empty() {
+            return true;
+        }
+
+        match parts[0] {
+            "help" => {
+                self.print_help();
+            }
+            "quit" | "exit" => {
+                println!("{}", t!("game.bye"));
+                return false;
+            }
+            "click" => match Self::parse_coordinates(&parts[1..]) {
+                Some(pos) if !pos.in_bounds() => Self::print_out_of_bounds(),
+                Some(pos) => self.handle_click(pos),
+                None => println!("{}", t!("game.usage_click")),
+            },
+            "flag" => match Self::parse_coordinates(&parts[1..]) {
+                Some(pos) if !pos.in_bounds() => Self::print_out_of_bounds(),
+                Some(pos) => match self.board.right_click(pos) {
+                    ClickResult::Invalid => println!("{}", t!("flag.invalid")),
+                    _ => println!("{}", t!("flag.ok")),
+                },
+                None => println!("{}", t!("game.usage_flag")),
+            },
+            "goto" => match Self::parse_coordinates(&parts[1..]) {
+                Some(pos) if !pos.in_bounds() => Self::print_out_of_bounds(),
+                Some(pos) => self.center_on(pos),
+                None => println!("{}", t!("game.usage_goto")),
+            },
+            "w" | "a" | "s" | "d" => {
+                let step = match parts.get(1) {
+                    Some(n) => match n.parse::<i64>() {
+                        Ok(n) => n,
+                        Err(_) => {
+                            println!("{}", t!("viewport.bad_step"));
+                            return true;
+                        }
+                    },
+                    None if matches!(parts[0], "w" | "s") => VIEWPORT_HEIGHT as i64 / 2,
+                    None => VIEWPORT_WIDTH as i64 / 2,
+                };
+                // 视口停在世界边界上，步长再大也不会溢出
+                let step = step.clamp(-MAX_COORDINATE, MAX_COORDINATE);
+                self.viewport = match parts[0] {
+                    "w" => self.viewport.offset(-step, 0),
+                    "s" => self.viewport.offset(step, 0),
+                    "a" => self.viewport.offset(0, -step),
+                    _ => self.viewport.offset(0, step),
+                };
+            }
+            _ => {
+                println!("{}", t!("game.unknown_command", parts[0]));
+            }
+        }
+
+        true
+    }
+
+    fn handle_click(&mut self, pos: WorldPosition) {
+        match self.board.left_click(pos) {
+            ClickResult::Continue | ClickResult::Victory => {
+                println!("{}", t!("infinite.click_ok", self.board.score()));
+            }
+            ClickResult::GameOver => {
+                println!("{}", t!("click.mine"));
+                self.game_over = true;
+                self.board.reveal_all_mines();
+                // 让踩到的地雷出现在视口中
+                self.center_on(pos);
+            }
+            ClickResult::Invalid => {
+                println!("{}", t!("click.invalid"));
+            }
+        }
+    }
+
+    fn center_on(&mut self, pos: WorldPosition) {
+        self.viewport = pos.offset(-(VIEWPORT_HEIGHT as i64 / 2), -(VIEWPORT_WIDTH as i64 / 2));
+    }
+
+    fn print_out_of_bounds() {
+        println!("{}", t!("infinite.out_of_bounds", MAX_COORDINATE));
+    }
+
+    fn parse_coordinates(coords: &[&str]) -> Option<WorldPosition> {
+        match coords {
+            [row, col] => Some(WorldPosition {
+                row: row.parse::<i64>().ok()?,
+                col: col.parse::<i64>().ok()?,
+            }),
+            _ => None,
+        }
+    }
+
+    fn print_game_over(&self) {
+        self.print_board();
+
+        println!("{}", t!("over.lost_banner"));
+        println!("{}", t!("infinite.final_score", self.board.score()));
+        println!("{}", t!("over.thanks"));
+    }
+}
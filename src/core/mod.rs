@@ -2,13 +2,59 @@
 //!
 //! 包含扫雷游戏的核心组件：
 //! - Board: 游戏棋盘逻辑
+//! - Board3D: 分层的三维棋盘
 //! - Cell: 单元格状态管理
+//! - 查询: Board 上只返回可见信息的只读查询，以及显式的 Spoiler
+//! - BoardEvent / BoardObserver: 棋盘状态改变时通知订阅者
 //! - Game: 游戏流程控制
+//! - Game3D: 三维棋盘的游戏流程
+//! - InfiniteBoard / InfiniteGame: 按区块按需生成的无限棋盘
+//! - GraphBoard / GraphGame: 从文件加载任意拓扑的图结构棋盘
+//! - CoopSession: 多人合作的共享棋盘
+//! - FlagsMatch / FlagsGame: 双人轮流找雷的 Minesweeper Flags
+//! - TimedChallenge / TimedGame: 由引擎时钟驱动的倒计时和限时挑战
 
 pub mod board;
+pub mod board3d;
 pub mod cell;
+pub mod clock;
+pub mod coop;
+pub mod events;
+pub mod flags;
+pub mod flags_game;
 pub mod game;
+pub mod game3d;
+pub mod graph_board;
+pub mod graph_game;
+pub mod infinite_board;
+pub mod infinite_game;
+mod packed_cell;
+pub mod query;
+pub mod solver;
+pub mod timed;
+pub mod timed_game;
 
-pub use board::{Board, BoardConfig, BoardSize, ClickResult, Position};
+pub use board::{
+    Board, BoardConfig, BoardShape, BoardSize, ClickDetails, ClickResult, CustomOffsets,
+    FlagChange, Neighbourhood, Neighbours, Position,
+};
+pub use board3d::{Board3D, BoardConfig3D, BoardSize3D, Position3D};
 pub use cell::{Cell, CellContent, CellState};
-pub use game::Game;
+pub use clock::{Clock, ManualClock, SystemClock};
+pub use coop::{CoopAction, CoopEvent, CoopOutcome, CoopSession};
+pub use events::{BoardEvent, BoardObserver, ObserverId};
+pub use flags::{FlagsEvent, FlagsMatch};
+pub use flags_game::FlagsGame;
+pub use game::{Game, Lives};
+pub use game3d::Game3D;
+pub use graph_board::{BoardGraph, GraphBoard, GraphLoadError, GraphNode};
+pub use graph_game::GraphGame;
+pub use infinite_board::{ChunkCoord, InfiniteBoard, InfiniteBoardConfig, WorldPosition};
+pub use infinite_game::InfiniteGame;
+pub use query::Spoiler;
+pub use solver::{Analysis, VisibleBoard, VisibleCell, analyze, analyze_visible};
+pub use timed::{
+    RecordsError, TimedChallenge, TimedConfig, TimedEvent, TimedMode, TimedOutcome, TimedRecords,
+    TimedResult,
+};
+pub use timed_game::TimedGame;
@@ -0,0 +1,152 @@
+//! 棋盘格子的紧凑存储
+//!
+//! Board 的每个格子只占四个字节，整块棋盘是一段连续内存，
+//! 适合 5000x5000 这样的超大棋盘。
+
+use crate::core::{Cell, CellContent, CellState};
+
+// 位布局：
+// - 第 0-15 位：相邻地雷的代数和（i16，负雷会让它变小甚至为负）
+// - 第 16-18 位：格子中的地雷数（0 表示不是地雷）
+// - 第 19 位：格子中的地雷是否为负雷
+// - 第 20-21 位：格子状态（0 隐藏、1 翻开、2 标记）
+// - 第 22-24 位：旗子数
+// - 第 25 位：是否为负雷旗
+// - 第 26 位：周围是否有任何地雷（正负抵消后数字为 0 时仍然置位）
+// - 第 27 位：生命模式下已踩中并自动标记的地雷，之后不能再改动
+const COUNT_MASK: u32 = 0xFFFF;
+const MINE_SHIFT: u32 = 16;
+const MINE_MASK: u32 = 0b111 << MINE_SHIFT;
+const NEGATIVE_MINE_BIT: u32 = 1 << 19;
+const STATE_SHIFT: u32 = 20;
+const STATE_MASK: u32 = 0b11 << STATE_SHIFT;
+const FLAG_SHIFT: u32 = 22;
+const FLAG_MASK: u32 = 0b111 << FLAG_SHIFT;
+const NEGATIVE_FLAG_BIT: u32 = 1 << 25;
+const NEAR_MINE_BIT: u32 = 1 << 26;
+const TRIGGERED_BIT: u32 = 1 << 27;
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct PackedCell(u32);
+
+impl PackedCell {
+    pub(crate) fn is_mine(self) -> bool {
+        self.0 & MINE_MASK != 0
+    }
+
+    /// 格子中的地雷数，负雷为负数
+    pub(crate) fn mine_count(self) -> i8 {
+        let count = ((self.0 & MINE_MASK) >> MINE_SHIFT) as i8;
+        if self.0 & NEGATIVE_MINE_BIT != 0 {
+            -count
+        } else {
+            count
+        }
+    }
+
+    /// 再放入一颗地雷
+    pub(crate) fn add_mine(&mut self) {
+        debug_assert!(self.0 & MINE_MASK != MINE_MASK);
+        self.0 += 1 << MINE_SHIFT;
+    }
+
+    /// 把格子中的地雷变为负雷
+    pub(crate) fn set_negative(&mut self) {
+        self.0 |= NEGATIVE_MINE_BIT;
+    }
+
+    /// 隐藏且不是地雷：自动展开只需要检查这一种情况
+    pub(crate) fn is_hidden_safe(self) -> bool {
+        self.0 & (MINE_MASK | STATE_MASK) == 0
+    }
+
+    pub(crate) fn adjacent_mines(self) -> i16 {
+        (self.0 & COUNT_MASK) as u16 as i16
+    }
+
+    /// 周围是否有地雷；负雷模式下数字为 0 不代表周围没有地雷
+    pub(crate) fn is_near_mine(self) -> bool {
+        self.0 & NEAR_MINE_BIT != 0
+    }
+
+    /// 相邻格子放置了 count 颗（可为负）地雷时调用，增量更新数字
+    pub(crate) fn add_adjacent_mines(&mut self, count: i8) {
+        let sum = self.adjacent_mines() + i16::from(count);
+        self.0 = (self.0 & !COUNT_MASK) | u32::from(sum as u16) | NEAR_MINE_BIT;
+    }
+
+    /// 是否为已踩中并自动标记的地雷
+    pub(crate) fn is_triggered(self) -> bool {
+        self.0 & TRIGGERED_BIT != 0
+    }
+
+    pub(crate) fn set_triggered(&mut self) {
+        self.0 |= TRIGGERED_BIT;
+    }
+
+    pub(crate) fn content(self) -> CellContent {
+        if self.is_mine() {
+            CellContent::Mine(self.mine_count())
+        } else {
+            CellContent::Number(self.adjacent_mines())
+        }
+    }
+
+    pub(crate) fn state(self) -> CellState {
+        match (self.0 & STATE_MASK) >> STATE_SHIFT {
+            0 => CellState::Hidden,
+            1 => CellState::Revealed,
+            _ => CellState::Flagged,
+        }
+    }
+
+    /// 设置状态；标记时旗子数至少为 1，其他状态清空旗子数
+    pub(crate) fn set_state(&mut self, state: CellState) {
+        match state {
+            CellState::Hidden => self.write_state(0, 0),
+            CellState::Revealed => self.write_state(1, 0),
+            CellState::Flagged => {
+                let flags = self.flag_count();
+                self.write_state(2, if flags == 0 { 1 } else { flags })
+            }
+        }
+    }
+
+    /// 旗子数，负雷旗为负数
+    pub(crate) fn flag_count(self) -> i8 {
+        let count = ((self.0 & FLAG_MASK) >> FLAG_SHIFT) as i8;
+        if self.0 & NEGATIVE_FLAG_BIT != 0 {
+            -count
+        } else {
+            count
+        }
+    }
+
+    /// 设置旗子数，0 表示取消标记，负数表示负雷旗
+    pub(crate) fn set_flag_count(&mut self, count: i8) {
+        if count == 0 {
+            self.write_state(0, 0);
+        } else {
+            self.write_state(2, count);
+        }
+    }
+
+    fn write_state(&mut self, bits: u32, flags: i8) {
+        let magnitude = u32::from(flags.unsigned_abs());
+        debug_assert!(magnitude <= FLAG_MASK >> FLAG_SHIFT);
+        let negative = if flags < 0 { NEGATIVE_FLAG_BIT } else { 0 };
+        self.0 = (self.0 & !(STATE_MASK | FLAG_MASK | NEGATIVE_FLAG_BIT))
+            | (bits << STATE_SHIFT)
+            | (magnitude << FLAG_SHIFT)
+            | negative;
+    }
+
+    /// 展开为普通的 Cell，供打印等只读场景使用
+    pub(crate) fn to_cell(self) -> Cell {
+        let mut cell = Cell::new();
+        cell.set_content(self.content());
+        cell.set_flag_count(self.flag_count());
+        cell.set_state(self.state());
+        cell
+    }
+}
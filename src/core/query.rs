@@ -0,0 +1,163 @@
+//! 棋盘的只读查询
+//!
+//! Board 上的查询方法只返回玩家能看到的信息：隐藏格子只知道是隐藏的或插了几面旗，
+//! 不会透露下面是否有地雷。需要真实布局的场合（结束画面、调试、测试、导出）
+//! 必须显式地通过 `Board::spoiler()` 获取。三维、图结构和无限棋盘同样只能通过各自的
+//! `spoiler()` 读取真实布局。
+
+use crate::core::{
+    Board, Board3D, CellContent, CellState, GraphBoard, InfiniteBoard, Position, Position3D,
+    VisibleCell, WorldPosition,
+};
+
+impl Board {
+    // 按行优先顺序的全部坐标
+    fn positions(&self) -> impl Iterator<Item = Position> + use<> {
+        let size = self.get_board_config().board_size;
+        (0..size.height).flat_map(move |row| (0..size.width).map(move |col| Position { row, col }))
+    }
+
+    /// 格子的可见内容
+    pub fn get_visible_cell(&self, pos: Position) -> VisibleCell {
+        VisibleCell::of(self, pos)
+    }
+
+    /// 按行优先顺序迭代每个格子的坐标、状态和可见内容
+    pub fn cells(&self) -> impl Iterator<Item = (Position, CellState, VisibleCell)> + '_ {
+        self.positions()
+            .map(|pos| (pos, self.get_cell_state(pos), self.get_visible_cell(pos)))
+    }
+
+    /// 既没有翻开也没有标记的格子
+    pub fn hidden_cells(&self) -> impl Iterator<Item = Position> + '_ {
+        self.positions()
+            .filter(|&pos| self.get_cell_state(pos) == CellState::Hidden)
+    }
+
+    /// 插了旗的格子
+    pub fn flagged_cells(&self) -> impl Iterator<Item = Position> + '_ {
+        self.positions()
+            .filter(|&pos| self.get_cell_state(pos) == CellState::Flagged)
+    }
+
+    /// 边界：与已翻开的格子相邻、既没有翻开也没有标记的格子
+    pub fn frontier_cells(&self) -> impl Iterator<Item = Position> + '_ {
+        self.hidden_cells().filter(|&pos| {
+            self.neighbours(pos)
+                .any(|neighbour| self.get_cell_state(neighbour) == CellState::Revealed)
+        })
+    }
+
+    /// 已插的旗子总数；多雷格子按旗子数计，负雷旗按绝对值计
+    pub fn flags_placed(&self) -> usize {
+        self.flagged_cells()
+            .map(|pos| usize::from(self.get_flag_count(pos).unsigned_abs()))
+            .sum()
+    }
+
+    /// 地雷总数减去已插的旗子数；插旗过多时为负数
+    pub fn remaining_mines(&self) -> isize {
+        self.get_board_config().mine_count as isize - self.flags_placed() as isize
+    }
+
+    /// 按棋盘形状和相邻规则列出相邻的格子（不含自身）
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + use<> {
+        self.get_board_config().neighbours(pos, true)
+    }
+
+    /// 查看真实布局的接口，会暴露隐藏格子下面的内容
+    pub fn spoiler(&self) -> Spoiler<'_> {
+        Spoiler { board: self }
+    }
+}
+
+/// 棋盘的真实布局；只应在结束画面、调试、测试和导出等场合使用
+pub struct Spoiler<'a, B = Board> {
+    board: &'a B,
+}
+
+impl Spoiler<'_> {
+    /// 格子的真实内容，不论是否翻开
+    pub fn content(&self, pos: Position) -> CellContent {
+        self.board.cell_content(pos)
+    }
+
+    pub fn is_mine(&self, pos: Position) -> bool {
+        matches!(self.content(pos), CellContent::Mine(_))
+    }
+
+    /// 含有地雷的格子及其地雷数（负雷为负数）；首次点击布雷之前为空
+    pub fn mines(&self) -> impl Iterator<Item = (Position, i8)> + '_ {
+        self.board
+            .positions()
+            .filter_map(|pos| match self.content(pos) {
+                CellContent::Mine(count) => Some((pos, count)),
+                CellContent::Number(_) => None,
+            })
+    }
+
+    /// 旗子数与真实地雷数不符的格子（包括插在安全格子上的旗）
+    pub fn wrong_flags(&self) -> impl Iterator<Item = Position> + '_ {
+        self.board.flagged_cells().filter(|&pos| {
+            let mines = match self.content(pos) {
+                CellContent::Mine(count) => count,
+                CellContent::Number(_) => 0,
+            };
+            self.board.get_flag_count(pos) != mines
+        })
+    }
+}
+
+impl Board3D {
+    /// 查看真实布局的接口，会暴露隐藏格子下面的内容
+    pub fn spoiler(&self) -> Spoiler<'_, Board3D> {
+        Spoiler { board: self }
+    }
+}
+
+impl Spoiler<'_, Board3D> {
+    /// 格子的真实内容，不论是否翻开
+    pub fn content(&self, pos: Position3D) -> CellContent {
+        self.board.get_cell_content(pos)
+    }
+
+    pub fn is_mine(&self, pos: Position3D) -> bool {
+        matches!(self.content(pos), CellContent::Mine(_))
+    }
+}
+
+impl GraphBoard {
+    /// 查看真实布局的接口，会暴露隐藏节点下面的内容
+    pub fn spoiler(&self) -> Spoiler<'_, GraphBoard> {
+        Spoiler { board: self }
+    }
+}
+
+impl Spoiler<'_, GraphBoard> {
+    /// 节点的真实内容，不论是否翻开
+    pub fn content(&self, node: usize) -> CellContent {
+        self.board.get_cell_content(node)
+    }
+
+    pub fn is_mine(&self, node: usize) -> bool {
+        matches!(self.content(node), CellContent::Mine(_))
+    }
+}
+
+impl InfiniteBoard {
+    /// 查看真实布局的接口，会暴露隐藏格子下面的内容；未生成的区块按种子计算，不会修改棋盘
+    pub fn spoiler(&self) -> Spoiler<'_, InfiniteBoard> {
+        Spoiler { board: self }
+    }
+}
+
+impl Spoiler<'_, InfiniteBoard> {
+    /// 格子的真实内容，不论是否翻开
+    pub fn content(&self, pos: WorldPosition) -> CellContent {
+        self.board.get_cell_content(pos)
+    }
+
+    pub fn is_mine(&self, pos: WorldPosition) -> bool {
+        self.board.is_mine(pos)
+    }
+}
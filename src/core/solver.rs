@@ -0,0 +1,317 @@
+//! 只依据玩家可见信息的推理
+//!
+//! 从翻开的数字推出必然是地雷和必然安全的格子，并估计其余隐藏格子是地雷的概率。
+//! 只读取已翻开格子的内容，隐藏格子下面是什么对推理不可见。
+//! 推理假设每格最多一颗地雷且没有负雷；其他变体只给出按地雷密度的估计。
+
+use crate::core::{Board, BoardConfig, CellContent, CellState, Position};
+use std::collections::{HashMap, HashSet};
+
+/// 玩家能看到的格子信息
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VisibleCell {
+    Hidden,
+    /// 插旗的格子按地雷处理
+    Flagged,
+    /// 已翻开的地雷（例如双人模式中被认领的地雷）
+    Mine,
+    Number(i16),
+}
+
+impl VisibleCell {
+    pub fn of(board: &Board, pos: Position) -> VisibleCell {
+        match board.get_cell_state(pos) {
+            CellState::Hidden => VisibleCell::Hidden,
+            CellState::Flagged => VisibleCell::Flagged,
+            CellState::Revealed => match board.cell_content(pos) {
+                CellContent::Mine(_) => VisibleCell::Mine,
+                CellContent::Number(n) => VisibleCell::Number(n),
+            },
+        }
+    }
+
+    fn is_known_mine(self) -> bool {
+        matches!(self, VisibleCell::Flagged | VisibleCell::Mine)
+    }
+}
+
+/// 棋盘上玩家能看到的全部信息：配置和每个格子的可见内容，不含地雷布局
+#[derive(Clone, Debug)]
+pub struct VisibleBoard {
+    config: BoardConfig,
+    // 按行优先排列
+    cells: Vec<VisibleCell>,
+}
+
+impl VisibleBoard {
+    pub fn of(board: &Board) -> Self {
+        let config = *board.get_board_config();
+        let width = config.board_size.width;
+        let cells = (0..config.board_size.cell_count())
+            .map(|index| {
+                VisibleCell::of(
+                    board,
+                    Position {
+                        row: index / width,
+                        col: index % width,
+                    },
+                )
+            })
+            .collect();
+        VisibleBoard { config, cells }
+    }
+
+    pub fn config(&self) -> &BoardConfig {
+        &self.config
+    }
+
+    pub fn cell(&self, pos: Position) -> VisibleCell {
+        self.cells[pos.row * self.config.board_size.width + pos.col]
+    }
+
+    /// 按行优先排列的全部格子
+    pub fn cells(&self) -> &[VisibleCell] {
+        &self.cells
+    }
+
+    /// 按行优先顺序的隐藏格子
+    pub fn hidden(&self) -> impl Iterator<Item = Position> + '_ {
+        let width = self.config.board_size.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| **cell == VisibleCell::Hidden)
+            .map(move |(index, _)| Position {
+                row: index / width,
+                col: index % width,
+            })
+    }
+}
+
+// 一个数字给出的约束：cells 中恰好有 mines 颗地雷
+#[derive(Clone, PartialEq, Eq)]
+struct Constraint {
+    cells: Vec<usize>,
+    mines: usize,
+}
+
+/// 推理结果
+pub struct Analysis {
+    width: usize,
+    known_mines: Vec<Position>,
+    known_safe: Vec<Position>,
+    // 按行优先排列；非隐藏格子为 None
+    probabilities: Vec<Option<f64>>,
+}
+
+impl Analysis {
+    /// 必然是地雷、但还没有插旗或翻开的格子
+    pub fn known_mines(&self) -> &[Position] {
+        &self.known_mines
+    }
+
+    /// 必然安全的隐藏格子
+    pub fn known_safe(&self) -> &[Position] {
+        &self.known_safe
+    }
+
+    /// 隐藏格子是地雷的估计概率；已翻开或已标记的格子返回 None
+    pub fn probability(&self, pos: Position) -> Option<f64> {
+        self.probabilities[pos.row * self.width + pos.col]
+    }
+
+    /// 最可能是地雷的隐藏格子
+    pub fn most_likely_mine(&self) -> Option<Position> {
+        self.extreme(|a, b| a > b)
+    }
+
+    /// 最不可能是地雷的隐藏格子，即最好的猜测
+    pub fn safest(&self) -> Option<Position> {
+        self.extreme(|a, b| a < b)
+    }
+
+    // 按行优先顺序找出概率最符合 better 的格子，相同时取先出现的
+    fn extreme(&self, better: impl Fn(f64, f64) -> bool) -> Option<Position> {
+        let mut best: Option<(usize, f64)> = None;
+        for (index, probability) in self.probabilities.iter().enumerate() {
+            if let Some(p) = *probability
+                && best.is_none_or(|(_, best_p)| better(p, best_p))
+            {
+                best = Some((index, p));
+            }
+        }
+        best.map(|(index, _)| Position {
+            row: index / self.width,
+            col: index % self.width,
+        })
+    }
+}
+
+/// 分析棋盘的可见信息
+pub fn analyze(board: &Board) -> Analysis {
+    analyze_visible(&VisibleBoard::of(board))
+}
+
+/// 分析可见信息，供只拿到 VisibleBoard 的调用者（例如机器人）使用
+pub fn analyze_visible(view: &VisibleBoard) -> Analysis {
+    let config = &view.config;
+    let width = config.board_size.width;
+    let to_pos = |index: usize| Position {
+        row: index / width,
+        col: index % width,
+    };
+    let visible = &view.cells;
+
+    // 数字约束：只在经典规则下有效
+    let mut constraints = Vec::new();
+    if config.mines_per_cell == 1 && config.negative_mines == 0 {
+        for (index, cell) in visible.iter().enumerate() {
+            let VisibleCell::Number(n) = *cell else {
+                continue;
+            };
+            let mut cells = Vec::new();
+            let mut flagged = 0;
+            for adj in config.neighbours(to_pos(index), true) {
+                let adj_index = adj.row * width + adj.col;
+                match visible[adj_index] {
+                    VisibleCell::Hidden => cells.push(adj_index),
+                    cell if cell.is_known_mine() => flagged += 1,
+                    _ => {}
+                }
+            }
+            if !cells.is_empty() {
+                let mines = usize::try_from(i32::from(n) - flagged).unwrap_or(0);
+                constraints.push(Constraint { cells, mines });
+            }
+        }
+    }
+
+    let (mines, safe) = deduce(&mut constraints);
+
+    let known_visible = visible.iter().filter(|cell| cell.is_known_mine()).count();
+    let hidden_unknown = visible
+        .iter()
+        .enumerate()
+        .filter(|&(index, cell)| {
+            *cell == VisibleCell::Hidden && !mines.contains(&index) && !safe.contains(&index)
+        })
+        .count();
+    let mines_left = config
+        .mine_count
+        .saturating_sub(known_visible + mines.len()) as f64;
+    let density = if hidden_unknown == 0 {
+        0.0
+    } else {
+        (mines_left / hidden_unknown as f64).min(1.0)
+    };
+
+    // 边界格子取所在约束中最高的比例，其他隐藏格子取剩余地雷密度
+    let mut frontier: Vec<Option<f64>> = vec![None; visible.len()];
+    for constraint in &constraints {
+        let ratio = constraint.mines as f64 / constraint.cells.len() as f64;
+        for &index in &constraint.cells {
+            frontier[index] = Some(frontier[index].map_or(ratio, |p: f64| p.max(ratio)));
+        }
+    }
+    let mut probabilities: Vec<Option<f64>> = visible
+        .iter()
+        .zip(&frontier)
+        .map(|(cell, p)| (*cell == VisibleCell::Hidden).then(|| p.unwrap_or(density)))
+        .collect();
+    for &index in &mines {
+        probabilities[index] = Some(1.0);
+    }
+    for &index in &safe {
+        probabilities[index] = Some(0.0);
+    }
+
+    let mut known_mines: Vec<usize> = mines.into_iter().collect();
+    let mut known_safe: Vec<usize> = safe.into_iter().collect();
+    known_mines.sort_unstable();
+    known_safe.sort_unstable();
+
+    Analysis {
+        width,
+        known_mines: known_mines.into_iter().map(to_pos).collect(),
+        known_safe: known_safe.into_iter().map(to_pos).collect(),
+        probabilities,
+    }
+}
+
+// 反复应用单约束规则和子集规则，直到推不出新结论；
+// 推出的格子会从约束中移除，剩下的约束只包含未确定的格子
+fn deduce(constraints: &mut Vec<Constraint>) -> (HashSet<usize>, HashSet<usize>) {
+    let mut mines = HashSet::new();
+    let mut safe = HashSet::new();
+
+    loop {
+        let mut found_mines = Vec::new();
+        let mut found_safe = Vec::new();
+
+        for constraint in constraints.iter() {
+            if constraint.mines == 0 {
+                found_safe.extend(constraint.cells.iter().copied());
+            } else if constraint.mines == constraint.cells.len() {
+                found_mines.extend(constraint.cells.iter().copied());
+            }
+        }
+
+        // 子集规则：A 的格子都在 B 中时，B 多出的格子里恰好有 B.mines - A.mines 颗地雷；
+        // 只比较共享格子的约束
+        if found_mines.is_empty() && found_safe.is_empty() {
+            let mut by_cell: HashMap<usize, Vec<usize>> = HashMap::new();
+            for (index, constraint) in constraints.iter().enumerate() {
+                for &cell in &constraint.cells {
+                    by_cell.entry(cell).or_default().push(index);
+                }
+            }
+            for a in constraints.iter() {
+                for &b_index in &by_cell[&a.cells[0]] {
+                    let b = &constraints[b_index];
+                    if a.cells.len() >= b.cells.len()
+                        || b.mines < a.mines
+                        || !a.cells.iter().all(|cell| b.cells.contains(cell))
+                    {
+                        continue;
+                    }
+                    let rest = b
+                        .cells
+                        .iter()
+                        .copied()
+                        .filter(|cell| !a.cells.contains(cell));
+                    let rest_count = b.cells.len() - a.cells.len();
+                    let rest_mines = b.mines - a.mines;
+                    if rest_mines == 0 {
+                        found_safe.extend(rest);
+                    } else if rest_mines == rest_count {
+                        found_mines.extend(rest);
+                    }
+                }
+            }
+        }
+
+        let before = mines.len() + safe.len();
+        mines.extend(found_mines);
+        safe.extend(found_safe);
+        if mines.len() + safe.len() == before {
+            break;
+        }
+
+        // 把新确定的格子代入约束
+        for constraint in constraints.iter_mut() {
+            let flagged = constraint
+                .cells
+                .iter()
+                .filter(|cell| mines.contains(cell))
+                .count();
+            constraint.mines = constraint.mines.saturating_sub(flagged);
+            constraint
+                .cells
+                .retain(|cell| !mines.contains(cell) && !safe.contains(cell));
+        }
+        constraints.retain(|constraint| !constraint.cells.is_empty());
+        constraints.dedup();
+    }
+
+    (mines, safe)
+}
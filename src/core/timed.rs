@@ -0,0 +1,378 @@
+//! 计时模式：倒计时和限时挑战
+//!
+//! TimedChallenge 持有时钟，每次操作前由引擎自己判断时间是否耗尽；
+//! 命令行只负责显示剩余时间和转发指令。成绩按模式保存在 TimedRecords 中。
+
+use crate::core::{Board, BoardConfig, ClickResult, Clock, Position};
+use crate::t;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimedMode {
+    /// 倒计时：在时间耗尽前清空棋盘，每次展开空白区域获得加时
+    Countdown,
+    /// 限时挑战：在固定时间内连续清空尽可能多的种子棋盘
+    TimeAttack,
+}
+
+impl TimedMode {
+    pub const ALL: [TimedMode; 2] = [TimedMode::Countdown, TimedMode::TimeAttack];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TimedMode::Countdown => t!("timed.countdown"),
+            TimedMode::TimeAttack => t!("timed.time_attack"),
+        }
+    }
+
+    // 成绩文件中使用的名字
+    fn key(self) -> &'static str {
+        match self {
+            TimedMode::Countdown => "countdown",
+            TimedMode::TimeAttack => "time_attack",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.key() == key)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TimedConfig {
+    pub mode: TimedMode,
+    pub board_config: BoardConfig,
+    /// 开局时的可用时间
+    pub time_limit: Duration,
+    /// 每次展开空白区域奖励的时间，为 0 时不加时
+    pub bonus_per_opening: Duration,
+    /// 第 n 块棋盘（从 0 开始）使用 seed + n 作为布雷种子
+    pub seed: u64,
+}
+
+/// 一局计时挑战的结束方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimedOutcome {
+    /// 倒计时模式下清空了棋盘
+    Cleared,
+    MineHit,
+    TimeUp,
+}
+
+impl TimedOutcome {
+    fn key(self) -> &'static str {
+        match self {
+            TimedOutcome::Cleared => "cleared",
+            TimedOutcome::MineHit => "mine",
+            TimedOutcome::TimeUp => "timeout",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        [
+            TimedOutcome::Cleared,
+            TimedOutcome::MineHit,
+            TimedOutcome::TimeUp,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.key() == key)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimedResult {
+    pub mode: TimedMode,
+    pub outcome: TimedOutcome,
+    pub boards_cleared: u32,
+    /// 所有棋盘上翻开的安全格子总数
+    pub cells_revealed: usize,
+    /// 实际用时，超时的局记为截止时间
+    pub elapsed: Duration,
+}
+
+impl TimedResult {
+    /// 同一模式下是否优于另一个成绩：
+    /// 倒计时先比是否清空、再比用时；限时挑战先比清空的棋盘数、再比翻开的格子数
+    pub fn is_better_than(&self, other: &TimedResult) -> bool {
+        match self.mode {
+            TimedMode::Countdown => {
+                let cleared = self.outcome == TimedOutcome::Cleared;
+                let other_cleared = other.outcome == TimedOutcome::Cleared;
+                match (cleared, other_cleared) {
+                    (true, true) => self.elapsed < other.elapsed,
+                    (false, false) => self.cells_revealed > other.cells_revealed,
+                    (cleared, _) => cleared,
+                }
+            }
+            TimedMode::TimeAttack => {
+                (self.boards_cleared, self.cells_revealed)
+                    > (other.boards_cleared, other.cells_revealed)
+            }
+        }
+    }
+}
+
+/// 一次操作在计时挑战中的结果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimedEvent {
+    Continue,
+    /// 展开了空白区域并获得加时
+    Opening(Duration),
+    /// 限时挑战中清空了一块棋盘，已换上下一块；参数为累计清空的棋盘数
+    BoardCleared(u32),
+    Finished(TimedResult),
+    Invalid,
+}
+
+/// 计时挑战引擎
+pub struct TimedChallenge<C: Clock> {
+    config: TimedConfig,
+    clock: C,
+    board: Board,
+    // 截止时刻（以时钟起点计），加时会把它推后
+    deadline: Duration,
+    boards_cleared: u32,
+    // 已清空的棋盘上翻开的格子数
+    cells_revealed_before: usize,
+    result: Option<TimedResult>,
+}
+
+impl<C: Clock> TimedChallenge<C> {
+    pub fn new(config: TimedConfig, clock: C) -> Self {
+        let deadline = clock.elapsed() + config.time_limit;
+        TimedChallenge {
+            board: Board::with_seed(config.board_config, config.seed),
+            config,
+            clock,
+            deadline,
+            boards_cleared: 0,
+            cells_revealed_before: 0,
+            result: None,
+        }
+    }
+
+    pub fn config(&self) -> &TimedConfig {
+        &self.config
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn boards_cleared(&self) -> u32 {
+        self.boards_cleared
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.deadline.saturating_sub(self.clock.elapsed())
+    }
+
+    pub fn result(&self) -> Option<TimedResult> {
+        self.result
+    }
+
+    /// 读取时钟，时间耗尽时结束挑战；返回已结束挑战的成绩
+    pub fn tick(&mut self) -> Option<TimedResult> {
+        if self.result.is_none() && self.remaining().is_zero() {
+            self.finish(TimedOutcome::TimeUp);
+        }
+        self.result
+    }
+
+    pub fn left_click(&mut self, pos: Position) -> TimedEvent {
+        if let Some(result) = self.tick() {
+            return TimedEvent::Finished(result);
+        }
+
+        let revealed_before = self.board.get_revealed_count();
+        match self.board.left_click(pos) {
+            ClickResult::Invalid => TimedEvent::Invalid,
+            ClickResult::GameOver => {
+                self.board.reveal_all_mines();
+                TimedEvent::Finished(self.finish(TimedOutcome::MineHit))
+            }
+            ClickResult::Victory => match self.config.mode {
+                TimedMode::Countdown => {
+                    self.boards_cleared = 1;
+                    TimedEvent::Finished(self.finish(TimedOutcome::Cleared))
+                }
+                TimedMode::TimeAttack => {
+                    self.next_board();
+                    TimedEvent::BoardCleared(self.boards_cleared)
+                }
+            },
+            ClickResult::Continue => {
+                // 一次翻开多个格子说明展开了空白区域
+                let bonus = self.config.bonus_per_opening;
+                if self.board.get_revealed_count() - revealed_before > 1 && !bonus.is_zero() {
+                    self.deadline += bonus;
+                    TimedEvent::Opening(bonus)
+                } else {
+                    TimedEvent::Continue
+                }
+            }
+        }
+    }
+
+    pub fn right_click(&mut self, pos: Position) -> TimedEvent {
+        if let Some(result) = self.tick() {
+            return TimedEvent::Finished(result);
+        }
+
+        match self.board.right_click(pos) {
+            ClickResult::Invalid => TimedEvent::Invalid,
+            _ => TimedEvent::Continue,
+        }
+    }
+
+    fn next_board(&mut self) {
+        self.cells_revealed_before += self.board.get_revealed_count();
+        self.boards_cleared += 1;
+        let seed = self
+            .config
+            .seed
+            .wrapping_add(u64::from(self.boards_cleared));
+        self.board = Board::with_seed(self.config.board_config, seed);
+    }
+
+    fn finish(&mut self, outcome: TimedOutcome) -> TimedResult {
+        let result = TimedResult {
+            mode: self.config.mode,
+            outcome,
+            boards_cleared: self.boards_cleared,
+            cells_revealed: self.cells_revealed_before + self.board.get_revealed_count(),
+            elapsed: self.clock.elapsed().min(self.deadline),
+        };
+        self.result = Some(result);
+        result
+    }
+}
+
+#[derive(Debug)]
+pub enum RecordsError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for RecordsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordsError::Io(err) => write!(f, "{}", t!("timed.error_io", err)),
+            RecordsError::Parse { line, message } => {
+                write!(f, "{}", t!("error.line", line, message))
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecordsError {}
+
+impl From<io::Error> for RecordsError {
+    fn from(err: io::Error) -> Self {
+        RecordsError::Io(err)
+    }
+}
+
+/// 按模式分别保存的计时成绩
+///
+/// 文件每行一条成绩：`<模式> <结束方式> <清空棋盘数> <翻开格子数> <用时毫秒>`，
+/// `#` 开头的行是注释。
+#[derive(Debug, Default)]
+pub struct TimedRecords {
+    results: HashMap<TimedMode, Vec<TimedResult>>,
+}
+
+impl TimedRecords {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 读取成绩文件，文件不存在时返回空记录
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RecordsError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, RecordsError> {
+        let mut records = Self::new();
+        for (index, raw_line) in text.lines().enumerate() {
+            let line = index + 1;
+            let content = raw_line.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            let parse_error = |message: String| RecordsError::Parse { line, message };
+            let fields: Vec<&str> = content.split_whitespace().collect();
+            let [mode, outcome, boards, cells, millis] = fields[..] else {
+                return Err(parse_error(t!("timed.error_fields", fields.len())));
+            };
+            let number = |token: &str| {
+                token
+                    .parse::<u64>()
+                    .map_err(|_| parse_error(t!("error.number", token)))
+            };
+
+            records.record(TimedResult {
+                mode: TimedMode::from_key(mode)
+                    .ok_or_else(|| parse_error(t!("timed.error_mode", mode)))?,
+                outcome: TimedOutcome::from_key(outcome)
+                    .ok_or_else(|| parse_error(t!("timed.error_outcome", outcome)))?,
+                boards_cleared: u32::try_from(number(boards)?)
+                    .map_err(|_| parse_error(t!("timed.error_boards", boards)))?,
+                cells_revealed: number(cells)? as usize,
+                elapsed: Duration::from_millis(number(millis)?),
+            });
+        }
+        Ok(records)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), RecordsError> {
+        fs::write(path, self.to_text())?;
+        Ok(())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# 模式 结束方式 清空棋盘数 翻开格子数 用时毫秒\n");
+        for mode in TimedMode::ALL {
+            for result in self.results(mode) {
+                text.push_str(&format!(
+                    "{} {} {} {} {}\n",
+                    mode.key(),
+                    result.outcome.key(),
+                    result.boards_cleared,
+                    result.cells_revealed,
+                    result.elapsed.as_millis()
+                ));
+            }
+        }
+        text
+    }
+
+    pub fn record(&mut self, result: TimedResult) {
+        self.results.entry(result.mode).or_default().push(result);
+    }
+
+    /// 某个模式下的全部成绩，按记录顺序排列
+    pub fn results(&self, mode: TimedMode) -> &[TimedResult] {
+        self.results.get(&mode).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn best(&self, mode: TimedMode) -> Option<&TimedResult> {
+        self.results(mode).iter().reduce(|best, result| {
+            if result.is_better_than(best) {
+                result
+            } else {
+                best
+            }
+        })
+    }
+}
@@ -0,0 +1,229 @@
+use crate::config::timed::RECORDS_FILE;
+use crate::core::{
+    Position, SystemClock, TimedChallenge, TimedConfig, TimedEvent, TimedMode, TimedOutcome,
+    TimedRecords, TimedResult,
+};
+use crate::t;
+use std::io::{self, Write};
+
+/// 倒计时和限时挑战的命令行控制器；计时完全由引擎的时钟负责
+pub struct TimedGame {
+    challenge: TimedChallenge<SystemClock>,
+}
+
+impl TimedGame {
+    pub fn new(config: TimedConfig) -> Self {
+        TimedGame {
+            challenge: TimedChallenge::new(config, SystemClock::new()),
+        }
+    }
+
+    pub fn run(&mut self) {
+        self.print_welcome();
+        self.print_help();
+
+        while self.challenge.tick().is_none() {
+            self.print_board();
+
+            match self.get_user_input() {
+                Some(command) => {
+                    if !self.process_command(&command) {
+                        println!("{}", t!("game.bye"));
+                        return; // 中途退出不记录成绩
+                    }
+                }
+                None => {
+                    println!("{}", t!("game.invalid_input"));
+                    continue;
+                }
+            }
+        }
+
+        if let Some(result) = self.challenge.result() {
+            self.print_game_over(&result);
+            self.save_result(result);
+        }
+        println!("{}", t!("over.thanks"));
+    }
+
+    fn print_welcome(&self) {
+        let config = self.challenge.config();
+        println!("{}", t!("timed.welcome", config.mode.label()));
+        println!(
+            "{}",
+            t!(
+                "timed.config",
+                config.board_config.board_size.width,
+                config.board_config.board_size.height,
+                config.board_config.mine_count,
+                config.time_limit.as_secs()
+            )
+        );
+        match config.mode {
+            TimedMode::Countdown => println!(
+                "{}",
+                t!("timed.countdown_hint", config.bonus_per_opening.as_secs())
+            ),
+            TimedMode::TimeAttack => println!("{}", t!("timed.time_attack_hint")),
+        }
+        println!();
+    }
+
+    fn print_help(&self) {
+        println!("{}", t!("help.title"));
+        println!("{}", t!("help.click"));
+        println!("{}", t!("help.flag"));
+        println!("{}", t!("help.help"));
+        println!("{}", t!("timed.help_quit"));
+        println!("{}", t!("timed.help_time"));
+        println!();
+    }
+
+    fn print_board(&self) {
+        println!("{}", t!("game.board_title"));
+        self.challenge.board().print_debug();
+        let remaining = self.challenge.remaining();
+        print!(
+            "{}",
+            t!(
+                "timed.remaining",
+                remaining.as_secs(),
+                remaining.subsec_millis() / 100
+            )
+        );
+        if self.challenge.config().mode == TimedMode::TimeAttack {
+            print!(
+                "{}",
+                t!("timed.boards_cleared", self.challenge.boards_cleared())
+            );
+        }
+        println!();
+        println!();
+    }
+
+    fn get_user_input(&self) -> Option<String> {
+        print!("{}", t!("game.input_prompt"));
+        io::stdout().flush().ok()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).ok()?;
+
+        Some(input.trim().to_lowercase())
+    }
+
+    fn process_command(&mut self, command: &str) -> bool {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+
+        if parts.is_empty() {
+            return true;
+        }
+
+        match parts[0] {
+            "help" => {
+                self.print_help();
+            }
+            "quit" | "exit" => {
+                return false;
+            }
+            "click" | "flag" => match self.parse_coordinates(&parts[1..]) {
+                Some(pos) => {
+                    let event = if parts[0] == "click" {
+                        self.challenge.left_click(pos)
+                    } else {
+                        self.challenge.right_click(pos)
+                    };
+                    self.print_event(event);
+                }
+                None => println!("{}", t!("game.usage_position", parts[0])),
+            },
+            _ => {
+                println!("{}", t!("game.unknown_command", parts[0]));
+            }
+        }
+
+        true
+    }
+
+    fn parse_coordinates(&self, coords: &[&str]) -> Option<Position> {
+        let [row, col] = coords else {
+            return None;
+        };
+        let pos = Position {
+            row: row.parse().ok()?,
+            col: col.parse().ok()?,
+        };
+
+        let size = self.challenge.config().board_config.board_size;
+        if pos.row >= size.height || pos.col >= size.width {
+            println!(
+                "{}",
+                t!("game.out_of_range", size.height - 1, size.width - 1)
+            );
+            return None;
+        }
+
+        Some(pos)
+    }
+
+    fn print_event(&self, event: TimedEvent) {
+        match event {
+            TimedEvent::Continue => println!("{}", t!("timed.ok")),
+            TimedEvent::Opening(bonus) => {
+                println!("{}", t!("timed.bonus", bonus.as_secs()))
+            }
+            TimedEvent::BoardCleared(count) => {
+                println!("{}", t!("timed.board_cleared", count))
+            }
+            TimedEvent::Invalid => println!("{}", t!("click.invalid")),
+            // 结束信息统一在 print_game_over 中显示
+            TimedEvent::Finished(_) => {}
+        }
+    }
+
+    fn print_game_over(&self, result: &TimedResult) {
+        self.print_board();
+
+        match result.outcome {
+            TimedOutcome::Cleared => println!("{}", t!("timed.cleared")),
+            TimedOutcome::MineHit => println!("{}", t!("timed.mine_hit")),
+            TimedOutcome::TimeUp => println!("{}", t!("timed.time_up")),
+        }
+        println!(
+            "{}",
+            t!(
+                "timed.result",
+                result.boards_cleared,
+                result.cells_revealed,
+                format!("{:.1}", result.elapsed.as_secs_f64())
+            )
+        );
+    }
+
+    // 追加到成绩文件并显示该模式的最佳成绩
+    fn save_result(&self, result: TimedResult) {
+        let mut records = match TimedRecords::load(RECORDS_FILE) {
+            Ok(records) => records,
+            Err(err) => {
+                println!("{}", t!("timed.load_failed", RECORDS_FILE, err));
+                return;
+            }
+        };
+        records.record(result);
+        if let Err(err) = records.save(RECORDS_FILE) {
+            println!("{}", t!("timed.save_failed", RECORDS_FILE, err));
+        }
+
+        if let Some(best) = records.best(result.mode) {
+            println!(
+                "{}",
+                t!(
+                    "timed.best",
+                    result.mode.label(),
+                    best.boards_cleared,
+                    best.cells_revealed,
+                    format!("{:.1}", best.elapsed.as_secs_f64())
+                )
+            );
+        }
+    }
+}
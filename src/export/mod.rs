@@ -0,0 +1,317 @@
+//! 棋盘的图片导出
+//!
+//! 把任意棋盘状态导出为 SVG 或 PNG 图片，便于贴到聊天和问题报告中：
+//! - svg: 矢量图，格子、数字和行列号都是 SVG 元素
+//! - png: 纯 Rust 实现的光栅化和 PNG 编码，不依赖外部库
+//!
+//! 两种格式共用同一份布局（Scene）：每个格子的位置和外观、行列号的位置。
+//! 默认只导出玩家能看到的内容；可以选择显示隐藏的地雷、标出插错的旗子，
+//! 或者在隐藏格子上叠加求解器算出的地雷概率。六边形棋盘的奇数行右移半格，与文本输出一致。
+
+mod png;
+mod svg;
+
+pub use png::{Image, rasterize, to_png};
+pub use svg::to_svg;
+
+use crate::config::export::{CELL_SIZE, MIN_CELL_SIZE};
+use crate::core::{Board, BoardShape, CellContent, CellState, Position, analyze};
+use crate::t;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// 导出选项，默认只显示玩家能看到的内容
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExportOptions {
+    /// 显示隐藏格子下面的地雷
+    pub reveal_mines: bool,
+    /// 用红色叉号标出插错的旗子
+    pub mark_wrong_flags: bool,
+    /// 在隐藏格子上叠加求解器算出的地雷概率
+    pub probabilities: bool,
+    /// 每个格子的边长（像素），小于 MIN_CELL_SIZE 时按 MIN_CELL_SIZE 处理
+    pub cell_size: u32,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            reveal_mines: false,
+            mark_wrong_flags: false,
+            probabilities: false,
+            cell_size: CELL_SIZE,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// 显示地雷并标出插错的旗子，用于结束画面和问题报告
+    pub fn spoiler() -> Self {
+        ExportOptions {
+            reveal_mines: true,
+            mark_wrong_flags: true,
+            ..ExportOptions::default()
+        }
+    }
+}
+
+/// 图片格式，按文件扩展名选择
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Svg,
+    Png,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?;
+        if extension.eq_ignore_ascii_case("svg") {
+            Some(Format::Svg)
+        } else if extension.eq_ignore_ascii_case("png") {
+            Some(Format::Png)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    Io(io::Error),
+    /// 扩展名既不是 .svg 也不是 .png
+    UnsupportedFormat(String),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Io(err) => write!(f, "{}", t!("export.error_io", err)),
+            ExportError::UnsupportedFormat(path) => {
+                write!(f, "{}", t!("export.unsupported", path))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<io::Error> for ExportError {
+    fn from(err: io::Error) -> Self {
+        ExportError::Io(err)
+    }
+}
+
+/// 按扩展名把棋盘导出为 SVG 或 PNG 文件
+pub fn save(
+    board: &Board,
+    path: impl AsRef<Path>,
+    options: &ExportOptions,
+) -> Result<(), ExportError> {
+    let path = path.as_ref();
+    match Format::from_path(path) {
+        Some(Format::Svg) => fs::write(path, to_svg(board, options))?,
+        Some(Format::Png) => fs::write(path, to_png(board, options))?,
+        None => {
+            return Err(ExportError::UnsupportedFormat(path.display().to_string()));
+        }
+    }
+    Ok(())
+}
+
+type Rgb = (u8, u8, u8);
+
+const BACKGROUND: Rgb = (245, 245, 245);
+const GRID: Rgb = (150, 150, 150);
+const HIDDEN: Rgb = (172, 181, 194);
+const REVEALED: Rgb = (226, 226, 226);
+const REVEALED_MINE: Rgb = (240, 120, 120);
+const LABEL: Rgb = (110, 110, 110);
+const MINE: Rgb = (30, 30, 30);
+const FLAG: Rgb = (215, 35, 35);
+const POLE: Rgb = (60, 60, 60);
+/// 负雷、负雷旗和负数
+const NEGATIVE: Rgb = (30, 90, 200);
+const WRONG: Rgb = (200, 0, 0);
+const SAFE: Rgb = (60, 170, 70);
+const DANGER: Rgb = (220, 50, 50);
+/// 概率底色的不透明度
+const PROBABILITY_ALPHA: f64 = 0.55;
+
+// 经典扫雷的数字颜色，8 以上使用 8 的颜色
+const NUMBER_COLORS: [Rgb; 8] = [
+    (25, 25, 210),
+    (20, 125, 20),
+    (205, 20, 20),
+    (10, 10, 120),
+    (120, 10, 10),
+    (10, 120, 120),
+    (20, 20, 20),
+    (110, 110, 110),
+];
+
+fn number_color(n: i16) -> Rgb {
+    match n {
+        n if n < 0 => NEGATIVE,
+        1..=8 => NUMBER_COLORS[n as usize - 1],
+        _ => NUMBER_COLORS[7],
+    }
+}
+
+// 概率从 0 到 1 对应从绿到红
+fn probability_color(probability: f64) -> Rgb {
+    mix(SAFE, DANGER, probability)
+}
+
+// 按 amount（0-1）从 from 混合到 to
+fn mix(from: Rgb, to: Rgb, amount: f64) -> Rgb {
+    let amount = amount.clamp(0.0, 1.0);
+    let channel =
+        |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * amount).round() as u8;
+    (
+        channel(from.0, to.0),
+        channel(from.1, to.1),
+        channel(from.2, to.2),
+    )
+}
+
+fn hex_color((r, g, b): Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// 格子在图片上的外观
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Look {
+    Hidden,
+    /// 旗子数，负雷旗为负数；wrong 表示插错
+    Flag {
+        count: i8,
+        wrong: bool,
+    },
+    /// 地雷数，负雷为负数；revealed 为 false 表示导出时才显示的隐藏地雷
+    Mine {
+        count: i8,
+        revealed: bool,
+    },
+    Number(i16),
+}
+
+struct SceneCell {
+    /// 左上角的像素坐标
+    x: u32,
+    y: u32,
+    look: Look,
+    /// 隐藏格子是地雷的概率，只在选择叠加概率时存在
+    probability: Option<f64>,
+}
+
+struct Label {
+    /// 中心的像素坐标
+    x: u32,
+    y: u32,
+    text: String,
+}
+
+/// 两种格式共用的布局
+struct Scene {
+    width: u32,
+    height: u32,
+    cell_size: u32,
+    cells: Vec<SceneCell>,
+    labels: Vec<Label>,
+}
+
+// 行列号字符的宽度（按格子边长的比例），与 PNG 字体的宽高比一致
+fn label_char_width(cell_size: u32) -> u32 {
+    cell_size / 3
+}
+
+impl Scene {
+    fn of(board: &Board, options: &ExportOptions) -> Scene {
+        let config = board.get_board_config();
+        let size = config.board_size;
+        let cell_size = options.cell_size.max(MIN_CELL_SIZE);
+        let hex = config.shape == BoardShape::Hex;
+
+        // 左侧留出最长行号的宽度，上方留出一行列号
+        let row_digits = size.height.saturating_sub(1).to_string().len() as u32;
+        let left = (row_digits + 1) * label_char_width(cell_size) + cell_size / 4;
+        let top = cell_size;
+        let shift = if hex { cell_size / 2 } else { 0 };
+        let width = left + size.width as u32 * cell_size + shift + cell_size / 4;
+        let height = top + size.height as u32 * cell_size + cell_size / 4;
+
+        let analysis = options.probabilities.then(|| analyze(board));
+        let spoiler = board.spoiler();
+        let wrong_flags: Vec<Position> = if options.mark_wrong_flags {
+            spoiler.wrong_flags().collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut cells = Vec::with_capacity(size.cell_count());
+        for row in 0..size.height {
+            let indent = if row % 2 == 1 { shift } else { 0 };
+            for col in 0..size.width {
+                let pos = Position { row, col };
+                let cell = board.cell_at(pos);
+                let look = match cell.state() {
+                    CellState::Flagged => Look::Flag {
+                        count: cell.flag_count(),
+                        wrong: wrong_flags.contains(&pos),
+                    },
+                    CellState::Revealed => match cell.content() {
+                        CellContent::Mine(count) => Look::Mine {
+                            count,
+                            revealed: true,
+                        },
+                        CellContent::Number(n) => Look::Number(n),
+                    },
+                    CellState::Hidden => match spoiler.content(pos) {
+                        CellContent::Mine(count) if options.reveal_mines => Look::Mine {
+                            count,
+                            revealed: false,
+                        },
+                        _ => Look::Hidden,
+                    },
+                };
+                let probability = match look {
+                    Look::Hidden => analysis.as_ref().and_then(|a| a.probability(pos)),
+                    _ => None,
+                };
+                cells.push(SceneCell {
+                    x: left + indent + col as u32 * cell_size,
+                    y: top + row as u32 * cell_size,
+                    look,
+                    probability,
+                });
+            }
+        }
+
+        // 列号太宽时每隔几列写一个，避免互相重叠
+        let col_digits = size.width.saturating_sub(1).to_string().len() as u32;
+        let step = ((col_digits + 1) * label_char_width(cell_size)).div_ceil(cell_size) as usize;
+        let mut labels: Vec<Label> = (0..size.width)
+            .step_by(step.max(1))
+            .map(|col| Label {
+                x: left + col as u32 * cell_size + cell_size / 2,
+                y: top / 2,
+                text: col.to_string(),
+            })
+            .collect();
+        labels.extend((0..size.height).map(|row| Label {
+            x: left / 2,
+            y: top + row as u32 * cell_size + cell_size / 2,
+            text: row.to_string(),
+        }));
+
+        Scene {
+            width,
+            height,
+            cell_size,
+            cells,
+            labels,
+        }
+    }
+}
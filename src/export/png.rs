@@ -0,0 +1,537 @@
+//! PNG 导出：把布局光栅化为 RGB 像素，再编码为 PNG
+//!
+//! 数字和行列号使用内置的 3x5 点阵字体按比例放大。压缩使用固定哈夫曼编码的 DEFLATE 和简单的
+//! LZ77 匹配，对棋盘这种大片纯色的图片足够有效，不需要外部库。
+
+use super::{
+    BACKGROUND, ExportOptions, FLAG, GRID, HIDDEN, LABEL, Look, MINE, NEGATIVE, POLE,
+    PROBABILITY_ALPHA, REVEALED, REVEALED_MINE, Rgb, Scene, SceneCell, WRONG, label_char_width,
+    mix, number_color, probability_color,
+};
+use crate::core::Board;
+
+/// RGB 图片，像素按行优先排列
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    fn new(width: u32, height: u32, color: Rgb) -> Self {
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 3);
+        for _ in 0..width as usize * height as usize {
+            pixels.extend_from_slice(&[color.0, color.1, color.2]);
+        }
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// 像素的颜色；超出图片时返回 None
+    pub fn pixel(&self, x: u32, y: u32) -> Option<(u8, u8, u8)> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 3;
+        Some((
+            self.pixels[index],
+            self.pixels[index + 1],
+            self.pixels[index + 2],
+        ))
+    }
+
+    // 超出图片的像素直接忽略
+    fn set(&mut self, x: i64, y: i64, color: Rgb) {
+        if x < 0 || y < 0 || x >= i64::from(self.width) || y >= i64::from(self.height) {
+            return;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 3;
+        self.pixels[index..index + 3].copy_from_slice(&[color.0, color.1, color.2]);
+    }
+
+    fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: Rgb) {
+        for py in y..y + height {
+            for px in x..x + width {
+                self.set(i64::from(px), i64::from(py), color);
+            }
+        }
+    }
+
+    // 以 alpha 的不透明度把 color 叠加到矩形上
+    fn blend_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: Rgb, alpha: f64) {
+        for py in y..y + height {
+            for px in x..x + width {
+                if let Some(below) = self.pixel(px, py) {
+                    self.set(i64::from(px), i64::from(py), mix(below, color, alpha));
+                }
+            }
+        }
+    }
+
+    // 一像素宽的矩形边框
+    fn stroke_rect(&mut self, x: u32, y: u32, size: u32, color: Rgb) {
+        self.fill_rect(x, y, size, 1, color);
+        self.fill_rect(x, y + size - 1, size, 1, color);
+        self.fill_rect(x, y, 1, size, color);
+        self.fill_rect(x + size - 1, y, 1, size, color);
+    }
+
+    fn fill_circle(&mut self, cx: u32, cy: u32, radius: u32, color: Rgb) {
+        let r = i64::from(radius);
+        for dy in -r..=r {
+            for dx in -r..=r {
+                if dx * dx + dy * dy <= r * r {
+                    self.set(i64::from(cx) + dx, i64::from(cy) + dy, color);
+                }
+            }
+        }
+    }
+
+    // 三角形：逐个检查外接矩形中的像素是否在三条边的同一侧
+    fn fill_triangle(&mut self, points: [(i64, i64); 3], color: Rgb) {
+        let edge = |(ax, ay): (i64, i64), (bx, by): (i64, i64), (px, py): (i64, i64)| {
+            (bx - ax) * (py - ay) - (by - ay) * (px - ax)
+        };
+        let [a, b, c] = points;
+        let xs = [a.0, b.0, c.0];
+        let ys = [a.1, b.1, c.1];
+        for y in *ys.iter().min().unwrap_or(&0)..=*ys.iter().max().unwrap_or(&0) {
+            for x in *xs.iter().min().unwrap_or(&0)..=*xs.iter().max().unwrap_or(&0) {
+                let p = (x, y);
+                let (d1, d2, d3) = (edge(a, b, p), edge(b, c, p), edge(c, a, p));
+                let negative = d1 < 0 || d2 < 0 || d3 < 0;
+                let positive = d1 > 0 || d2 > 0 || d3 > 0;
+                if !(negative && positive) {
+                    self.set(x, y, color);
+                }
+            }
+        }
+    }
+
+    // 粗线：沿线段每一步画一个 width x width 的方块
+    fn line(&mut self, from: (i64, i64), to: (i64, i64), width: u32, color: Rgb) {
+        let steps = (to.0 - from.0).abs().max((to.1 - from.1).abs()).max(1);
+        let half = i64::from(width / 2);
+        for step in 0..=steps {
+            let x = from.0 + (to.0 - from.0) * step / steps;
+            let y = from.1 + (to.1 - from.1) * step / steps;
+            for dy in 0..i64::from(width) {
+                for dx in 0..i64::from(width) {
+                    self.set(x - half + dx, y - half + dy, color);
+                }
+            }
+        }
+    }
+
+    // 以 (cx, cy) 为中心写一行字，scale 为点阵每个点的像素数
+    fn text(&mut self, cx: u32, cy: u32, scale: u32, color: Rgb, text: &str) {
+        let scale = scale.max(1);
+        let advance = 4 * scale;
+        let width = (text.chars().count() as u32 * advance).saturating_sub(scale);
+        let left = i64::from(cx) - i64::from(width / 2);
+        let top = i64::from(cy) - i64::from(5 * scale / 2);
+        for (index, ch) in text.chars().enumerate() {
+            let Some(rows) = glyph(ch) else {
+                continue;
+            };
+            let x0 = left + (index as u32 * advance) as i64;
+            for (row, bits) in rows.iter().enumerate() {
+                for col in 0..3 {
+                    if bits & (0b100 >> col) == 0 {
+                        continue;
+                    }
+                    for dy in 0..i64::from(scale) {
+                        for dx in 0..i64::from(scale) {
+                            let x = x0 + i64::from(col * scale) + dx;
+                            let y = top + (row as u32 * scale) as i64 + dy;
+                            self.set(x, y, color);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// 3x5 点阵字体，每行三位，高位在左
+fn glyph(ch: char) -> Option<[u8; 5]> {
+    Some(match ch {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        _ => return None,
+    })
+}
+
+/// 把棋盘光栅化为 RGB 图片
+pub fn rasterize(board: &Board, options: &ExportOptions) -> Image {
+    let scene = Scene::of(board, options);
+    let mut image = Image::new(scene.width, scene.height, BACKGROUND);
+    let label_scale = label_char_width(scene.cell_size) / 4;
+    for label in &scene.labels {
+        image.text(label.x, label.y, label_scale, LABEL, &label.text);
+    }
+    for cell in &scene.cells {
+        draw_cell(&mut image, cell, scene.cell_size);
+    }
+    image
+}
+
+fn draw_cell(image: &mut Image, cell: &SceneCell, size: u32) {
+    let (x, y) = (cell.x, cell.y);
+    let (cx, cy) = (x + size / 2, y + size / 2);
+    let background = match cell.look {
+        Look::Number(_) => REVEALED,
+        Look::Mine { revealed: true, .. } => REVEALED_MINE,
+        _ => HIDDEN,
+    };
+    image.fill_rect(x, y, size, size, background);
+    image.stroke_rect(x, y, size, GRID);
+
+    if let Some(probability) = cell.probability {
+        image.blend_rect(
+            x + 1,
+            y + 1,
+            size - 2,
+            size - 2,
+            probability_color(probability),
+            PROBABILITY_ALPHA,
+        );
+        let percent = format!("{}", (probability * 100.0).round());
+        image.text(cx, cy, (size / 12).max(1), POLE, &percent);
+    }
+
+    let (x, y) = (i64::from(x), i64::from(y));
+    let size_i = i64::from(size);
+    match cell.look {
+        Look::Hidden | Look::Number(0) => {}
+        Look::Number(n) => image.text(cx, cy, (size / 8).max(1), number_color(n), &n.to_string()),
+        Look::Mine { count, .. } => {
+            let color = if count < 0 { NEGATIVE } else { MINE };
+            image.fill_circle(cx, cy, size * 7 / 25, color);
+            draw_badge(image, cell, size, count);
+        }
+        Look::Flag { count, wrong } => {
+            let color = if count < 0 { NEGATIVE } else { FLAG };
+            let pole = x + size_i * 2 / 5;
+            image.line(
+                (pole, y + size_i / 5),
+                (pole, y + size_i * 4 / 5),
+                (size / 12).max(1),
+                POLE,
+            );
+            image.fill_triangle(
+                [
+                    (pole, y + size_i / 5),
+                    (x + size_i * 4 / 5, y + size_i * 7 / 20),
+                    (pole, y + size_i / 2),
+                ],
+                color,
+            );
+            draw_badge(image, cell, size, count);
+            if wrong {
+                let inset = size_i / 6;
+                let (a, b) = (x + inset, y + inset);
+                let (c, d) = (x + size_i - inset, y + size_i - inset);
+                let width = (size / 10).max(2);
+                image.line((a, b), (c, d), width, WRONG);
+                image.line((c, b), (a, d), width, WRONG);
+            }
+        }
+    }
+}
+
+// 多雷格子和多面旗子在右下角标出数量
+fn draw_badge(image: &mut Image, cell: &SceneCell, size: u32, count: i8) {
+    if count.unsigned_abs() > 1 {
+        image.text(
+            cell.x + size * 4 / 5,
+            cell.y + size * 4 / 5,
+            (size / 16).max(1),
+            POLE,
+            &count.unsigned_abs().to_string(),
+        );
+    }
+}
+
+/// 把棋盘导出为 PNG 文件的内容
+pub fn to_png(board: &Board, options: &ExportOptions) -> Vec<u8> {
+    encode(&rasterize(board, options))
+}
+
+// PNG：文件签名、IHDR（8 位 RGB）、一个 IDAT 和 IEND
+fn encode(image: &Image) -> Vec<u8> {
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&image.width.to_be_bytes());
+    header.extend_from_slice(&image.height.to_be_bytes());
+    // 位深 8、颜色类型 2（RGB）、默认压缩、默认过滤、不隔行
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    // 每行前加过滤类型 0（不过滤）
+    let stride = image.width as usize * 3;
+    let mut raw = Vec::with_capacity((stride + 1) * image.height as usize);
+    for row in image.pixels.chunks(stride.max(1)) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib(&raw));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let mut crc = Crc32::new();
+    crc.update(kind);
+    crc.update(data);
+    png.extend_from_slice(&crc.finish().to_be_bytes());
+}
+
+struct Crc32 {
+    table: [u32; 256],
+    value: u32,
+}
+
+impl Crc32 {
+    fn new() -> Self {
+        let mut table = [0u32; 256];
+        for (n, entry) in table.iter_mut().enumerate() {
+            let mut c = n as u32;
+            for _ in 0..8 {
+                c = if c & 1 == 1 {
+                    0xEDB8_8320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                };
+            }
+            *entry = c;
+        }
+        Crc32 {
+            table,
+            value: 0xFFFF_FFFF,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.value =
+                self.table[((self.value ^ u32::from(byte)) & 0xFF) as usize] ^ (self.value >> 8);
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        self.value ^ 0xFFFF_FFFF
+    }
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+// zlib 格式：两字节头、一个固定哈夫曼编码的 DEFLATE 块和 Adler-32 校验
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+    // 最后一个块，类型 01（固定哈夫曼）
+    bits.write(1, 1);
+    bits.write(1, 2);
+    for token in lz77(data) {
+        match token {
+            Token::Literal(byte) => write_literal(&mut bits, u16::from(byte)),
+            Token::Match { length, distance } => write_match(&mut bits, length, distance),
+        }
+    }
+    write_literal(&mut bits, 256);
+
+    let mut out = vec![0x78, 0x01];
+    out.extend(bits.finish());
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const WINDOW: usize = 32768;
+const HASH_BITS: u32 = 15;
+// 每个位置最多比较的候选数，限制最坏情况的耗时
+const MAX_CHAIN: usize = 32;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+enum Token {
+    Literal(u8),
+    Match { length: usize, distance: usize },
+}
+
+// 贪心的 LZ77：用三字节哈希链在窗口内找最长的匹配
+fn lz77(data: &[u8]) -> Vec<Token> {
+    let hash = |i: usize| {
+        let value = u32::from(data[i]) << 16 | u32::from(data[i + 1]) << 8 | u32::from(data[i + 2]);
+        (value.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize
+    };
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut previous = vec![usize::MAX; data.len()];
+    let insert = |i: usize, head: &mut Vec<usize>, previous: &mut Vec<usize>| {
+        if i + MIN_MATCH <= data.len() {
+            let h = hash(i);
+            previous[i] = head[h];
+            head[h] = i;
+        }
+    };
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        let mut best = (0, 0);
+        if i + MIN_MATCH <= data.len() {
+            let mut candidate = head[hash(i)];
+            let limit = (data.len() - i).min(MAX_MATCH);
+            for _ in 0..MAX_CHAIN {
+                if candidate == usize::MAX || i - candidate > WINDOW {
+                    break;
+                }
+                let length = (0..limit)
+                    .take_while(|&k| data[candidate + k] == data[i + k])
+                    .count();
+                if length > best.0 {
+                    best = (length, i - candidate);
+                    if length == limit {
+                        break;
+                    }
+                }
+                candidate = previous[candidate];
+            }
+        }
+
+        if best.0 >= MIN_MATCH {
+            tokens.push(Token::Match {
+                length: best.0,
+                distance: best.1,
+            });
+            for k in i..i + best.0 {
+                insert(k, &mut head, &mut previous);
+            }
+            i += best.0;
+        } else {
+            tokens.push(Token::Literal(data[i]));
+            insert(i, &mut head, &mut previous);
+            i += 1;
+        }
+    }
+    tokens
+}
+
+// 固定哈夫曼编码的字面量 / 长度符号
+fn write_literal(bits: &mut BitWriter, symbol: u16) {
+    let (code, length) = match symbol {
+        0..=143 => (0x30 + symbol, 8),
+        144..=255 => (0x190 + symbol - 144, 9),
+        256..=279 => (symbol - 256, 7),
+        _ => (0xC0 + symbol - 280, 8),
+    };
+    bits.write_code(code, length);
+}
+
+fn write_match(bits: &mut BitWriter, length: usize, distance: usize) {
+    let index = LENGTH_BASE
+        .iter()
+        .rposition(|&base| usize::from(base) <= length)
+        .unwrap_or(0);
+    write_literal(bits, 257 + index as u16);
+    bits.write(
+        (length - usize::from(LENGTH_BASE[index])) as u32,
+        LENGTH_EXTRA[index],
+    );
+
+    let index = DISTANCE_BASE
+        .iter()
+        .rposition(|&base| usize::from(base) <= distance)
+        .unwrap_or(0);
+    bits.write_code(index as u16, 5);
+    bits.write(
+        (distance - usize::from(DISTANCE_BASE[index])) as u32,
+        DISTANCE_EXTRA[index],
+    );
+}
+
+/// DEFLATE 的位流：数值从低位开始写，哈夫曼码从高位开始写
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, count: u8) {
+        for bit in 0..count {
+            self.buffer |= ((value >> bit) & 1) << self.count;
+            self.count += 1;
+            if self.count == 8 {
+                self.bytes.push(self.buffer as u8);
+                self.buffer = 0;
+                self.count = 0;
+            }
+        }
+    }
+
+    fn write_code(&mut self, code: u16, length: u8) {
+        let reversed =
+            (0..length).fold(0u32, |acc, bit| (acc << 1) | ((u32::from(code) >> bit) & 1));
+        self.write(reversed, length);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
@@ -0,0 +1,182 @@
+//! SVG 导出：每个格子是一个矩形，数字、旗子、地雷和行列号是其上的 SVG 元素
+
+use super::{
+    BACKGROUND, ExportOptions, FLAG, GRID, HIDDEN, LABEL, Look, MINE, NEGATIVE, POLE,
+    PROBABILITY_ALPHA, REVEALED, REVEALED_MINE, Rgb, Scene, SceneCell, WRONG, hex_color,
+    number_color, probability_color,
+};
+use crate::core::Board;
+use std::fmt::Write;
+
+/// 把棋盘导出为 SVG 文本
+pub fn to_svg(board: &Board, options: &ExportOptions) -> String {
+    let scene = Scene::of(board, options);
+    let mut out = String::new();
+    // 写入 String 不会失败
+    let _ = write_scene(&scene, &mut out);
+    out
+}
+
+fn write_scene(scene: &Scene, out: &mut String) -> std::fmt::Result {
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="DejaVu Sans Mono, Menlo, Consolas, monospace">"#,
+        w = scene.width,
+        h = scene.height
+    )?;
+    writeln!(
+        out,
+        r#"<rect width="{}" height="{}" fill="{}"/>"#,
+        scene.width,
+        scene.height,
+        hex_color(BACKGROUND)
+    )?;
+    for label in &scene.labels {
+        write_text(
+            out,
+            label.x,
+            label.y,
+            scene.cell_size * 9 / 20,
+            LABEL,
+            false,
+            &label.text,
+        )?;
+    }
+    for cell in &scene.cells {
+        write_cell(out, cell, scene.cell_size)?;
+    }
+    writeln!(out, "</svg>")
+}
+
+fn write_text(
+    out: &mut String,
+    x: u32,
+    y: u32,
+    size: u32,
+    color: Rgb,
+    bold: bool,
+    text: &str,
+) -> std::fmt::Result {
+    writeln!(
+        out,
+        r#"<text x="{}" y="{}" font-size="{}"{} fill="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+        x,
+        y,
+        size,
+        if bold { r#" font-weight="bold""# } else { "" },
+        hex_color(color),
+        text
+    )
+}
+
+fn write_cell(out: &mut String, cell: &SceneCell, size: u32) -> std::fmt::Result {
+    let (x, y) = (cell.x, cell.y);
+    let (cx, cy) = (x + size / 2, y + size / 2);
+    let background = match cell.look {
+        Look::Number(_) => REVEALED,
+        Look::Mine { revealed: true, .. } => REVEALED_MINE,
+        _ => HIDDEN,
+    };
+    writeln!(
+        out,
+        r#"<rect x="{}" y="{}" width="{s}" height="{s}" fill="{}" stroke="{}"/>"#,
+        x,
+        y,
+        hex_color(background),
+        hex_color(GRID),
+        s = size
+    )?;
+
+    if let Some(probability) = cell.probability {
+        writeln!(
+            out,
+            r#"<rect x="{}" y="{}" width="{s}" height="{s}" fill="{}" fill-opacity="{}"/>"#,
+            x,
+            y,
+            hex_color(probability_color(probability)),
+            PROBABILITY_ALPHA,
+            s = size
+        )?;
+        let percent = format!("{}%", (probability * 100.0).round());
+        write_text(out, cx, cy, size * 7 / 20, POLE, false, &percent)?;
+    }
+
+    match cell.look {
+        Look::Hidden | Look::Number(0) => {}
+        Look::Number(n) => write_text(
+            out,
+            cx,
+            cy,
+            size * 3 / 5,
+            number_color(n),
+            true,
+            &n.to_string(),
+        )?,
+        Look::Mine { count, .. } => {
+            let color = if count < 0 { NEGATIVE } else { MINE };
+            writeln!(
+                out,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+                cx,
+                cy,
+                size * 7 / 25,
+                hex_color(color)
+            )?;
+            write_badge(out, cell, size, count)?;
+        }
+        Look::Flag { count, wrong } => {
+            let color = if count < 0 { NEGATIVE } else { FLAG };
+            let pole = x + size * 2 / 5;
+            writeln!(
+                out,
+                r#"<line x1="{p}" y1="{}" x2="{p}" y2="{}" stroke="{}" stroke-width="{}"/>"#,
+                y + size / 5,
+                y + size * 4 / 5,
+                hex_color(POLE),
+                (size / 12).max(1),
+                p = pole
+            )?;
+            writeln!(
+                out,
+                r#"<polygon points="{p},{} {},{} {p},{}" fill="{}"/>"#,
+                y + size / 5,
+                x + size * 4 / 5,
+                y + size * 7 / 20,
+                y + size / 2,
+                hex_color(color),
+                p = pole
+            )?;
+            write_badge(out, cell, size, count)?;
+            if wrong {
+                let inset = size / 6;
+                writeln!(
+                    out,
+                    r#"<path d="M{a} {b} L{c} {d} M{c} {b} L{a} {d}" stroke="{}" stroke-width="{}"/>"#,
+                    hex_color(WRONG),
+                    (size / 10).max(2),
+                    a = x + inset,
+                    b = y + inset,
+                    c = x + size - inset,
+                    d = y + size - inset
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// 多雷格子和多面旗子在右下角标出数量
+fn write_badge(out: &mut String, cell: &SceneCell, size: u32, count: i8) -> std::fmt::Result {
+    if count.unsigned_abs() > 1 {
+        write_text(
+            out,
+            cell.x + size * 4 / 5,
+            cell.y + size * 4 / 5,
+            size * 2 / 5,
+            POLE,
+            true,
+            &count.unsigned_abs().to_string(),
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,857 @@
+//! English message catalog
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // Main menu
+    ("menu.title", "🎮 Minesweeper is starting!"),
+    ("menu.prompt", "Choose a difficulty:"),
+    ("menu.easy", "1. Easy ({}x{}, {} mines)"),
+    ("menu.medium", "2. Medium ({}x{}, {} mines)"),
+    ("menu.hard", "3. Hard ({}x{}, {} mines)"),
+    ("menu.demo", "4. Demo (walk through the core features)"),
+    (
+        "menu.hex",
+        "5. Hexagonal ({}x{}, {} mines, 6 neighbours per cell)",
+    ),
+    (
+        "menu.cube",
+        "6. 3D ({}x{}x{}, {} mines, up to 26 neighbours per cell)",
+    ),
+    (
+        "menu.infinite",
+        "7. Infinite (no borders, mine density {}%, score by cells revealed before a mine)",
+    ),
+    (
+        "menu.graph",
+        "8. Graph (nodes and edges loaded from a file, default {})",
+    ),
+    (
+        "menu.neighbourhood",
+        "9. Neighbourhood variants (orthogonal / knight / radius 2 / custom offsets, easy size)",
+    ),
+    (
+        "menu.multi_mine",
+        "10. Multi-mine cells ({}x{}, {} mines in total, up to {} per cell, stackable flags)",
+    ),
+    (
+        "menu.negative_mine",
+        "11. Negative mines ({}x{}, {} mines, {} of them subtract one from nearby numbers)",
+    ),
+    (
+        "menu.lives",
+        "12. Lives ({}x{}, {} mines, {} lives by default, mines hit are flagged automatically)",
+    ),
+    (
+        "menu.timed",
+        "13. Timed (clear a medium board in {} seconds / clear easy boards back to back for {} seconds)",
+    ),
+    (
+        "menu.flags",
+        "14. Two players ({}x{}, {} mines, take turns finding mines, first to {} wins, computer opponent available)",
+    ),
+    (
+        "menu.versus",
+        "15. Online versus (race on the same medium board over TCP, {} lives each, default port {})",
+    ),
+    (
+        "menu.coop",
+        "16. Co-op (up to {} players clear one medium board over TCP, any mine loses for everyone, default port {})",
+    ),
+    ("menu.input", "Enter your choice (1-16): "),
+    (
+        "menu.input_error",
+        "❌ Could not read input, please try again",
+    ),
+    (
+        "menu.invalid_choice",
+        "❌ Invalid choice, please enter 1-16",
+    ),
+    ("menu.game_start", "\n🚀 Starting the game...\n"),
+    // Mode setup prompts
+    (
+        "prompt.graph_path",
+        "Enter a graph file path (press Enter for the default file): ",
+    ),
+    (
+        "prompt.lives",
+        "Enter the number of lives 1-{} (press Enter for the default {}): ",
+    ),
+    (
+        "prompt.timed_mode",
+        "Choose a timed mode (1. Countdown  2. Time attack): ",
+    ),
+    (
+        "prompt.flags_opponent",
+        "Choose an opponent (1. Another player at this terminal  2. Computer): ",
+    ),
+    (
+        "prompt.coop_players",
+        "Enter the number of players 1-{} (press Enter for the default {}): ",
+    ),
+    (
+        "prompt.room_role",
+        "Choose (1. Host a room  2. Join a room): ",
+    ),
+    ("prompt.player_name", "Enter your name: "),
+    (
+        "prompt.address",
+        "Enter the server address (press Enter for 127.0.0.1:{}): ",
+    ),
+    (
+        "prompt.neighbourhood",
+        "Choose a neighbourhood (1. Orthogonal 4  2. Knight 8  3. Radius 2, 24 cells  4. Custom): ",
+    ),
+    (
+        "prompt.custom_offsets",
+        "Enter offsets as row,col separated by spaces (e.g. -1,0 1,0 0,-2 0,2): ",
+    ),
+    ("default.player_name", "Player"),
+    ("default.player_number", "Player {}"),
+    // Menu errors and notices
+    ("error.graph_load", "❌ Failed to load graph file {}: {}"),
+    (
+        "error.invalid_lives",
+        "❌ Invalid number of lives, please enter 1-{}",
+    ),
+    (
+        "error.invalid_choice_2",
+        "❌ Invalid choice, please enter 1-2",
+    ),
+    (
+        "error.invalid_choice_4",
+        "❌ Invalid choice, please enter 1-4",
+    ),
+    (
+        "error.invalid_players",
+        "❌ Invalid number of players, please enter 1-{}",
+    ),
+    ("error.online", "❌ Could not play online at {}: {}"),
+    ("error.host", "❌ Could not host a room on port {}: {}"),
+    (
+        "error.custom_offsets",
+        "❌ The offset list is malformed, empty or longer than {}",
+    ),
+    ("error.bot_io", "❌ Bot protocol I/O failed: {}"),
+    ("error.line", "line {}: {}"),
+    ("error.number", "invalid number: {}"),
+    (
+        "error.settings",
+        "⚠️ Settings file {}, line {} not recognised: {}",
+    ),
+    (
+        "info.room_created",
+        "🌐 Room created, other players can connect to port {} on this machine",
+    ),
+    (
+        "tournament.title",
+        "🤖 Bot tournament: {} games per difficulty",
+    ),
+    // Names
+    ("difficulty.easy", "Easy"),
+    ("difficulty.medium", "Medium"),
+    ("difficulty.hard", "Hard"),
+    ("neighbourhood.moore", "8 neighbours"),
+    ("neighbourhood.orthogonal", "orthogonal 4"),
+    ("neighbourhood.knight", "knight moves"),
+    ("neighbourhood.radius2", "radius 2"),
+    ("neighbourhood.custom", "custom offsets"),
+    // Playing
+    ("game.welcome", "🎮 Welcome to command-line Minesweeper!"),
+    ("game.config", "📏 Board: {}x{}, {} mines"),
+    (
+        "game.neighbourhood_hint",
+        "💡 Neighbourhood: {}, numbers go up to {}",
+    ),
+    (
+        "game.multi_mine_hint",
+        "💡 Each cell holds up to {} mines, numbers count all adjacent mines and go up to {}",
+    ),
+    (
+        "game.negative_mine_hint",
+        "💡 {} of them are negative mines: they subtract one from nearby numbers, so numbers can be 0 or negative (shown inverted)",
+    ),
+    (
+        "game.letters_hint",
+        "💡 Numbers from 10 up are shown as letters (a=10, b=11, ...)",
+    ),
+    (
+        "game.lives_hint",
+        "❤️ You have {} lives: a mine you hit is flagged automatically and costs one life",
+    ),
+    ("game.board_title", "🗺️ Current board:"),
+    ("game.input_prompt", "Enter a command: "),
+    ("game.invalid_input", "❌ Invalid input, please try again"),
+    ("game.bye", "👋 Goodbye!"),
+    ("game.usage_click", "❌ Usage: click <row> <col>"),
+    ("game.usage_flag", "❌ Usage: flag <row> <col>"),
+    ("game.usage_position", "❌ Usage: {} <row> <col>"),
+    ("game.usage_goto", "❌ Usage: goto <row> <col>"),
+    ("game.usage_view", "❌ Usage: view <width> <height>"),
+    ("game.usage_follow", "❌ Usage: follow [on|off]"),
+    (
+        "game.usage_screenshot",
+        "❌ Usage: screenshot <file.svg|file.png> [mines] [wrong] [odds]",
+    ),
+    (
+        "game.usage_read",
+        "❌ Usage: row <row>, column <col> or cell <row> <col>",
+    ),
+    (
+        "game.unknown_command",
+        "❌ Unknown command: {}. Type 'help' for help",
+    ),
+    ("game.bad_coordinates", "❌ Malformed coordinates"),
+    (
+        "game.out_of_range",
+        "❌ Coordinates out of range! Valid range: rows 0-{}, columns 0-{}",
+    ),
+    ("help.title", "📋 Commands:"),
+    (
+        "help.click",
+        "  click <row> <col> - reveal a cell (e.g. click 3 5)",
+    ),
+    (
+        "help.flag",
+        "  flag <row> <col>  - flag or unflag a cell (e.g. flag 2 4)",
+    ),
+    (
+        "help.hex_click",
+        "  click <q>,<r>     - reveal by axial hex coordinates (e.g. click 1,2)",
+    ),
+    (
+        "help.hex_flag",
+        "  flag <q>,<r>      - flag by axial hex coordinates (e.g. flag 0,3)",
+    ),
+    (
+        "help.multi_flag",
+        "  💡 Flagging the same cell again cycles through 1-{} flags, then removes them",
+    ),
+    (
+        "help.negative_flag",
+        "  💡 Flagging again switches between mine flags and negative-mine flags (blue, with a - sign), then removes them",
+    ),
+    (
+        "help.pan",
+        "  w / a / s / d [n] - scroll the viewport up/left/down/right by n cells (default half a screen)",
+    ),
+    (
+        "help.goto",
+        "  goto <row> <col>  - centre the viewport on a coordinate",
+    ),
+    (
+        "help.follow",
+        "  follow [on|off]   - toggle whether the viewport follows your last action",
+    ),
+    (
+        "help.view",
+        "  view <w> <h>      - set the viewport width and height in cells",
+    ),
+    (
+        "help.read",
+        "  row <row> / column <col> / cell <row> <col> / summary - read a row, a column, a cell and its neighbours, or a summary",
+    ),
+    (
+        "help.screenshot",
+        "  screenshot <file> [mines] [wrong] [odds] - export an .svg or .png image, optionally showing mines, wrong flags or mine odds",
+    ),
+    (
+        "help.theme",
+        "  theme [name...]   - show or switch palette, glyphs and colour depth (e.g. theme colorblind emoji)",
+    ),
+    ("help.help", "  help              - show this help"),
+    ("help.quit", "  quit              - leave the game"),
+    ("help.coordinates", "  💡 Coordinates start at 0"),
+    (
+        "theme.current",
+        "🎨 Current theme: palette {}, glyphs {}, colour depth {}",
+    ),
+    (
+        "theme.options",
+        "  Palettes: {}\n  Glyphs: {}\n  Colour depths: {}",
+    ),
+    (
+        "theme.unknown",
+        "❌ Unknown palette, glyph set or colour depth: {}",
+    ),
+    // Viewport and minimap
+    (
+        "viewport.title",
+        "🗺️ Viewport: rows {}-{}, columns {}-{} (board has {} rows, {} columns)",
+    ),
+    (
+        "viewport.minimap",
+        "🧭 Minimap (each character is {}x{} cells; * mine, F flag, # hidden, . revealed, + partly revealed; < and ^ mark the viewport):",
+    ),
+    (
+        "viewport.follow_on",
+        "📍 The viewport now follows your last action",
+    ),
+    (
+        "viewport.follow_off",
+        "📍 The viewport no longer follows your actions",
+    ),
+    (
+        "viewport.bad_step",
+        "❌ The scroll distance must be an integer",
+    ),
+    // Accessible mode: linear text for screen readers, without icons or box characters
+    ("a11y.position", "row {} column {}"),
+    ("a11y.hidden", "hidden"),
+    ("a11y.flag", "flagged"),
+    ("a11y.flags", "{} flags"),
+    ("a11y.mine", "mine"),
+    ("a11y.mines", "{} mines"),
+    ("a11y.blank", "blank"),
+    ("a11y.cell", "{} is {}"),
+    ("a11y.item", "{} {}"),
+    ("a11y.separator", "; "),
+    ("a11y.row", "row {}"),
+    ("a11y.rows", "rows {} to {}"),
+    ("a11y.column", "column {}"),
+    ("a11y.columns", "columns {} to {}"),
+    ("a11y.row_line", "Row {}: {}"),
+    ("a11y.column_line", "Column {}: {}"),
+    ("a11y.neighbourhood", "{}. Neighbours: {}"),
+    ("a11y.revealed", "revealed {} cells, {}"),
+    ("a11y.unflagged", "{} unflagged"),
+    (
+        "a11y.summary",
+        "{} mines left, {} hidden cells, frontier of {} cells",
+    ),
+    ("screenshot.saved", "📷 Image saved: {}"),
+    ("export.error_io", "Could not write the image file: {}"),
+    (
+        "export.unsupported",
+        "Unsupported image format: {} (use .svg or .png)",
+    ),
+    ("screenshot.failed", "❌ Export failed: {}"),
+    ("status.won", "🎉 Congratulations! You won!"),
+    ("status.lost", "💥 Game over! You hit a mine!"),
+    ("status.playing", "🎯 Keep going... 💣 Mines left: {}"),
+    (
+        "status.playing_lives",
+        "🎯 Keep going... 💣 Mines left: {} ❤️ Lives left: {}/{}",
+    ),
+    ("click.ok", "✅ Revealed"),
+    ("click.victory", "🎉 Congratulations! You win!"),
+    (
+        "click.life_lost",
+        "💔 You hit a mine! It has been flagged, lives left: {}/{}",
+    ),
+    ("click.mine", "💥 Oops! You hit a mine!"),
+    (
+        "click.invalid",
+        "❌ Invalid move (the cell is already revealed or flagged)",
+    ),
+    ("flag.count", "🚩 Flags on this cell: {}"),
+    ("flag.ok", "🚩 Flag updated"),
+    ("flag.invalid", "❌ A revealed cell cannot be flagged"),
+    ("over.won_banner", "🎊🎊🎊 You won! 🎊🎊🎊"),
+    ("over.won", "🏆 You found all the mines!"),
+    ("over.lost_banner", "💀💀💀 Game over! 💀💀💀"),
+    ("over.lost", "💣 Don't give up, try again!"),
+    ("over.draw", "🤝 It's a draw!"),
+    ("over.lives_spent", "❤️ Lives used: {}/{}"),
+    ("over.thanks", "Thanks for playing!"),
+    // Board legend, the {} are colour codes and glyphs in order
+    (
+        "legend.basic",
+        "{}Legend:{} {}{}{} hidden, {}{}{} flag, {}{}{} mine, colored numbers show adjacent mines",
+    ),
+    (
+        "legend.negative",
+        "{}Negative:{} {}{}{} anti-mine subtracts one, {}{}{} anti-mine flag, {}-1{} negative number; a 0 may still hide mines",
+    ),
+    (
+        "legend.multi",
+        "{}Multi:{} up to {} mines per cell, {}2 = two flags, {}3 = three mines, numbers sum all adjacent mines",
+    ),
+    (
+        "legend.hex",
+        "{}Hex:{} odd rows are shifted right, axial input q,r means row r, col q + r / 2",
+    ),
+    (
+        "legend.ascii",
+        "Legend: # hidden, F flag, * mine, numbers show adjacent mines",
+    ),
+    (
+        "legend.ascii_negative",
+        "Negative: *, F and numbers with a - sign are anti-mines, anti-mine flags and negative numbers",
+    ),
+    (
+        "legend.ascii_multi",
+        "Multi: up to {} mines per cell, F2 = two flags, *3 = three mines",
+    ),
+    // Infinite mode
+    ("infinite.welcome", "♾️ Welcome to infinite Minesweeper!"),
+    ("infinite.config", "🌱 World seed: {}, mine density: {}%"),
+    (
+        "infinite.hint",
+        "💡 The board has no edges; your score is the number of safe cells revealed before hitting a mine",
+    ),
+    (
+        "infinite.help_click",
+        "  click <row> <col> - Reveal a cell in world coordinates, which may be negative (e.g. click -3 5)",
+    ),
+    (
+        "infinite.help_flag",
+        "  flag <row> <col>  - Flag or unflag a cell (e.g. flag 2 -4)",
+    ),
+    (
+        "infinite.title",
+        "🗺️ Viewport (rows {}..{}, columns {}..{}), score: {}, chunks generated: {}",
+    ),
+    ("infinite.click_ok", "✅ Revealed, score: {}"),
+    (
+        "infinite.out_of_bounds",
+        "❌ Coordinates must be at most {} in absolute value",
+    ),
+    ("infinite.final_score", "🏆 Final score: {} safe cells"),
+    // Graph mode
+    ("graph.welcome", "🕸️ Welcome to graph Minesweeper!"),
+    ("graph.config", "📏 Board: {} nodes, {} mines"),
+    (
+        "graph.hint",
+        "💡 Numbers count the mines connected to a node; numbers from 10 up are shown as letters",
+    ),
+    (
+        "graph.help_click",
+        "  click <node>  - Reveal a node (e.g. click 3)",
+    ),
+    (
+        "graph.help_flag",
+        "  flag <node>   - Flag or unflag a node (e.g. flag 7)",
+    ),
+    (
+        "graph.help_display",
+        "  💡 Display coordinates also work instead of node numbers (e.g. click 2 4)",
+    ),
+    ("graph.help_help", "  help          - Show this help"),
+    ("graph.help_quit", "  quit          - Quit the game"),
+    ("graph.help_nodes", "  💡 Nodes are numbered from 0"),
+    ("graph.usage_click", "❌ Usage: click <node>"),
+    ("graph.usage_flag", "❌ Usage: flag <node>"),
+    (
+        "graph.out_of_range",
+        "❌ Node out of range! Valid nodes: 0-{}",
+    ),
+    (
+        "graph.click_invalid",
+        "❌ Invalid move (the node is already revealed or flagged)",
+    ),
+    ("graph.flag_invalid", "❌ A revealed node cannot be flagged"),
+    ("graph.error_io", "cannot read the graph file: {}"),
+    ("graph.error_node_id", "expected node {}, found {}"),
+    ("graph.error_node_usage", "usage: node <id> [<row> <col>]"),
+    (
+        "graph.error_display_range",
+        "display coordinates out of range, rows and columns go up to {}",
+    ),
+    (
+        "graph.error_duplicate_display",
+        "display coordinates ({}, {}) are already used by node {}",
+    ),
+    (
+        "graph.error_unknown_node",
+        "the edge refers to a node that has not been declared",
+    ),
+    (
+        "graph.error_self_loop",
+        "a node cannot be its own neighbour",
+    ),
+    ("graph.error_unrecognised", "unrecognised line: {}"),
+    (
+        "graph.error_too_few_nodes",
+        "the graph needs at least two nodes",
+    ),
+    (
+        "graph.error_too_many_mines",
+        "there must be fewer mines than nodes",
+    ),
+    (
+        "graph.error_degree",
+        "a node cannot have more than {} neighbours",
+    ),
+    (
+        "graph.error_partial_display",
+        "some nodes have no display coordinates",
+    ),
+    // 3D mode
+    ("cube.welcome", "🧊 Welcome to 3D Minesweeper!"),
+    ("cube.config", "📏 Board: {}x{}x{} ({} layers), {} mines"),
+    (
+        "cube.hint",
+        "💡 Each cell has up to 26 neighbours (the layer above, its own layer and the layer below); numbers from 10 up are shown as a-q",
+    ),
+    (
+        "cube.help_click",
+        "  click <row> <col> [layer] - Reveal a cell, on the current layer if none is given (e.g. click 3 5 2)",
+    ),
+    (
+        "cube.help_flag",
+        "  flag <row> <col> [layer]  - Flag or unflag a cell (e.g. flag 2 4)",
+    ),
+    (
+        "cube.help_layer",
+        "  layer <layer>             - Switch to a layer (e.g. layer 1)",
+    ),
+    (
+        "cube.help_up_down",
+        "  up / down                 - Switch to the layer above / below",
+    ),
+    (
+        "cube.help_help",
+        "  help                      - Show this help",
+    ),
+    (
+        "cube.help_quit",
+        "  quit                      - Quit the game",
+    ),
+    (
+        "cube.help_coordinates",
+        "  💡 Coordinates and layers are counted from 0",
+    ),
+    ("cube.input_prompt", "[layer {}] Enter a command: "),
+    ("cube.usage_click", "❌ Usage: click <row> <col> [layer]"),
+    ("cube.usage_flag", "❌ Usage: flag <row> <col> [layer]"),
+    ("cube.usage_layer", "❌ Usage: layer <layer>"),
+    ("cube.top_layer", "❌ Already on the top layer"),
+    ("cube.layer", "Layer {}/{}"),
+    (
+        "cube.layer_out_of_range",
+        "❌ Layer out of range! Valid layers: 0-{}",
+    ),
+    (
+        "cube.out_of_range",
+        "❌ Coordinates out of range! Valid range: rows 0-{}, columns 0-{}, layers 0-{}",
+    ),
+    // Timed mode
+    ("timed.countdown", "Countdown"),
+    ("timed.time_attack", "Time Attack"),
+    ("timed.welcome", "⏱️ Welcome to {} mode!"),
+    (
+        "timed.config",
+        "📏 Board: {}x{}, {} mines, {} second time limit",
+    ),
+    (
+        "timed.countdown_hint",
+        "💡 Clear the board before time runs out; each blank area you open adds {} seconds",
+    ),
+    (
+        "timed.time_attack_hint",
+        "💡 Clear as many boards as you can in the time limit; hitting a mine ends the run",
+    ),
+    (
+        "timed.help_quit",
+        "  quit              - Quit the game (the result is not recorded)",
+    ),
+    (
+        "timed.help_time",
+        "  💡 Coordinates start at 0, and the clock keeps running while you type",
+    ),
+    ("timed.remaining", "⏳ Time left: {}.{} s"),
+    ("timed.boards_cleared", "  🏁 Boards cleared: {}"),
+    ("timed.ok", "✅ Done"),
+    ("timed.bonus", "⏱️ Blank area opened, {} bonus seconds!"),
+    (
+        "timed.board_cleared",
+        "🏁 Board {} cleared, here comes the next one!",
+    ),
+    ("timed.cleared", "🎊 You cleared the board in time!"),
+    (
+        "timed.mine_hit",
+        "💥 You hit a mine, the challenge is over!",
+    ),
+    ("timed.time_up", "⌛ Time's up!"),
+    (
+        "timed.result",
+        "🏆 Result: {} boards cleared, {} cells revealed in {} s",
+    ),
+    (
+        "timed.best",
+        "📜 {} best: {} boards cleared, {} cells revealed in {} s",
+    ),
+    (
+        "timed.load_failed",
+        "⚠️ Could not read the records file {}, the result was not saved: {}",
+    ),
+    (
+        "timed.save_failed",
+        "⚠️ Could not save the records file {}: {}",
+    ),
+    (
+        "timed.error_io",
+        "cannot read or write the records file: {}",
+    ),
+    ("timed.error_fields", "expected 5 fields, found {}"),
+    ("timed.error_mode", "unknown mode: {}"),
+    ("timed.error_outcome", "unknown outcome: {}"),
+    ("timed.error_boards", "too many boards: {}"),
+    // Flags duel
+    ("flags.computer", "Computer"),
+    ("flags.welcome", "🚩 Welcome to the Minesweeper Flags duel!"),
+    (
+        "flags.goal_hint",
+        "💡 The goal is to find mines: hitting a mine scores a point and you move again, a safe cell passes the turn",
+    ),
+    (
+        "flags.majority_hint",
+        "💡 The first player to find {} mines wins",
+    ),
+    (
+        "flags.help_click",
+        "  click <row> <col> - Click a cell (e.g. click 3 5)",
+    ),
+    ("flags.computer_click", "🤖 The computer clicks ({}, {})"),
+    ("flags.claimed", "🎯 {} found a mine! Score {}, move again"),
+    ("flags.revealed", "✅ Safe cell, {} to move"),
+    (
+        "flags.invalid",
+        "❌ Invalid move (the cell is already revealed)",
+    ),
+    ("flags.aborted", "🏳️ Match abandoned"),
+    ("flags.winner", "🏆 {} wins!"),
+    ("flags.scoreboard", "📊 Scoreboard (first to {} wins):"),
+    ("flags.score_line", "  {} {}✹ {}{}: {}"),
+    // Online versus
+    (
+        "net.help_click",
+        "  click <row> <col> - Reveal a cell (e.g. click 3 5)",
+    ),
+    (
+        "net.help_flag",
+        "  flag <row> <col>  - Flag or unflag a mine",
+    ),
+    ("versus.help_quit", "  quit              - Resign and leave"),
+    (
+        "versus.joined",
+        "🌐 Joined the match: {}x{}, {} mines, {} lives, waiting for an opponent...",
+    ),
+    ("versus.start", "⚔️ The match begins! Opponent: {}"),
+    ("versus.you", "You"),
+    ("versus.opponent", "Opponent"),
+    ("versus.finish_time", ", finished in {} s"),
+    ("versus.progress", "📊 {}: {}% done, {} lives left{}"),
+    ("versus.won", "🏆 You win!"),
+    ("versus.lost", "💥 Your opponent wins"),
+    ("net.bad_view", "❌ Could not read the board"),
+    ("net.bad_message", "❌ Unrecognised server message: {}"),
+    ("net.hello_first", "Send HELLO <name> first"),
+    ("net.bad_client_message", "Unrecognised message: {}"),
+    ("net.out_of_range", "Coordinates out of range"),
+    (
+        "versus.finished_wait",
+        "You have finished this game, wait for your opponent",
+    ),
+    ("versus.already_started", "The match has already started"),
+    (
+        "versus.classic_only",
+        "Versus mode only supports classic single-mine boards",
+    ),
+    ("coop.already_started", "The game has already started"),
+    (
+        "coop.invalid",
+        "Invalid move (coordinates out of range or the cell is already revealed)",
+    ),
+    (
+        "coop.classic_only",
+        "Co-op mode only supports classic single-mine boards",
+    ),
+    ("net.press_enter", "Press Enter to go back"),
+    (
+        "net.disconnected",
+        "🔌 Disconnected from the server, press Enter to go back",
+    ),
+    // Co-op mode
+    (
+        "coop.help_move",
+        "  move <row> <col>  - Move your cursor so teammates can see where you are looking",
+    ),
+    ("coop.help_quit", "  quit              - Leave the room"),
+    (
+        "coop.help_colors",
+        "  💡 Coordinates start at 0; revealed and flagged cells are shown in the colour of whoever played them",
+    ),
+    (
+        "coop.joined_room",
+        "🌐 Joined the co-op room: {}x{}, {} mines, {} players, waiting for the others...",
+    ),
+    ("coop.joined", "👋 {} joined"),
+    ("coop.bad_owners", "❌ Could not read who played each cell"),
+    ("coop.cursor_moved", "👀 {} moved their cursor to ({}, {})"),
+    (
+        "coop.revealed",
+        "✅ {} revealed ({}, {}), {} cells in total",
+    ),
+    ("coop.flagged", "🚩 {} flagged ({}, {})"),
+    ("coop.unflagged", "🚩 {} unflagged ({}, {})"),
+    (
+        "coop.conflict",
+        "⚠️ ({}, {}) was just changed by {}; your move was not applied, check the latest board and try again",
+    ),
+    ("coop.left", "🚪 {} left the room"),
+    ("coop.won", "🎉 The team wins! Every safe cell is revealed"),
+    ("coop.lost", "💥 {} hit a mine, the team loses"),
+    ("coop.me", " (you)"),
+    ("coop.cursor_line", "  ▶ {}{} cursor: {}"),
+    // Bot tournament
+    ("tournament.difficulty", "Level"),
+    ("tournament.bot", "Bot"),
+    ("tournament.games", "Games"),
+    ("tournament.win_rate", "Win rate"),
+    ("tournament.guesses", "Guesses"),
+    (
+        "tournament.footnote",
+        "* One second per action, winning games only",
+    ),
+    // Bot protocol
+    ("bot.missing_field", "Missing field {}"),
+    ("bot.not_integer", "{} must be a non-negative integer"),
+    ("bot.no_game", "No game in progress, send new first"),
+    ("bot.game_over", "This game is already over"),
+    ("bot.unknown_command", "Unknown command: {}"),
+    (
+        "bot.invalid_reveal",
+        "The cell is already revealed or flagged",
+    ),
+    ("bot.invalid_flag", "Cannot flag a revealed cell"),
+    (
+        "bot.invalid_chord",
+        "Only revealed numbers with a matching number of adjacent flags can be chorded",
+    ),
+    (
+        "bot.out_of_bounds",
+        "Coordinates out of range, valid range: rows 0-{}, columns 0-{}",
+    ),
+    (
+        "bot.bad_difficulty",
+        "difficulty must be easy, medium or hard",
+    ),
+    ("bot.bad_size", "Width and height must be between 1 and {}"),
+    (
+        "bot.too_many_mines",
+        "Too many mines, the first click needs room around it",
+    ),
+    ("json.error", "JSON byte {}: {}"),
+    ("json.trailing", "Unexpected trailing content"),
+    ("json.expected", "Expected '{}'"),
+    ("json.expected_either", "Expected '{}' or '{}'"),
+    ("json.bad_value", "Unrecognised value"),
+    ("json.unexpected_end", "Unexpected end of input"),
+    ("json.too_deep", "Nesting too deep"),
+    ("json.bad_number", "Invalid number"),
+    ("json.bad_utf8", "Invalid UTF-8"),
+    ("json.unterminated", "Unterminated string"),
+    ("json.bad_unicode", "Invalid \\u escape"),
+    ("json.bad_escape", "Invalid escape"),
+    // Demo
+    ("demo.start", "\n🔍 Demo mode!"),
+    (
+        "demo.intro",
+        "📖 This demo walks through the core features of the game\n",
+    ),
+    ("demo.part1", "🔶 Part 1: setting up the board"),
+    ("demo.config", "📏 Board: {}x{}, {} mines"),
+    ("demo.density", "💡 Mine density: {}%"),
+    (
+        "demo.initial_board",
+        "\n🔍 The initial board (nothing revealed yet):",
+    ),
+    (
+        "demo.part2",
+        "\n🔶 Part 2: the first click and mine placement",
+    ),
+    (
+        "demo.first_click",
+        "🎯 Clicking the centre for the first click: ({}, {})",
+    ),
+    (
+        "demo.first_click_hint",
+        "💡 The first click places the mines and is always safe",
+    ),
+    ("demo.click_result", "📊 Click result: {}"),
+    (
+        "demo.after_first_click",
+        "\n🗺️ The board after the first click (mines placed, cells revealed):",
+    ),
+    ("demo.part3", "\n🔶 Part 3: automatic reveal"),
+    (
+        "demo.auto_reveal_hint",
+        "💡 Clicking an empty cell automatically reveals the connected empty area",
+    ),
+    (
+        "demo.auto_reveal_click",
+        "🔍 Clicking a corner to trigger an automatic reveal: ({}, {})",
+    ),
+    ("demo.auto_reveal_result", "📊 Automatic reveal result: {}"),
+    (
+        "demo.after_auto_reveal",
+        "\n🗺️ The board after the automatic reveal:",
+    ),
+    ("demo.part4", "\n🔶 Part 4: flags"),
+    ("demo.flag", "🚩 Flagging a cell: ({}, {})"),
+    (
+        "demo.flag_hint",
+        "💡 A right click flags a cell you suspect hides a mine",
+    ),
+    ("demo.flag_result", "📊 Flag result: {}"),
+    ("demo.after_flag", "\n🗺️ The board after flagging:"),
+    (
+        "demo.unflag",
+        "\n🔄 Removing the flag (right click the same cell again):",
+    ),
+    ("demo.unflag_result", "📊 Unflag result: {}"),
+    ("demo.part5", "\n🔶 Part 5: game states"),
+    ("demo.part6", "\n🔶 Part 6: edge cases"),
+    ("demo.part7", "\n🔶 Part 7: statistics"),
+    ("demo.complete", "\n✅ Demo of the core features complete!"),
+    (
+        "demo.ready",
+        "🚀 The game logic is ready to be connected to a GUI!",
+    ),
+    (
+        "demo.test_hint",
+        "📋 Run 'cargo test' for the detailed tests",
+    ),
+    ("demo.summary", "\n🎓 Summary:"),
+    ("demo.summary_init", "• Showed the whole game setup"),
+    ("demo.summary_reveal", "• Demonstrated the automatic reveal"),
+    ("demo.summary_flag", "• Checked flagging and unflagging"),
+    (
+        "demo.summary_boundary",
+        "• Tried edge cases and error handling",
+    ),
+    ("demo.summary_stats", "• Reported game state and statistics"),
+    (
+        "demo.outro",
+        "\n🚀 Now that you know the core features, go and play!",
+    ),
+    ("demo.pause", "\n⏸️  Press Enter to continue..."),
+    ("demo.states", "💡 Detecting the different game states"),
+    (
+        "demo.small_board",
+        "\n🎯 A 3x3 board with 1 mine to show the game states quickly:",
+    ),
+    ("demo.state_playing", "🎮 Current state: playing"),
+    (
+        "demo.win_condition",
+        "\n💡 You win by revealing every cell without a mine",
+    ),
+    ("demo.lose_condition", "💡 You lose by clicking a mine"),
+    ("demo.boundary", "💡 Trying edge cases and error handling"),
+    ("demo.repeat_click", "\n🔄 Clicking a revealed cell again:"),
+    ("demo.repeat_result", "📊 Result of the repeated click: {}"),
+    ("demo.click_flagged", "\n🚩 Clicking a flagged cell:"),
+    (
+        "demo.click_flagged_result",
+        "📊 Result of clicking a flagged cell: {}",
+    ),
+    ("demo.corners", "\n📐 Cells on the edge of the board:"),
+    ("demo.corner", "  Corner {}: ({}, {}) - works normally"),
+    ("demo.stats", "📊 Statistics:"),
+    ("demo.total_cells", "• Cells: {}"),
+    ("demo.mine_count", "• Mines: {}"),
+    ("demo.safe_cells", "• Safe cells: {}"),
+    ("demo.revealed_cells", "• Revealed cells: {}"),
+    ("demo.flags_placed", "• Flags placed: {}"),
+    ("demo.hidden_cells", "• Untouched cells: {}"),
+];
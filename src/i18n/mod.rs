@@ -0,0 +1,136 @@
+//! 界面文本的多语言支持
+//!
+//! 每种语言一张消息表（键 → 文本），文本中的 `{}` 按顺序由参数替换。
+//! 当前语言保存在全局状态中，程序启动时按 命令行 `--lang` > 设置文件 > `LC_ALL`/`LC_MESSAGES`/`LANG`
+//! 的顺序选择，默认简体中文。某种语言缺少的键回退到简体中文，仍然没有时显示键本身。
+//!
+//! - zh_cn: 简体中文
+//! - en: 英文
+
+mod en;
+mod zh_cn;
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Locale {
+    ZhCn,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::ZhCn, Locale::En];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::En => "en",
+        }
+    }
+
+    /// 识别语言代码，接受 `zh`、`zh-CN`、`zh_CN.UTF-8`、`en`、`en_US.UTF-8` 等形式
+    pub fn parse(text: &str) -> Option<Locale> {
+        let language = text
+            .trim()
+            .split(['_', '-', '.', '@'])
+            .next()?
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "zh" => Some(Locale::ZhCn),
+            "en" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    /// 按 LC_ALL、LC_MESSAGES、LANG 的顺序读取环境变量，取第一个非空的值
+    pub fn from_env() -> Option<Locale> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Locale::parse(&value))
+    }
+
+    fn messages(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::ZhCn => zh_cn::MESSAGES,
+            Locale::En => en::MESSAGES,
+        }
+    }
+
+    /// 本语言中的文本；没有该键时返回 None
+    pub fn lookup(self, key: &str) -> Option<&'static str> {
+        self.messages()
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, text)| *text)
+    }
+
+    /// 本语言的全部键
+    pub fn keys(self) -> impl Iterator<Item = &'static str> {
+        self.messages().iter().map(|(key, _)| *key)
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_locale(locale: Locale) {
+    CURRENT.store(locale as u8, Ordering::Relaxed);
+}
+
+pub fn locale() -> Locale {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Locale::En,
+        _ => Locale::ZhCn,
+    }
+}
+
+/// 按优先级选择语言：命令行参数、设置文件、环境变量，都没有时为简体中文
+pub fn select_locale(cli: Option<&str>, settings: Option<Locale>) -> Locale {
+    cli.and_then(Locale::parse)
+        .or(settings)
+        .or_else(Locale::from_env)
+        .unwrap_or(Locale::ZhCn)
+}
+
+/// 当前语言中的文本
+pub fn text(key: &'static str) -> &'static str {
+    locale()
+        .lookup(key)
+        .or_else(|| Locale::ZhCn.lookup(key))
+        .unwrap_or(key)
+}
+
+/// 当前语言中的文本，依次用参数替换其中的 `{}`
+pub fn format(key: &'static str, args: &[&dyn Display]) -> String {
+    fill(text(key), args)
+}
+
+/// 依次用参数替换模板中的 `{}`，多余的占位符保持原样
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(index) = rest.find("{}") {
+        result.push_str(&rest[..index]);
+        match args.next() {
+            Some(arg) => result.push_str(&arg.to_string()),
+            None => result.push_str("{}"),
+        }
+        rest = &rest[index + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 取当前语言的文本：`t!("key")` 返回 `&'static str`，`t!("key", a, b)` 返回替换参数后的 `String`
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::text($key)
+    };
+    ($key:expr, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::format($key, &[$(&$arg as &dyn ::std::fmt::Display),+])
+    };
+}
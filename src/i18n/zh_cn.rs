@@ -0,0 +1,677 @@
+//! 简体中文消息表
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // 主菜单
+    ("menu.title", "🎮 扫雷游戏启动！"),
+    ("menu.prompt", "请选择难度："),
+    ("menu.easy", "1. 简单 ({}x{}, {}个地雷)"),
+    ("menu.medium", "2. 中等 ({}x{}, {}个地雷)"),
+    ("menu.hard", "3. 困难 ({}x{}, {}个地雷)"),
+    ("menu.demo", "4. 演示模式 (查看核心功能演示)"),
+    ("menu.hex", "5. 六边形 ({}x{}, {}个地雷, 每格6个邻居)"),
+    (
+        "menu.cube",
+        "6. 三维 ({}x{}x{}, {}个地雷, 每格最多26个邻居)",
+    ),
+    (
+        "menu.infinite",
+        "7. 无限 (无边界棋盘, 地雷密度 {}%, 踩雷前揭示越多得分越高)",
+    ),
+    ("menu.graph", "8. 图结构 (从文件加载节点和边, 默认 {})"),
+    (
+        "menu.neighbourhood",
+        "9. 邻域变体 (正交 / 马步 / 半径2 / 自定义偏移, 简单难度尺寸)",
+    ),
+    (
+        "menu.multi_mine",
+        "10. 多雷格子 ({}x{}, 共{}颗地雷, 每格最多{}颗, 旗子可叠加)",
+    ),
+    (
+        "menu.negative_mine",
+        "11. 负雷 ({}x{}, {}个地雷, 其中{}个负雷会让周围数字减一)",
+    ),
+    (
+        "menu.lives",
+        "12. 生命模式 ({}x{}, {}个地雷, 默认{}条生命, 踩雷自动标记)",
+    ),
+    (
+        "menu.timed",
+        "13. 计时模式 (倒计时 {} 秒清空中等棋盘 / {} 秒内连续清空简单棋盘)",
+    ),
+    (
+        "menu.flags",
+        "14. 双人对战 ({}x{}, {}个地雷, 轮流找雷, 先找到{}颗者胜, 可对战电脑)",
+    ),
+    (
+        "menu.versus",
+        "15. 联机对战 (通过 TCP 在相同的中等棋盘上竞速, 每人{}条生命, 默认端口 {})",
+    ),
+    (
+        "menu.coop",
+        "16. 合作模式 (最多{}人通过 TCP 共同清理一块中等棋盘, 任何人踩雷全队失败, 默认端口 {})",
+    ),
+    ("menu.input", "请输入选择 (1-16): "),
+    ("menu.input_error", "❌ 输入错误，请重试"),
+    ("menu.invalid_choice", "❌ 无效选择，请输入 1-16"),
+    ("menu.game_start", "\n🚀 启动游戏...\n"),
+    // 各模式的设置提示
+    (
+        "prompt.graph_path",
+        "请输入图文件路径 (直接回车使用默认文件): ",
+    ),
+    ("prompt.lives", "请输入生命数 1-{} (直接回车使用默认 {}): "),
+    (
+        "prompt.timed_mode",
+        "请选择计时模式 (1. 倒计时  2. 限时挑战): ",
+    ),
+    (
+        "prompt.flags_opponent",
+        "请选择对手 (1. 同一终端的另一位玩家  2. 电脑): ",
+    ),
+    (
+        "prompt.coop_players",
+        "请输入玩家人数 1-{} (直接回车使用默认 {}): ",
+    ),
+    ("prompt.room_role", "请选择 (1. 创建房间  2. 加入房间): "),
+    ("prompt.player_name", "请输入你的名字: "),
+    (
+        "prompt.address",
+        "请输入服务器地址 (直接回车使用 127.0.0.1:{}): ",
+    ),
+    (
+        "prompt.neighbourhood",
+        "请选择相邻规则 (1. 正交4格  2. 马步8格  3. 半径2共24格  4. 自定义): ",
+    ),
+    (
+        "prompt.custom_offsets",
+        "请输入偏移列表，格式为 行,列 并以空格分隔 (例: -1,0 1,0 0,-2 0,2): ",
+    ),
+    ("default.player_name", "玩家"),
+    ("default.player_number", "玩家 {}"),
+    // 菜单中的错误和提示
+    ("error.graph_load", "❌ 加载图文件 {} 失败: {}"),
+    ("error.invalid_lives", "❌ 无效生命数，请输入 1-{}"),
+    ("error.invalid_choice_2", "❌ 无效选择，请输入 1-2"),
+    ("error.invalid_choice_4", "❌ 无效选择，请输入 1-4"),
+    ("error.invalid_players", "❌ 无效人数，请输入 1-{}"),
+    ("error.online", "❌ 联机失败 {}: {}"),
+    ("error.host", "❌ 无法在端口 {} 创建房间: {}"),
+    (
+        "error.custom_offsets",
+        "❌ 偏移列表格式错误、为空或超过 {} 个",
+    ),
+    ("error.bot_io", "❌ 机器协议读写失败: {}"),
+    ("error.line", "第 {} 行: {}"),
+    ("error.number", "无效的数字: {}"),
+    ("error.settings", "⚠️ 设置文件 {} 第 {} 行无法识别: {}"),
+    (
+        "info.room_created",
+        "🌐 房间已创建，其他玩家可连接到本机的 {} 端口",
+    ),
+    ("tournament.title", "🤖 机器人比赛：每种难度 {} 局"),
+    // 名称
+    ("difficulty.easy", "简单"),
+    ("difficulty.medium", "中等"),
+    ("difficulty.hard", "困难"),
+    ("neighbourhood.moore", "8 邻域"),
+    ("neighbourhood.orthogonal", "正交 4 邻域"),
+    ("neighbourhood.knight", "马步"),
+    ("neighbourhood.radius2", "半径 2"),
+    ("neighbourhood.custom", "自定义偏移"),
+    // 游戏过程
+    ("game.welcome", "🎮 欢迎来到命令行扫雷游戏！"),
+    ("game.config", "📏 游戏配置: {}x{}, {} 个地雷"),
+    ("game.neighbourhood_hint", "💡 相邻规则: {}，数字最大为 {}"),
+    (
+        "game.multi_mine_hint",
+        "💡 每个格子最多 {} 颗地雷，数字为周围地雷的总数，数字最大为 {}",
+    ),
+    (
+        "game.negative_mine_hint",
+        "💡 其中 {} 个是负雷：周围数字会减一，数字可能为 0 甚至负数 (反色显示)",
+    ),
+    (
+        "game.letters_hint",
+        "💡 10 以上的数字用字母表示 (a=10, b=11, ...)",
+    ),
+    (
+        "game.lives_hint",
+        "❤️ 你有 {} 条生命：踩到地雷会自动标记它并扣除一条生命",
+    ),
+    ("game.board_title", "🗺️ 当前棋盘状态："),
+    ("game.input_prompt", "请输入指令: "),
+    ("game.invalid_input", "❌ 无效输入，请重试"),
+    ("game.bye", "👋 再见！"),
+    ("game.usage_click", "❌ 用法: click <行> <列>"),
+    ("game.usage_flag", "❌ 用法: flag <行> <列>"),
+    ("game.usage_position", "❌ 用法: {} <行> <列>"),
+    ("game.usage_goto", "❌ 用法: goto <行> <列>"),
+    ("game.usage_view", "❌ 用法: view <宽> <高>"),
+    ("game.usage_follow", "❌ 用法: follow [on|off]"),
+    (
+        "game.usage_screenshot",
+        "❌ 用法: screenshot <文件.svg|文件.png> [mines] [wrong] [odds]",
+    ),
+    (
+        "game.usage_read",
+        "❌ 用法: row <行>、column <列> 或 cell <行> <列>",
+    ),
+    (
+        "game.unknown_command",
+        "❌ 未知指令: {}. 输入 'help' 查看帮助",
+    ),
+    ("game.bad_coordinates", "❌ 坐标格式错误"),
+    (
+        "game.out_of_range",
+        "❌ 坐标超出范围! 有效范围: 行 0-{}, 列 0-{}",
+    ),
+    ("help.title", "📋 游戏指令："),
+    (
+        "help.click",
+        "  click <行> <列>   - 左键点击格子 (例: click 3 5)",
+    ),
+    (
+        "help.flag",
+        "  flag <行> <列>    - 右键标记/取消标记 (例: flag 2 4)",
+    ),
+    (
+        "help.hex_click",
+        "  click <q>,<r>     - 六边形轴向坐标点击 (例: click 1,2)",
+    ),
+    (
+        "help.hex_flag",
+        "  flag <q>,<r>      - 六边形轴向坐标标记 (例: flag 0,3)",
+    ),
+    (
+        "help.multi_flag",
+        "  💡 对同一格子重复 flag 会在 1-{} 面旗子之间循环，再次标记则取消",
+    ),
+    (
+        "help.negative_flag",
+        "  💡 重复 flag 依次切换为正雷旗、负雷旗 (蓝色，带 - 号)，再次标记则取消",
+    ),
+    (
+        "help.pan",
+        "  w / a / s / d [n] - 向上/左/下/右滚动视口 n 格（默认半屏）",
+    ),
+    (
+        "help.goto",
+        "  goto <行> <列>    - 将视口中心移动到指定坐标",
+    ),
+    (
+        "help.follow",
+        "  follow [on|off]   - 开关视口自动跟随最近操作的格子",
+    ),
+    (
+        "help.view",
+        "  view <宽> <高>    - 设置视口的宽和高（格子数）",
+    ),
+    (
+        "help.read",
+        "  row <行> / column <列> / cell <行> <列> / summary - 朗读一行、一列、格子及其相邻格子或局面统计",
+    ),
+    (
+        "help.screenshot",
+        "  screenshot <文件> [mines] [wrong] [odds] - 导出 .svg 或 .png 图片，可显示地雷、标出插错的旗子或叠加地雷概率",
+    ),
+    (
+        "help.theme",
+        "  theme [名称...]   - 查看或切换配色、图标和颜色深度 (例: theme colorblind emoji)",
+    ),
+    ("help.help", "  help              - 显示帮助信息"),
+    ("help.quit", "  quit              - 退出游戏"),
+    ("help.coordinates", "  💡 坐标从0开始计算"),
+    (
+        "theme.current",
+        "🎨 当前主题: 配色 {}, 图标 {}, 颜色深度 {}",
+    ),
+    ("theme.options", "  配色: {}\n  图标: {}\n  颜色深度: {}"),
+    ("theme.unknown", "❌ 未知的配色、图标或颜色深度: {}"),
+    // 视口和小地图
+    (
+        "viewport.title",
+        "🗺️ 视口: 行 {}-{}, 列 {}-{} (棋盘共 {} 行 {} 列)",
+    ),
+    (
+        "viewport.minimap",
+        "🧭 小地图 (每个字符代表 {}x{} 格; * 地雷, F 旗子, # 未翻开, . 已翻开, + 部分翻开; < 和 ^ 标出视口):",
+    ),
+    ("viewport.follow_on", "📍 视口会自动跟随最近操作的格子"),
+    ("viewport.follow_off", "📍 视口不再自动跟随"),
+    ("viewport.bad_step", "❌ 滚动格数必须是整数"),
+    // 无障碍模式：线性朗读的文本，不含图标和框线字符
+    ("a11y.position", "第 {} 行第 {} 列"),
+    ("a11y.hidden", "未翻开"),
+    ("a11y.flag", "旗子"),
+    ("a11y.flags", "{}面旗子"),
+    ("a11y.mine", "地雷"),
+    ("a11y.mines", "{}颗地雷"),
+    ("a11y.blank", "空白"),
+    ("a11y.cell", "{}是{}"),
+    ("a11y.item", "{} {}"),
+    ("a11y.separator", "；"),
+    ("a11y.row", "第 {} 行"),
+    ("a11y.rows", "第 {} 至 {} 行"),
+    ("a11y.column", "第 {} 列"),
+    ("a11y.columns", "第 {} 至 {} 列"),
+    ("a11y.row_line", "第 {} 行：{}"),
+    ("a11y.column_line", "第 {} 列：{}"),
+    ("a11y.neighbourhood", "{}，相邻格子：{}"),
+    ("a11y.revealed", "翻开了 {} 个格子，{}"),
+    ("a11y.unflagged", "{}取消了旗子"),
+    ("a11y.summary", "剩余地雷 {}，未翻开 {} 格，边界 {} 格"),
+    ("screenshot.saved", "📷 已导出图片: {}"),
+    ("export.error_io", "无法写入图片文件: {}"),
+    (
+        "export.unsupported",
+        "不支持的图片格式: {}（请使用 .svg 或 .png）",
+    ),
+    ("screenshot.failed", "❌ 导出失败: {}"),
+    ("status.won", "🎉 恭喜你！游戏胜利！"),
+    ("status.lost", "💥 游戏结束！你踩到了地雷！"),
+    ("status.playing", "🎯 继续游戏... 💣 剩余地雷: {}"),
+    (
+        "status.playing_lives",
+        "🎯 继续游戏... 💣 剩余地雷: {} ❤️ 剩余生命: {}/{}",
+    ),
+    ("click.ok", "✅ 点击成功"),
+    ("click.victory", "🎉 恭喜！你赢了！"),
+    (
+        "click.life_lost",
+        "💔 踩到地雷！已自动标记，剩余生命: {}/{}",
+    ),
+    ("click.mine", "💥 糟糕！你踩到了地雷！"),
+    ("click.invalid", "❌ 无效操作（格子已翻开或已标记）"),
+    ("flag.count", "🚩 当前旗子数: {}"),
+    ("flag.ok", "🚩 标记操作成功"),
+    ("flag.invalid", "❌ 无法标记已翻开的格子"),
+    ("over.won_banner", "🎊🎊🎊 游戏胜利！🎊🎊🎊"),
+    ("over.won", "🏆 你成功找到了所有地雷！"),
+    ("over.lost_banner", "💀💀💀 游戏结束！💀💀💀"),
+    ("over.lost", "💣 不要灰心，再试一次吧！"),
+    ("over.draw", "🤝 平局！"),
+    ("over.lives_spent", "❤️ 消耗生命: {}/{}"),
+    ("over.thanks", "感谢游玩！"),
+    // 棋盘图例，{} 依次为颜色代码和图标
+    (
+        "legend.basic",
+        "{}图例:{} {}{}{} 隐藏, {}{}{} 旗子, {}{}{} 地雷, 彩色数字表示周围的地雷数",
+    ),
+    (
+        "legend.negative",
+        "{}负雷:{} {}{}{} 负雷让数字减一, {}{}{} 负雷旗, {}-1{} 负数; 数字为 0 的格子周围仍可能有地雷",
+    ),
+    (
+        "legend.multi",
+        "{}多雷:{} 每格最多 {} 颗地雷, {}2 = 两面旗, {}3 = 三颗地雷, 数字为周围地雷的总数",
+    ),
+    (
+        "legend.hex",
+        "{}六边形:{} 奇数行右移半格, 轴向坐标 q,r 表示第 r 行、第 q + r / 2 列",
+    ),
+    (
+        "legend.ascii",
+        "图例: # 隐藏, F 旗子, * 地雷, 数字表示周围的地雷数",
+    ),
+    (
+        "legend.ascii_negative",
+        "负雷: 带 - 号的 *、F 和数字分别是负雷、负雷旗和负数",
+    ),
+    (
+        "legend.ascii_multi",
+        "多雷: 每格最多 {} 颗地雷, F2 = 两面旗, *3 = 三颗地雷",
+    ),
+    // 无限模式
+    ("infinite.welcome", "♾️ 欢迎来到无限扫雷！"),
+    ("infinite.config", "🌱 世界种子: {}, 地雷密度: {}%"),
+    (
+        "infinite.hint",
+        "💡 棋盘没有边界，得分为踩到地雷前揭示的安全格子数",
+    ),
+    (
+        "infinite.help_click",
+        "  click <行> <列>   - 左键点击格子，使用世界坐标，可以为负 (例: click -3 5)",
+    ),
+    (
+        "infinite.help_flag",
+        "  flag <行> <列>    - 右键标记/取消标记 (例: flag 2 -4)",
+    ),
+    (
+        "infinite.title",
+        "🗺️ 视口 (行 {}..{}, 列 {}..{})，当前得分: {}，已生成区块: {}",
+    ),
+    ("infinite.click_ok", "✅ 点击成功，当前得分: {}"),
+    ("infinite.out_of_bounds", "❌ 坐标的绝对值不能超过 {}"),
+    ("infinite.final_score", "🏆 最终得分: {} 个安全格子"),
+    // 图结构模式
+    ("graph.welcome", "🕸️ 欢迎来到图结构扫雷！"),
+    ("graph.config", "📏 游戏配置: {} 个节点, {} 个地雷"),
+    (
+        "graph.hint",
+        "💡 数字表示与该节点相连的地雷数，10 以上的数字用字母表示",
+    ),
+    (
+        "graph.help_click",
+        "  click <节点>  - 左键点击节点 (例: click 3)",
+    ),
+    (
+        "graph.help_flag",
+        "  flag <节点>   - 右键标记/取消标记 (例: flag 7)",
+    ),
+    (
+        "graph.help_display",
+        "  💡 也可以用显示坐标代替节点编号 (例: click 2 4)",
+    ),
+    ("graph.help_help", "  help          - 显示帮助信息"),
+    ("graph.help_quit", "  quit          - 退出游戏"),
+    ("graph.help_nodes", "  💡 节点编号从0开始计算"),
+    ("graph.usage_click", "❌ 用法: click <节点>"),
+    ("graph.usage_flag", "❌ 用法: flag <节点>"),
+    ("graph.out_of_range", "❌ 节点超出范围! 有效范围: 0-{}"),
+    ("graph.click_invalid", "❌ 无效操作（节点已翻开或已标记）"),
+    ("graph.flag_invalid", "❌ 无法标记已翻开的节点"),
+    ("graph.error_io", "无法读取图文件: {}"),
+    ("graph.error_node_id", "节点编号应为 {}，实际为 {}"),
+    ("graph.error_node_usage", "用法: node <id> [<行> <列>]"),
+    (
+        "graph.error_display_range",
+        "显示坐标超出范围，行和列最大为 {}",
+    ),
+    (
+        "graph.error_duplicate_display",
+        "显示坐标 ({}, {}) 与节点 {} 重复",
+    ),
+    ("graph.error_unknown_node", "边引用了尚未声明的节点"),
+    ("graph.error_self_loop", "节点不能与自身相邻"),
+    ("graph.error_unrecognised", "无法识别的内容: {}"),
+    ("graph.error_too_few_nodes", "图中至少需要两个节点"),
+    ("graph.error_too_many_mines", "地雷数必须小于节点数"),
+    ("graph.error_degree", "节点的邻居数不能超过 {}"),
+    ("graph.error_partial_display", "部分节点缺少显示坐标"),
+    // 三维模式
+    ("cube.welcome", "🧊 欢迎来到三维扫雷！"),
+    ("cube.config", "📏 游戏配置: {}x{}x{} ({} 层), {} 个地雷"),
+    (
+        "cube.hint",
+        "💡 每个格子最多有 26 个邻居（上一层、本层、下一层），10 以上的数字用 a-q 表示",
+    ),
+    (
+        "cube.help_click",
+        "  click <行> <列> [层] - 左键点击格子，省略层号时使用当前层 (例: click 3 5 2)",
+    ),
+    (
+        "cube.help_flag",
+        "  flag <行> <列> [层]  - 右键标记/取消标记 (例: flag 2 4)",
+    ),
+    (
+        "cube.help_layer",
+        "  layer <层>           - 切换到指定层 (例: layer 1)",
+    ),
+    (
+        "cube.help_up_down",
+        "  up / down            - 切换到上一层 / 下一层",
+    ),
+    ("cube.help_help", "  help                 - 显示帮助信息"),
+    ("cube.help_quit", "  quit                 - 退出游戏"),
+    ("cube.help_coordinates", "  💡 坐标和层号都从0开始计算"),
+    ("cube.input_prompt", "[第 {} 层] 请输入指令: "),
+    ("cube.usage_click", "❌ 用法: click <行> <列> [层]"),
+    ("cube.usage_flag", "❌ 用法: flag <行> <列> [层]"),
+    ("cube.usage_layer", "❌ 用法: layer <层>"),
+    ("cube.top_layer", "❌ 已经是最上层"),
+    ("cube.layer", "第 {}/{} 层"),
+    ("cube.layer_out_of_range", "❌ 层号超出范围! 有效范围: 0-{}"),
+    (
+        "cube.out_of_range",
+        "❌ 坐标超出范围! 有效范围: 行 0-{}, 列 0-{}, 层 0-{}",
+    ),
+    // 计时模式
+    ("timed.countdown", "倒计时"),
+    ("timed.time_attack", "限时挑战"),
+    ("timed.welcome", "⏱️ 欢迎来到{}模式！"),
+    ("timed.config", "📏 棋盘配置: {}x{}, {} 个地雷，限时 {} 秒"),
+    (
+        "timed.countdown_hint",
+        "💡 在时间耗尽前清空棋盘，每展开一片空白区域奖励 {} 秒",
+    ),
+    (
+        "timed.time_attack_hint",
+        "💡 在限定时间内连续清空尽可能多的棋盘，踩到地雷立即结束",
+    ),
+    (
+        "timed.help_quit",
+        "  quit              - 退出游戏 (不记录成绩)",
+    ),
+    (
+        "timed.help_time",
+        "  💡 坐标从0开始计算，时间在输入指令时也在流逝",
+    ),
+    ("timed.remaining", "⏳ 剩余时间: {}.{} 秒"),
+    ("timed.boards_cleared", "  🏁 已清空棋盘: {}"),
+    ("timed.ok", "✅ 操作成功"),
+    ("timed.bonus", "⏱️ 展开空白区域，奖励 {} 秒！"),
+    ("timed.board_cleared", "🏁 清空第 {} 块棋盘，换上下一块！"),
+    ("timed.cleared", "🎊 在时限内清空了棋盘！"),
+    ("timed.mine_hit", "💥 踩到地雷，挑战结束！"),
+    ("timed.time_up", "⌛ 时间到！"),
+    (
+        "timed.result",
+        "🏆 本局成绩: 清空 {} 块棋盘, 翻开 {} 个格子, 用时 {} 秒",
+    ),
+    (
+        "timed.best",
+        "📜 {}最佳成绩: 清空 {} 块棋盘, 翻开 {} 个格子, 用时 {} 秒",
+    ),
+    (
+        "timed.load_failed",
+        "⚠️ 读取成绩文件 {} 失败，成绩未保存: {}",
+    ),
+    ("timed.save_failed", "⚠️ 保存成绩文件 {} 失败: {}"),
+    ("timed.error_io", "无法读写成绩文件: {}"),
+    ("timed.error_fields", "需要 5 个字段，实际为 {}"),
+    ("timed.error_mode", "未知的模式: {}"),
+    ("timed.error_outcome", "未知的结束方式: {}"),
+    ("timed.error_boards", "棋盘数过大: {}"),
+    // 双人对战
+    ("flags.computer", "电脑"),
+    ("flags.welcome", "🚩 欢迎来到 Minesweeper Flags 双人对战！"),
+    (
+        "flags.goal_hint",
+        "💡 目标是找出地雷：点中地雷得一分并继续行动，点中安全格子则轮到对手",
+    ),
+    ("flags.majority_hint", "💡 先找到 {} 颗地雷的玩家获胜"),
+    (
+        "flags.help_click",
+        "  click <行> <列>   - 点击格子 (例: click 3 5)",
+    ),
+    ("flags.computer_click", "🤖 电脑点击 ({}, {})"),
+    ("flags.claimed", "🎯 {} 找到一颗地雷！当前 {} 分，继续行动"),
+    ("flags.revealed", "✅ 安全格子，轮到 {}"),
+    ("flags.invalid", "❌ 无效操作（格子已翻开）"),
+    ("flags.aborted", "🏳️ 比赛中止"),
+    ("flags.winner", "🏆 {} 获胜！"),
+    ("flags.scoreboard", "📊 记分牌 (先得 {} 分获胜)："),
+    ("flags.score_line", "  {} {}✹ {}{}: {} 分"),
+    // 联机对战
+    (
+        "net.help_click",
+        "  click <行> <列>   - 翻开格子 (例: click 3 5)",
+    ),
+    ("net.help_flag", "  flag <行> <列>    - 标记/取消标记地雷"),
+    ("versus.help_quit", "  quit              - 认输并退出"),
+    (
+        "versus.joined",
+        "🌐 已加入对战: {}x{}, {} 个地雷, {} 条生命，等待对手...",
+    ),
+    ("versus.start", "⚔️ 比赛开始！对手: {}"),
+    ("versus.you", "你"),
+    ("versus.opponent", "对手"),
+    ("versus.finish_time", ", 用时 {} 秒完成"),
+    ("versus.progress", "📊 {}: 已完成 {}%, 剩余生命 {}{}"),
+    ("versus.won", "🏆 你赢了！"),
+    ("versus.lost", "💥 对手获胜"),
+    ("net.bad_view", "❌ 无法解析棋盘"),
+    ("net.bad_message", "❌ 无法识别的服务器消息: {}"),
+    ("net.hello_first", "请先发送 HELLO <名字>"),
+    ("net.bad_client_message", "无法识别的消息: {}"),
+    ("net.out_of_range", "坐标超出范围"),
+    ("versus.finished_wait", "你已经结束本局，请等待对手"),
+    ("versus.already_started", "比赛已经开始"),
+    ("versus.classic_only", "对战模式只支持经典的单雷棋盘"),
+    ("coop.already_started", "游戏已经开始"),
+    ("coop.invalid", "无效操作（坐标超出范围或格子已翻开）"),
+    ("coop.classic_only", "合作模式只支持经典的单雷棋盘"),
+    ("net.press_enter", "按回车键返回"),
+    ("net.disconnected", "🔌 与服务器的连接已断开，按回车键返回"),
+    // 合作模式
+    (
+        "coop.help_move",
+        "  move <行> <列>    - 移动光标，让队友看到你在看哪里",
+    ),
+    ("coop.help_quit", "  quit              - 离开房间"),
+    (
+        "coop.help_colors",
+        "  💡 坐标从0开始计算，翻开和标记的格子以操作者的颜色显示",
+    ),
+    (
+        "coop.joined_room",
+        "🌐 已加入合作房间: {}x{}, {} 个地雷, 共 {} 名玩家，等待其他人...",
+    ),
+    ("coop.joined", "👋 {} 加入"),
+    ("coop.bad_owners", "❌ 无法解析操作者"),
+    ("coop.cursor_moved", "👀 {} 的光标移到 ({}, {})"),
+    ("coop.revealed", "✅ {} 翻开 ({}, {})，共 {} 格"),
+    ("coop.flagged", "🚩 {} 标记 ({}, {})"),
+    ("coop.unflagged", "🚩 {} 取消标记 ({}, {})"),
+    (
+        "coop.conflict",
+        "⚠️ ({}, {}) 刚被 {} 改动，你的操作未执行，请看最新棋盘后再试",
+    ),
+    ("coop.left", "🚪 {} 离开了房间"),
+    ("coop.won", "🎉 全队获胜！所有安全格子都已翻开"),
+    ("coop.lost", "💥 {} 踩到了地雷，全队失败"),
+    ("coop.me", "（你）"),
+    ("coop.cursor_line", "  ▶ {}{} 光标: {}"),
+    // 机器人比赛
+    ("tournament.difficulty", "难度"),
+    ("tournament.bot", "机器人"),
+    ("tournament.games", "局数"),
+    ("tournament.win_rate", "胜率"),
+    ("tournament.guesses", "平均猜测"),
+    ("tournament.footnote", "* 按每次操作 1 秒折算，只统计获胜局"),
+    // 机器协议
+    ("bot.missing_field", "缺少 {} 字段"),
+    ("bot.not_integer", "{} 必须是非负整数"),
+    ("bot.no_game", "还没有开始对局，请先发送 new"),
+    ("bot.game_over", "本局已经结束"),
+    ("bot.unknown_command", "未知命令: {}"),
+    ("bot.invalid_reveal", "格子已翻开或已标记"),
+    ("bot.invalid_flag", "无法标记已翻开的格子"),
+    (
+        "bot.invalid_chord",
+        "只能双击已翻开的数字，且周围旗子数必须等于该数字",
+    ),
+    (
+        "bot.out_of_bounds",
+        "坐标超出范围，有效范围: 行 0-{}, 列 0-{}",
+    ),
+    (
+        "bot.bad_difficulty",
+        "difficulty 必须是 easy、medium 或 hard",
+    ),
+    ("bot.bad_size", "宽和高必须在 1-{} 之间"),
+    ("bot.too_many_mines", "地雷太多，首次点击周围需要留出空位"),
+    ("json.error", "JSON 第 {} 字节: {}"),
+    ("json.trailing", "多余的内容"),
+    ("json.expected", "应为 '{}'"),
+    ("json.expected_either", "应为 '{}' 或 '{}'"),
+    ("json.bad_value", "无法识别的值"),
+    ("json.unexpected_end", "内容意外结束"),
+    ("json.too_deep", "嵌套层数过多"),
+    ("json.bad_number", "无效的数字"),
+    ("json.bad_utf8", "无效的 UTF-8"),
+    ("json.unterminated", "字符串没有结束"),
+    ("json.bad_unicode", "无效的 \\u 转义"),
+    ("json.bad_escape", "无效的转义"),
+    // 演示模式
+    ("demo.start", "\n🔍 演示模式启动！"),
+    ("demo.intro", "📖 本演示将展示扫雷游戏的核心功能\n"),
+    ("demo.part1", "🔶 第一部分：棋盘初始化演示"),
+    ("demo.config", "📏 游戏配置: {}x{} 棋盘，{} 个地雷"),
+    ("demo.density", "💡 地雷密度: {}%"),
+    (
+        "demo.initial_board",
+        "\n🔍 初始棋盘状态（所有格子都未揭示）：",
+    ),
+    ("demo.part2", "\n🔶 第二部分：首次点击和地雷生成演示"),
+    ("demo.first_click", "🎯 选择中心位置进行首次点击: ({}, {})"),
+    (
+        "demo.first_click_hint",
+        "💡 首次点击会触发地雷生成，且保证点击位置安全",
+    ),
+    ("demo.click_result", "📊 点击结果: {}"),
+    (
+        "demo.after_first_click",
+        "\n🗺️ 首次点击后的棋盘（地雷已生成并开始揭示）：",
+    ),
+    ("demo.part3", "\n🔶 第三部分：自动揭示功能演示"),
+    (
+        "demo.auto_reveal_hint",
+        "💡 当点击空白格子时，会自动揭示相邻的空白区域",
+    ),
+    (
+        "demo.auto_reveal_click",
+        "🔍 尝试点击角落位置触发自动揭示: ({}, {})",
+    ),
+    ("demo.auto_reveal_result", "📊 自动揭示结果: {}"),
+    ("demo.after_auto_reveal", "\n🗺️ 自动揭示后的棋盘："),
+    ("demo.part4", "\n🔶 第四部分：标记功能演示"),
+    ("demo.flag", "🚩 演示标记功能，标记位置: ({}, {})"),
+    ("demo.flag_hint", "💡 右键点击可以标记可疑的地雷位置"),
+    ("demo.flag_result", "📊 标记结果: {}"),
+    ("demo.after_flag", "\n🗺️ 标记后的棋盘："),
+    ("demo.unflag", "\n🔄 演示取消标记（再次右键点击相同位置）:"),
+    ("demo.unflag_result", "📊 取消标记结果: {}"),
+    ("demo.part5", "\n🔶 第五部分：游戏状态检测演示"),
+    ("demo.part6", "\n🔶 第六部分：边界条件测试"),
+    ("demo.part7", "\n🔶 第七部分：性能和统计信息"),
+    ("demo.complete", "\n✅ 核心功能演示完成！"),
+    ("demo.ready", "🚀 游戏逻辑已就绪，可以接入GUI界面了！"),
+    ("demo.test_hint", "📋 运行 'cargo test' 查看详细测试"),
+    ("demo.summary", "\n🎓 演示总结:"),
+    ("demo.summary_init", "• 展示了完整的游戏初始化流程"),
+    ("demo.summary_reveal", "• 演示了核心的自动揭示功能"),
+    ("demo.summary_flag", "• 验证了标记和取消标记功能"),
+    ("demo.summary_boundary", "• 测试了各种边界条件和错误处理"),
+    ("demo.summary_stats", "• 提供了游戏状态和性能统计信息"),
+    (
+        "demo.outro",
+        "\n🚀 现在你已经了解了所有核心功能，可以开始游戏了！",
+    ),
+    ("demo.pause", "\n⏸️  按 Enter 继续下一部分演示..."),
+    ("demo.states", "💡 演示不同的游戏状态检测"),
+    (
+        "demo.small_board",
+        "\n🎯 创建3x3小棋盘（1个地雷）用于快速演示游戏状态：",
+    ),
+    ("demo.state_playing", "🎮 当前游戏状态：进行中"),
+    (
+        "demo.win_condition",
+        "\n💡 游戏胜利条件：揭示所有非地雷格子",
+    ),
+    ("demo.lose_condition", "💡 游戏失败条件：点击到地雷"),
+    ("demo.boundary", "💡 测试各种边界条件和错误处理"),
+    ("demo.repeat_click", "\n🔄 测试重复点击已揭示的格子:"),
+    ("demo.repeat_result", "📊 重复点击结果: {}"),
+    ("demo.click_flagged", "\n🚩 测试点击已标记的格子:"),
+    ("demo.click_flagged_result", "📊 点击已标记格子的结果: {}"),
+    ("demo.corners", "\n📐 测试棋盘边界位置的操作:"),
+    ("demo.corner", "  角落{}：({}, {}) - 可正常操作"),
+    ("demo.stats", "📊 游戏统计信息:"),
+    ("demo.total_cells", "• 总格子数: {}"),
+    ("demo.mine_count", "• 地雷数量: {}"),
+    ("demo.safe_cells", "• 安全格子数: {}"),
+    ("demo.revealed_cells", "• 当前已揭示格子数: {}"),
+    ("demo.flags_placed", "• 当前已标记旗子数: {}"),
+    ("demo.hidden_cells", "• 剩余未操作格子数: {}"),
+];
@@ -11,33 +11,184 @@ pub mod core;
 
 // 配置模块
 pub mod config;
+
+// 多语言模块
+pub mod i18n;
+
+// 棋盘输出模块
+pub mod render;
+
+// 图片导出模块
+pub mod export;
+
 // 应用模块
 pub mod app;
 
 // 演示模块
 pub mod demo;
 
+// 联机对战模块
+pub mod net;
+
+// 机器接口模块
+pub mod bot;
+
 // 测试模块 - 直接声明，不需要 tests/mod.rs
+#[cfg(test)]
+mod accessible_tests {
+    include!("tests/accessible_tests.rs");
+}
+
 #[cfg(test)]
 mod auto_reveal_tests {
     include!("tests/auto_reveal_tests.rs");
 }
 
+#[cfg(test)]
+mod board3d_tests {
+    include!("tests/board3d_tests.rs");
+}
+
 #[cfg(test)]
 mod board_tests {
     include!("tests/board_tests.rs");
 }
 
+#[cfg(test)]
+mod bot_protocol_tests {
+    include!("tests/bot_protocol_tests.rs");
+}
+
 #[cfg(test)]
 mod cell_tests {
     include!("tests/cell_tests.rs");
 }
 
+#[cfg(test)]
+mod click_details_tests {
+    include!("tests/click_details_tests.rs");
+}
+
+#[cfg(test)]
+mod coop_tests {
+    include!("tests/coop_tests.rs");
+}
+
+#[cfg(test)]
+mod env_tests {
+    include!("tests/env_tests.rs");
+}
+
+#[cfg(test)]
+mod event_tests {
+    include!("tests/event_tests.rs");
+}
+
+#[cfg(test)]
+mod export_tests {
+    include!("tests/export_tests.rs");
+}
+
+#[cfg(test)]
+mod flags_tests {
+    include!("tests/flags_tests.rs");
+}
+
 #[cfg(test)]
 mod game_flow_tests {
     include!("tests/game_flow_tests.rs");
 }
 
+#[cfg(test)]
+mod graph_board_tests {
+    include!("tests/graph_board_tests.rs");
+}
+
+#[cfg(test)]
+mod hex_tests {
+    include!("tests/hex_tests.rs");
+}
+
+#[cfg(test)]
+mod large_board_tests {
+    include!("tests/large_board_tests.rs");
+}
+
+#[cfg(test)]
+mod lives_tests {
+    include!("tests/lives_tests.rs");
+}
+
+#[cfg(test)]
+mod multi_mine_tests {
+    include!("tests/multi_mine_tests.rs");
+}
+
+#[cfg(test)]
+mod negative_mine_tests {
+    include!("tests/negative_mine_tests.rs");
+}
+
+#[cfg(test)]
+mod neighbourhood_tests {
+    include!("tests/neighbourhood_tests.rs");
+}
+
+#[cfg(test)]
+mod i18n_tests {
+    include!("tests/i18n_tests.rs");
+}
+
+#[cfg(test)]
+mod infinite_board_tests {
+    include!("tests/infinite_board_tests.rs");
+}
+
+#[cfg(test)]
+mod query_tests {
+    include!("tests/query_tests.rs");
+}
+
+#[cfg(test)]
+mod render_tests {
+    include!("tests/render_tests.rs");
+}
+
+#[cfg(test)]
+mod solver_tests {
+    include!("tests/solver_tests.rs");
+}
+
+#[cfg(test)]
+mod support {
+    include!("tests/support.rs");
+}
+
+#[cfg(test)]
+mod theme_tests {
+    include!("tests/theme_tests.rs");
+}
+
+#[cfg(test)]
+mod timed_tests {
+    include!("tests/timed_tests.rs");
+}
+
+#[cfg(test)]
+mod tournament_tests {
+    include!("tests/tournament_tests.rs");
+}
+
+#[cfg(test)]
+mod versus_tests {
+    include!("tests/versus_tests.rs");
+}
+
+#[cfg(test)]
+mod viewport_tests {
+    include!("tests/viewport_tests.rs");
+}
+
 // 重新导出主要的公共API，方便外部使用
 pub use config::difficulty::Difficulty;
 pub use core::board::{Board, Position};
@@ -0,0 +1,168 @@
+use crate::core::board::print_square_grid;
+use crate::core::{BoardSize, Position};
+use crate::net::protocol::{ClientMessage, ServerMessage, decode_view, visible_to_cell};
+use crate::net::server::PLAYER_COUNT;
+use crate::t;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+/// 联机对战的命令行客户端：读线程显示服务器消息，主线程把玩家输入发给服务器
+pub struct VersusClient {
+    stream: TcpStream,
+    name: String,
+}
+
+impl VersusClient {
+    pub fn connect(addr: impl ToSocketAddrs, name: &str) -> io::Result<Self> {
+        Ok(VersusClient {
+            stream: TcpStream::connect(addr)?,
+            name: name.to_string(),
+        })
+    }
+
+    pub fn run(&mut self) -> io::Result<()> {
+        let finished = Arc::new(AtomicBool::new(false));
+        let reader = {
+            let stream = self.stream.try_clone()?;
+            let finished = Arc::clone(&finished);
+            thread::spawn(move || display_messages(stream, &finished))
+        };
+
+        self.send(&ClientMessage::Hello(self.name.clone()))?;
+        print_help();
+
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            if finished.load(Ordering::SeqCst) {
+                break;
+            }
+            let command = line?.trim().to_lowercase();
+            let parts: Vec<&str> = command.split_whitespace().collect();
+            let message = match parts.as_slice() {
+                [] => continue,
+                ["help"] => {
+                    print_help();
+                    continue;
+                }
+                ["quit" | "exit"] => ClientMessage::Quit,
+                ["click", row, col] => match parse_position(row, col) {
+                    Some((row, col)) => ClientMessage::Click(Position { row, col }),
+                    None => {
+                        println!("{}", t!("game.usage_click"));
+                        continue;
+                    }
+                },
+                ["flag", row, col] => match parse_position(row, col) {
+                    Some((row, col)) => ClientMessage::Flag(Position { row, col }),
+                    None => {
+                        println!("{}", t!("game.usage_flag"));
+                        continue;
+                    }
+                },
+                _ => {
+                    println!("{}", t!("game.unknown_command", parts[0]));
+                    continue;
+                }
+            };
+            let quit = message == ClientMessage::Quit;
+            self.send(&message)?;
+            if quit {
+                break;
+            }
+        }
+
+        let _ = reader.join();
+        Ok(())
+    }
+
+    fn send(&mut self, message: &ClientMessage) -> io::Result<()> {
+        writeln!(self.stream, "{}", message.to_line())
+    }
+}
+
+fn parse_position(row: &str, col: &str) -> Option<(usize, usize)> {
+    Some((row.parse().ok()?, col.parse().ok()?))
+}
+
+fn print_help() {
+    println!("{}", t!("help.title"));
+    println!("{}", t!("net.help_click"));
+    println!("{}", t!("net.help_flag"));
+    println!("{}", t!("help.help"));
+    println!("{}", t!("versus.help_quit"));
+    println!("{}", t!("help.coordinates"));
+    println!();
+}
+
+// 显示服务器消息，直到收到比赛结果或连接断开
+fn display_messages(stream: TcpStream, finished: &AtomicBool) {
+    let mut me = 0;
+    let mut size = BoardSize {
+        width: 0,
+        height: 0,
+    };
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        match ServerMessage::parse(&line) {
+            Some(ServerMessage::Welcome {
+                player,
+                size: board_size,
+                mines,
+                lives,
+            }) => {
+                me = player;
+                size = board_size;
+                println!(
+                    "{}",
+                    t!("versus.joined", size.width, size.height, mines, lives)
+                );
+            }
+            Some(ServerMessage::Start { opponent }) => {
+                println!("{}", t!("versus.start", opponent));
+            }
+            Some(ServerMessage::View(cells)) => match decode_view(&cells, size) {
+                Some(view) => {
+                    print_square_grid(size, |pos| {
+                        visible_to_cell(view[pos.row * size.width + pos.col])
+                    });
+                }
+                None => println!("{}", t!("net.bad_view")),
+            },
+            Some(ServerMessage::Progress {
+                player,
+                percent,
+                lives,
+                finish,
+            }) => {
+                let who = if player == me {
+                    t!("versus.you")
+                } else {
+                    t!("versus.opponent")
+                };
+                let finish = finish.map_or(String::new(), |time| {
+                    t!("versus.finish_time", format!("{:.1}", time.as_secs_f64()))
+                });
+                println!("{}", t!("versus.progress", who, percent, lives, finish));
+            }
+            Some(ServerMessage::Result { winner }) => {
+                match winner {
+                    Some(player) if player == me => println!("{}", t!("versus.won")),
+                    Some(player) if player < PLAYER_COUNT => println!("{}", t!("versus.lost")),
+                    _ => println!("{}", t!("over.draw")),
+                }
+                println!("{}", t!("net.press_enter"));
+                finished.store(true, Ordering::SeqCst);
+                return;
+            }
+            Some(ServerMessage::Error(message)) => println!("❌ {}", message),
+            None => println!("{}", t!("net.bad_message", line)),
+        }
+    }
+    println!("{}", t!("net.disconnected"));
+    finished.store(true, Ordering::SeqCst);
+}
@@ -0,0 +1,264 @@
+use crate::core::board::print_square_grid_with_colors;
+use crate::core::coop::PLAYER_COLORS;
+use crate::core::{BoardSize, CoopOutcome, Position, VisibleCell};
+use crate::net::protocol::{
+    CoopClientMessage, CoopServerMessage, decode_owners, decode_view, visible_to_cell,
+};
+use crate::render::RESET;
+use crate::t;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread;
+
+/// 合作模式的命令行客户端：读线程显示共享棋盘和其他玩家的动作，主线程把玩家输入发给服务器
+pub struct CoopClient {
+    stream: TcpStream,
+    name: String,
+}
+
+impl CoopClient {
+    pub fn connect(addr: impl ToSocketAddrs, name: &str) -> io::Result<Self> {
+        Ok(CoopClient {
+            stream: TcpStream::connect(addr)?,
+            name: name.to_string(),
+        })
+    }
+
+    pub fn run(&mut self) -> io::Result<()> {
+        let finished = Arc::new(AtomicBool::new(false));
+        // 最后收到的棋盘版本号，随操作一起发送以便服务器检测冲突
+        let revision = Arc::new(AtomicU64::new(0));
+        let reader = {
+            let stream = self.stream.try_clone()?;
+            let finished = Arc::clone(&finished);
+            let revision = Arc::clone(&revision);
+            thread::spawn(move || Display::default().run(stream, &finished, &revision))
+        };
+
+        self.send(&CoopClientMessage::Hello(self.name.clone()))?;
+        print_help();
+
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            if finished.load(Ordering::SeqCst) {
+                break;
+            }
+            let command = line?.trim().to_lowercase();
+            let parts: Vec<&str> = command.split_whitespace().collect();
+            let seen = revision.load(Ordering::SeqCst);
+            let message = match parts.as_slice() {
+                [] => continue,
+                ["help"] => {
+                    print_help();
+                    continue;
+                }
+                ["quit" | "exit"] => CoopClientMessage::Quit,
+                [command @ ("click" | "flag" | "move"), row, col] => {
+                    let Some(pos) = parse_position(row, col) else {
+                        println!("{}", t!("game.usage_position", command));
+                        continue;
+                    };
+                    match *command {
+                        "click" => CoopClientMessage::Reveal { pos, seen },
+                        "flag" => CoopClientMessage::Flag { pos, seen },
+                        _ => CoopClientMessage::Cursor(pos),
+                    }
+                }
+                _ => {
+                    println!("{}", t!("game.unknown_command", parts[0]));
+                    continue;
+                }
+            };
+            let quit = message == CoopClientMessage::Quit;
+            self.send(&message)?;
+            if quit {
+                break;
+            }
+        }
+
+        let _ = reader.join();
+        Ok(())
+    }
+
+    fn send(&mut self, message: &CoopClientMessage) -> io::Result<()> {
+        writeln!(self.stream, "{}", message.to_line())
+    }
+}
+
+fn parse_position(row: &str, col: &str) -> Option<Position> {
+    Some(Position {
+        row: row.parse().ok()?,
+        col: col.parse().ok()?,
+    })
+}
+
+fn print_help() {
+    println!("{}", t!("help.title"));
+    println!("{}", t!("net.help_click"));
+    println!("{}", t!("net.help_flag"));
+    println!("{}", t!("coop.help_move"));
+    println!("{}", t!("help.help"));
+    println!("{}", t!("coop.help_quit"));
+    println!("{}", t!("coop.help_colors"));
+    println!();
+}
+
+// 读线程维护的显示状态
+#[derive(Default)]
+struct Display {
+    me: usize,
+    size: Option<BoardSize>,
+    names: Vec<String>,
+    cursors: Vec<Option<Position>>,
+    view: Vec<VisibleCell>,
+}
+
+impl Display {
+    // 显示服务器消息，直到收到结果或连接断开
+    fn run(mut self, stream: TcpStream, finished: &AtomicBool, revision: &AtomicU64) {
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            let Some(message) = CoopServerMessage::parse(&line) else {
+                println!("{}", t!("net.bad_message", line));
+                continue;
+            };
+            match message {
+                CoopServerMessage::Welcome {
+                    player,
+                    players,
+                    size,
+                    mines,
+                } => {
+                    self.me = player;
+                    self.size = Some(size);
+                    self.names = (0..players)
+                        .map(|p| t!("default.player_number", p + 1))
+                        .collect();
+                    self.cursors = vec![None; players];
+                    println!(
+                        "{}",
+                        t!("coop.joined_room", size.width, size.height, mines, players)
+                    );
+                }
+                CoopServerMessage::Joined { player, name } => {
+                    println!("{}", t!("coop.joined", self.colored(player, &name)));
+                    if let Some(slot) = self.names.get_mut(player) {
+                        *slot = name;
+                    }
+                }
+                CoopServerMessage::View {
+                    revision: rev,
+                    cells,
+                } => {
+                    revision.store(rev, Ordering::SeqCst);
+                    match self.size.and_then(|size| decode_view(&cells, size)) {
+                        Some(view) => self.view = view,
+                        None => println!("{}", t!("net.bad_view")),
+                    }
+                }
+                // 操作者信息总是紧跟在棋盘之后，收到后再一起打印
+                CoopServerMessage::Owners(owners) => {
+                    match self.size.and_then(|size| decode_owners(&owners, size)) {
+                        Some(owners) => self.print_board(&owners),
+                        None => println!("{}", t!("coop.bad_owners")),
+                    }
+                }
+                CoopServerMessage::Cursor { player, pos } => {
+                    self.move_cursor(player, pos);
+                    if player != self.me {
+                        println!(
+                            "{}",
+                            t!("coop.cursor_moved", self.name(player), pos.row, pos.col)
+                        );
+                    }
+                }
+                CoopServerMessage::Revealed { player, pos, cells } => {
+                    self.move_cursor(player, pos);
+                    println!(
+                        "{}",
+                        t!("coop.revealed", self.name(player), pos.row, pos.col, cells)
+                    );
+                }
+                CoopServerMessage::Flagged {
+                    player,
+                    pos,
+                    flagged,
+                } => {
+                    self.move_cursor(player, pos);
+                    let key = if flagged {
+                        "coop.flagged"
+                    } else {
+                        "coop.unflagged"
+                    };
+                    println!("{}", t!(key, self.name(player), pos.row, pos.col));
+                }
+                CoopServerMessage::Conflict { by, pos } => {
+                    println!("{}", t!("coop.conflict", pos.row, pos.col, self.name(by)));
+                }
+                CoopServerMessage::Left { player } => {
+                    println!("{}", t!("coop.left", self.name(player)));
+                }
+                CoopServerMessage::Result(outcome) => {
+                    match outcome {
+                        CoopOutcome::Won => println!("{}", t!("coop.won")),
+                        CoopOutcome::Lost { player } => {
+                            println!("{}", t!("coop.lost", self.name(player)))
+                        }
+                    }
+                    println!("{}", t!("net.press_enter"));
+                    finished.store(true, Ordering::SeqCst);
+                    return;
+                }
+                CoopServerMessage::Error(message) => println!("❌ {}", message),
+            }
+        }
+        println!("{}", t!("net.disconnected"));
+        finished.store(true, Ordering::SeqCst);
+    }
+
+    fn name(&self, player: usize) -> String {
+        let name = self.names.get(player).map_or("?", String::as_str);
+        self.colored(player, name)
+    }
+
+    fn colored(&self, player: usize, text: &str) -> String {
+        let color = PLAYER_COLORS.get(player).copied().unwrap_or("");
+        format!("{}{}{}", color, text, RESET)
+    }
+
+    fn move_cursor(&mut self, player: usize, pos: Position) {
+        if let Some(cursor) = self.cursors.get_mut(player) {
+            *cursor = Some(pos);
+        }
+    }
+
+    fn print_board(&self, owners: &[Option<usize>]) {
+        let Some(size) = self.size else {
+            return;
+        };
+        if self.view.len() != owners.len() {
+            return;
+        }
+        print_square_grid_with_colors(
+            size,
+            |pos| visible_to_cell(self.view[pos.row * size.width + pos.col]),
+            |pos| {
+                owners[pos.row * size.width + pos.col]
+                    .and_then(|player| PLAYER_COLORS.get(player).copied())
+            },
+        );
+        for (player, cursor) in self.cursors.iter().enumerate() {
+            let cursor =
+                cursor.map_or("-".to_string(), |pos| format!("({}, {})", pos.row, pos.col));
+            let marker = if player == self.me { t!("coop.me") } else { "" };
+            println!(
+                "{}",
+                t!("coop.cursor_line", self.name(player), marker, cursor)
+            );
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! 合作模式服务器
+//!
+//! 所有玩家共享服务器上的一个 CoopSession。各连接的读线程把消息转发到主循环，
+//! 主循环按到达顺序逐条执行，因此同时到达的操作也有确定的先后；
+//! 后到的操作如果基于过期的版本号，会由 CoopSession 判为冲突并只通知操作者本人。
+
+use crate::core::{BoardConfig, CellState, CoopEvent, CoopOutcome, CoopSession};
+use crate::net::protocol::{CoopClientMessage, CoopServerMessage, encode_owners, encode_view};
+use crate::net::server::{Incoming, spawn_reader};
+use crate::t;
+use std::io::{self, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver};
+
+#[derive(Clone, Copy, Debug)]
+pub struct CoopConfig {
+    pub board_config: BoardConfig,
+    pub seed: u64,
+    /// 玩家人数，1 到 MAX_PLAYERS
+    pub players: usize,
+}
+
+pub struct CoopServer {
+    listener: TcpListener,
+    config: CoopConfig,
+}
+
+impl CoopServer {
+    /// 只支持经典规则的棋盘（视图编码只能表示 0-8 的数字），其他配置返回 InvalidInput 错误
+    pub fn bind(addr: impl ToSocketAddrs, config: CoopConfig) -> io::Result<Self> {
+        let board_config = config.board_config;
+        if board_config.mines_per_cell != 1 || board_config.negative_mines != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t!("coop.classic_only"),
+            ));
+        }
+        Ok(CoopServer {
+            listener: TcpListener::bind(addr)?,
+            config,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// 等待所有玩家加入并进行一局，返回结果；所有玩家都中途离开时返回 None
+    pub fn run(self) -> io::Result<Option<CoopOutcome>> {
+        let config = self.config;
+        let session = CoopSession::with_seed(config.board_config, config.players, config.seed);
+
+        let (sender, receiver) = mpsc::channel();
+        let mut writers = Vec::with_capacity(config.players);
+        for player in 0..config.players {
+            let (stream, _) = self.listener.accept()?;
+            let mut writer = stream.try_clone()?;
+            send(
+                &mut writer,
+                &CoopServerMessage::Welcome {
+                    player,
+                    players: config.players,
+                    size: config.board_config.board_size,
+                    mines: config.board_config.mine_count,
+                },
+            )?;
+            spawn_reader(player, stream, sender.clone(), CoopClientMessage::parse);
+            writers.push(writer);
+        }
+        drop(sender);
+
+        let mut room = Room {
+            session,
+            writers,
+            connected: vec![true; config.players],
+            receiver,
+        };
+
+        let mut names: Vec<Option<String>> = vec![None; config.players];
+        while names.iter().any(Option::is_none) {
+            match room.receiver.recv() {
+                Ok(Incoming::Message(player, CoopClientMessage::Hello(name))) => {
+                    names[player] = Some(name)
+                }
+                Ok(Incoming::Message(player, _)) | Ok(Incoming::Invalid(player, _)) => {
+                    room.send(player, &error(t!("net.hello_first")))
+                }
+                // 开局前有人离开：无法凑齐人数，结束
+                Ok(Incoming::Disconnected(_)) | Err(_) => return Ok(None),
+            }
+        }
+
+        for (player, name) in names.into_iter().enumerate() {
+            let name = name.unwrap_or_default();
+            room.broadcast(&CoopServerMessage::Joined { player, name });
+        }
+        room.broadcast_board();
+        Ok(room.play())
+    }
+}
+
+struct Room {
+    session: CoopSession,
+    writers: Vec<TcpStream>,
+    connected: Vec<bool>,
+    receiver: Receiver<Incoming<CoopClientMessage>>,
+}
+
+impl Room {
+    fn play(&mut self) -> Option<CoopOutcome> {
+        loop {
+            let Ok(incoming) = self.receiver.recv() else {
+                return None;
+            };
+            match incoming {
+                Incoming::Message(player, message) => self.handle(player, message),
+                Incoming::Invalid(player, line) => {
+                    self.send(player, &error(&t!("net.bad_client_message", line)))
+                }
+                Incoming::Disconnected(player) => self.leave(player),
+            }
+
+            if let Some(outcome) = self.session.outcome() {
+                self.broadcast(&CoopServerMessage::Result(outcome));
+                return Some(outcome);
+            }
+            if !self.connected.contains(&true) {
+                return None;
+            }
+        }
+    }
+
+    fn handle(&mut self, player: usize, message: CoopClientMessage) {
+        let (action, seen) = match message {
+            CoopClientMessage::Hello(_) => {
+                self.send(player, &error(t!("coop.already_started")));
+                return;
+            }
+            CoopClientMessage::Quit => {
+                self.leave(player);
+                return;
+            }
+            message => message
+                .action()
+                .expect("only Hello and Quit carry no action"),
+        };
+
+        let pos = action.position();
+        match self.session.apply(player, action, seen) {
+            CoopEvent::Moved => self.broadcast(&CoopServerMessage::Cursor { player, pos }),
+            CoopEvent::Revealed { cells } => {
+                self.broadcast(&CoopServerMessage::Revealed { player, pos, cells });
+                self.broadcast_board();
+            }
+            CoopEvent::Flagged | CoopEvent::Unflagged => {
+                let flagged = self.session.board().get_cell_state(pos) == CellState::Flagged;
+                self.broadcast(&CoopServerMessage::Flagged {
+                    player,
+                    pos,
+                    flagged,
+                });
+                self.broadcast_board();
+            }
+            // 结果随后由主循环广播
+            CoopEvent::Lost { .. } | CoopEvent::Won => self.broadcast_board(),
+            // 冲突只通知操作者，并补发最新棋盘
+            CoopEvent::Conflict { by } => {
+                self.send(player, &CoopServerMessage::Conflict { by, pos });
+                self.send_board(player);
+            }
+            CoopEvent::Invalid => self.send(player, &error(t!("coop.invalid"))),
+        }
+    }
+
+    fn leave(&mut self, player: usize) {
+        if std::mem::replace(&mut self.connected[player], false) {
+            self.broadcast(&CoopServerMessage::Left { player });
+        }
+    }
+
+    fn board_messages(&self) -> [CoopServerMessage; 2] {
+        [
+            CoopServerMessage::View {
+                revision: self.session.revision(),
+                cells: encode_view(self.session.board()),
+            },
+            CoopServerMessage::Owners(encode_owners(&self.session)),
+        ]
+    }
+
+    fn send_board(&mut self, player: usize) {
+        for message in self.board_messages() {
+            self.send(player, &message);
+        }
+    }
+
+    fn broadcast_board(&mut self) {
+        for message in self.board_messages() {
+            self.broadcast(&message);
+        }
+    }
+
+    fn broadcast(&mut self, message: &CoopServerMessage) {
+        for player in 0..self.writers.len() {
+            if self.connected[player] {
+                self.send(player, message);
+            }
+        }
+    }
+
+    // 写入失败说明对方已断开，读线程会报告断开，这里忽略错误
+    fn send(&mut self, player: usize, message: &CoopServerMessage) {
+        let _ = send(&mut self.writers[player], message);
+    }
+}
+
+fn error(message: &str) -> CoopServerMessage {
+    CoopServerMessage::Error(message.to_string())
+}
+
+fn send(writer: &mut TcpStream, message: &CoopServerMessage) -> io::Result<()> {
+    writeln!(writer, "{}", message.to_line())
+}
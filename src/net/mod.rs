@@ -0,0 +1,21 @@
+//! 联机对战模块
+//!
+//! 通过 TCP 进行的多人游戏：
+//! - protocol: 逐行文本协议和可见棋盘的编码
+//! - server / client: 双人在相同棋盘上竞速的服务器和命令行客户端
+//! - coop_server / coop_client: 多人共享一块棋盘的合作模式
+
+pub mod client;
+pub mod coop_client;
+pub mod coop_server;
+pub mod protocol;
+pub mod server;
+
+pub use client::VersusClient;
+pub use coop_client::CoopClient;
+pub use coop_server::{CoopConfig, CoopServer};
+pub use protocol::{
+    ClientMessage, CoopClientMessage, CoopServerMessage, ServerMessage, decode_owners, decode_view,
+    encode_owners, encode_view,
+};
+pub use server::{VersusConfig, VersusServer};
@@ -0,0 +1,447 @@
+//! 联机对战的文本协议
+//!
+//! 每条消息占一行，以空格分隔字段。棋盘只以玩家可见的内容发送：
+//! `#` 隐藏、`F` 旗子、`*` 已翻开的地雷、`0`-`8` 数字，地雷布局从不离开服务器。
+//! 竞速模式使用 ClientMessage / ServerMessage，合作模式使用 CoopClientMessage / CoopServerMessage。
+
+use crate::config::network::MAX_NAME_CHARS;
+use crate::core::{
+    Board, BoardSize, Cell, CellContent, CellState, CoopAction, CoopOutcome, CoopSession, Position,
+    VisibleCell,
+};
+use std::time::Duration;
+
+/// 客户端发给服务器的消息
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClientMessage {
+    Hello(String),
+    Click(Position),
+    Flag(Position),
+    Quit,
+}
+
+/// 服务器发给客户端的消息
+#[derive(Clone, Debug, PartialEq)]
+pub enum ServerMessage {
+    /// 连接后告知玩家编号和棋盘参数
+    Welcome {
+        player: usize,
+        size: BoardSize,
+        mines: usize,
+        lives: u32,
+    },
+    /// 两名玩家都已加入，比赛开始
+    Start {
+        opponent: String,
+    },
+    /// 该玩家棋盘的可见内容，按行优先排列
+    View(String),
+    /// 某位玩家的进度，广播给双方
+    Progress {
+        player: usize,
+        percent: u8,
+        lives: u32,
+        finish: Option<Duration>,
+    },
+    /// 比赛结果，None 表示平局
+    Result {
+        winner: Option<usize>,
+    },
+    Error(String),
+}
+
+fn parse_position(fields: &[&str]) -> Option<Position> {
+    match fields {
+        [row, col] => Some(Position {
+            row: row.parse().ok()?,
+            col: col.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+// 名字过长时截掉多余的字符，避免其他玩家的界面被撑开
+fn player_name(rest: &str) -> String {
+    rest.trim().chars().take(MAX_NAME_CHARS).collect()
+}
+
+impl ClientMessage {
+    pub fn to_line(&self) -> String {
+        match self {
+            ClientMessage::Hello(name) => format!("HELLO {}", name),
+            ClientMessage::Click(pos) => format!("CLICK {} {}", pos.row, pos.col),
+            ClientMessage::Flag(pos) => format!("FLAG {} {}", pos.row, pos.col),
+            ClientMessage::Quit => "QUIT".to_string(),
+        }
+    }
+
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        match command {
+            "HELLO" if !rest.trim().is_empty() => Some(ClientMessage::Hello(player_name(rest))),
+            "CLICK" => parse_position(&fields).map(ClientMessage::Click),
+            "FLAG" => parse_position(&fields).map(ClientMessage::Flag),
+            "QUIT" if fields.is_empty() => Some(ClientMessage::Quit),
+            _ => None,
+        }
+    }
+}
+
+impl ServerMessage {
+    pub fn to_line(&self) -> String {
+        match self {
+            ServerMessage::Welcome {
+                player,
+                size,
+                mines,
+                lives,
+            } => format!(
+                "WELCOME {} {} {} {} {}",
+                player, size.width, size.height, mines, lives
+            ),
+            ServerMessage::Start { opponent } => format!("START {}", opponent),
+            ServerMessage::View(cells) => format!("VIEW {}", cells),
+            ServerMessage::Progress {
+                player,
+                percent,
+                lives,
+                finish,
+            } => {
+                let finish = finish.map_or("-".to_string(), |time| time.as_millis().to_string());
+                format!("PROGRESS {} {} {} {}", player, percent, lives, finish)
+            }
+            ServerMessage::Result { winner } => match winner {
+                Some(player) => format!("RESULT {}", player),
+                None => "RESULT DRAW".to_string(),
+            },
+            ServerMessage::Error(message) => format!("ERROR {}", message),
+        }
+    }
+
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        match (command, fields.as_slice()) {
+            ("WELCOME", [player, width, height, mines, lives]) => Some(ServerMessage::Welcome {
+                player: player.parse().ok()?,
+                size: BoardSize {
+                    width: width.parse().ok()?,
+                    height: height.parse().ok()?,
+                },
+                mines: mines.parse().ok()?,
+                lives: lives.parse().ok()?,
+            }),
+            ("START", _) => Some(ServerMessage::Start {
+                opponent: rest.trim().to_string(),
+            }),
+            ("VIEW", [cells]) => Some(ServerMessage::View(cells.to_string())),
+            ("PROGRESS", [player, percent, lives, finish]) => Some(ServerMessage::Progress {
+                player: player.parse().ok()?,
+                percent: percent.parse().ok()?,
+                lives: lives.parse().ok()?,
+                finish: match *finish {
+                    "-" => None,
+                    millis => Some(Duration::from_millis(millis.parse().ok()?)),
+                },
+            }),
+            ("RESULT", ["DRAW"]) => Some(ServerMessage::Result { winner: None }),
+            ("RESULT", [player]) => Some(ServerMessage::Result {
+                winner: Some(player.parse().ok()?),
+            }),
+            ("ERROR", _) => Some(ServerMessage::Error(rest.trim().to_string())),
+            _ => None,
+        }
+    }
+}
+
+/// 合作模式中客户端发给服务器的消息；seen 是客户端最后收到的棋盘版本号
+#[derive(Clone, Debug, PartialEq)]
+pub enum CoopClientMessage {
+    Hello(String),
+    Cursor(Position),
+    Reveal { pos: Position, seen: u64 },
+    Flag { pos: Position, seen: u64 },
+    Quit,
+}
+
+/// 合作模式中服务器发给客户端的消息
+#[derive(Clone, Debug, PartialEq)]
+pub enum CoopServerMessage {
+    /// 连接后告知玩家编号、总人数和棋盘参数
+    Welcome {
+        player: usize,
+        players: usize,
+        size: BoardSize,
+        mines: usize,
+    },
+    Joined {
+        player: usize,
+        name: String,
+    },
+    /// 共享棋盘的可见内容和版本号
+    View {
+        revision: u64,
+        cells: String,
+    },
+    /// 每个格子由哪位玩家翻开或标记，编码见 encode_owners
+    Owners(String),
+    Cursor {
+        player: usize,
+        pos: Position,
+    },
+    Revealed {
+        player: usize,
+        pos: Position,
+        cells: usize,
+    },
+    Flagged {
+        player: usize,
+        pos: Position,
+        flagged: bool,
+    },
+    /// 发给操作者本人：目标格子已被 by 改动，操作未执行
+    Conflict {
+        by: usize,
+        pos: Position,
+    },
+    Left {
+        player: usize,
+    },
+    Result(CoopOutcome),
+    Error(String),
+}
+
+impl CoopClientMessage {
+    /// 对应的棋盘操作和版本号；Hello 和 Quit 返回 None
+    pub fn action(&self) -> Option<(CoopAction, u64)> {
+        match *self {
+            CoopClientMessage::Cursor(pos) => Some((CoopAction::Cursor(pos), 0)),
+            CoopClientMessage::Reveal { pos, seen } => Some((CoopAction::Reveal(pos), seen)),
+            CoopClientMessage::Flag { pos, seen } => Some((CoopAction::Flag(pos), seen)),
+            CoopClientMessage::Hello(_) | CoopClientMessage::Quit => None,
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        match self {
+            CoopClientMessage::Hello(name) => format!("HELLO {}", name),
+            CoopClientMessage::Cursor(pos) => format!("CURSOR {} {}", pos.row, pos.col),
+            CoopClientMessage::Reveal { pos, seen } => {
+                format!("REVEAL {} {} {}", pos.row, pos.col, seen)
+            }
+            CoopClientMessage::Flag { pos, seen } => {
+                format!("FLAG {} {} {}", pos.row, pos.col, seen)
+            }
+            CoopClientMessage::Quit => "QUIT".to_string(),
+        }
+    }
+
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        match (command, fields.as_slice()) {
+            ("HELLO", _) if !rest.trim().is_empty() => {
+                Some(CoopClientMessage::Hello(player_name(rest)))
+            }
+            ("CURSOR", position) => parse_position(position).map(CoopClientMessage::Cursor),
+            ("REVEAL", [row, col, seen]) => Some(CoopClientMessage::Reveal {
+                pos: parse_position(&[row, col])?,
+                seen: seen.parse().ok()?,
+            }),
+            ("FLAG", [row, col, seen]) => Some(CoopClientMessage::Flag {
+                pos: parse_position(&[row, col])?,
+                seen: seen.parse().ok()?,
+            }),
+            ("QUIT", []) => Some(CoopClientMessage::Quit),
+            _ => None,
+        }
+    }
+}
+
+impl CoopServerMessage {
+    pub fn to_line(&self) -> String {
+        match self {
+            CoopServerMessage::Welcome {
+                player,
+                players,
+                size,
+                mines,
+            } => format!(
+                "WELCOME {} {} {} {} {}",
+                player, players, size.width, size.height, mines
+            ),
+            CoopServerMessage::Joined { player, name } => format!("JOINED {} {}", player, name),
+            CoopServerMessage::View { revision, cells } => format!("VIEW {} {}", revision, cells),
+            CoopServerMessage::Owners(owners) => format!("OWNERS {}", owners),
+            CoopServerMessage::Cursor { player, pos } => {
+                format!("CURSOR {} {} {}", player, pos.row, pos.col)
+            }
+            CoopServerMessage::Revealed { player, pos, cells } => {
+                format!("REVEALED {} {} {} {}", player, pos.row, pos.col, cells)
+            }
+            CoopServerMessage::Flagged {
+                player,
+                pos,
+                flagged,
+            } => format!(
+                "FLAGGED {} {} {} {}",
+                player,
+                pos.row,
+                pos.col,
+                if *flagged { "ON" } else { "OFF" }
+            ),
+            CoopServerMessage::Conflict { by, pos } => {
+                format!("CONFLICT {} {} {}", by, pos.row, pos.col)
+            }
+            CoopServerMessage::Left { player } => format!("LEFT {}", player),
+            CoopServerMessage::Result(CoopOutcome::Won) => "RESULT WON".to_string(),
+            CoopServerMessage::Result(CoopOutcome::Lost { player }) => {
+                format!("RESULT LOST {}", player)
+            }
+            CoopServerMessage::Error(message) => format!("ERROR {}", message),
+        }
+    }
+
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        match (command, fields.as_slice()) {
+            ("WELCOME", [player, players, width, height, mines]) => {
+                Some(CoopServerMessage::Welcome {
+                    player: player.parse().ok()?,
+                    players: players.parse().ok()?,
+                    size: BoardSize {
+                        width: width.parse().ok()?,
+                        height: height.parse().ok()?,
+                    },
+                    mines: mines.parse().ok()?,
+                })
+            }
+            ("JOINED", [player, ..]) => Some(CoopServerMessage::Joined {
+                player: player.parse().ok()?,
+                name: rest.trim()[player.len()..].trim().to_string(),
+            }),
+            ("VIEW", [revision, cells]) => Some(CoopServerMessage::View {
+                revision: revision.parse().ok()?,
+                cells: cells.to_string(),
+            }),
+            ("OWNERS", [owners]) => Some(CoopServerMessage::Owners(owners.to_string())),
+            ("CURSOR", [player, row, col]) => Some(CoopServerMessage::Cursor {
+                player: player.parse().ok()?,
+                pos: parse_position(&[row, col])?,
+            }),
+            ("REVEALED", [player, row, col, cells]) => Some(CoopServerMessage::Revealed {
+                player: player.parse().ok()?,
+                pos: parse_position(&[row, col])?,
+                cells: cells.parse().ok()?,
+            }),
+            ("FLAGGED", [player, row, col, state]) => Some(CoopServerMessage::Flagged {
+                player: player.parse().ok()?,
+                pos: parse_position(&[row, col])?,
+                flagged: match *state {
+                    "ON" => true,
+                    "OFF" => false,
+                    _ => return None,
+                },
+            }),
+            ("CONFLICT", [by, row, col]) => Some(CoopServerMessage::Conflict {
+                by: by.parse().ok()?,
+                pos: parse_position(&[row, col])?,
+            }),
+            ("LEFT", [player]) => Some(CoopServerMessage::Left {
+                player: player.parse().ok()?,
+            }),
+            ("RESULT", ["WON"]) => Some(CoopServerMessage::Result(CoopOutcome::Won)),
+            ("RESULT", ["LOST", player]) => Some(CoopServerMessage::Result(CoopOutcome::Lost {
+                player: player.parse().ok()?,
+            })),
+            ("ERROR", _) => Some(CoopServerMessage::Error(rest.trim().to_string())),
+            _ => None,
+        }
+    }
+}
+
+/// 把棋盘的可见内容编码为一行字符，只读取玩家能看到的信息
+pub fn encode_view(board: &Board) -> String {
+    let size = board.get_board_config().board_size;
+    (0..size.height)
+        .flat_map(|row| (0..size.width).map(move |col| Position { row, col }))
+        .map(|pos| view_char(VisibleCell::of(board, pos)))
+        .collect()
+}
+
+/// 单个格子的可见内容编码
+pub(crate) fn view_char(cell: VisibleCell) -> char {
+    match cell {
+        VisibleCell::Hidden => '#',
+        VisibleCell::Flagged => 'F',
+        VisibleCell::Mine => '*',
+        VisibleCell::Number(n) => u32::try_from(n)
+            .ok()
+            .and_then(|n| char::from_digit(n, 36))
+            .unwrap_or('?'),
+    }
+}
+
+/// 解码可见内容；长度与棋盘不符或含有未知字符时返回 None
+pub fn decode_view(cells: &str, size: BoardSize) -> Option<Vec<VisibleCell>> {
+    let decoded: Vec<VisibleCell> = cells
+        .chars()
+        .map(|ch| match ch {
+            '#' => Some(VisibleCell::Hidden),
+            'F' => Some(VisibleCell::Flagged),
+            '*' => Some(VisibleCell::Mine),
+            digit => digit.to_digit(36).map(|n| VisibleCell::Number(n as i16)),
+        })
+        .collect::<Option<_>>()?;
+    (decoded.len() == size.cell_count()).then_some(decoded)
+}
+
+/// 编码每个格子的操作者：玩家编号的数字，无人操作的格子为 `-`
+pub fn encode_owners(session: &CoopSession) -> String {
+    let size = session.board().get_board_config().board_size;
+    (0..size.height)
+        .flat_map(|row| (0..size.width).map(move |col| Position { row, col }))
+        .map(|pos| {
+            session
+                .actor(pos)
+                .and_then(|player| char::from_digit(player as u32, 10))
+                .unwrap_or('-')
+        })
+        .collect()
+}
+
+/// 解码操作者；长度与棋盘不符或含有未知字符时返回 None
+pub fn decode_owners(owners: &str, size: BoardSize) -> Option<Vec<Option<usize>>> {
+    let decoded: Vec<Option<usize>> = owners
+        .chars()
+        .map(|ch| match ch {
+            '-' => Some(None),
+            digit => digit.to_digit(10).map(|player| Some(player as usize)),
+        })
+        .collect::<Option<_>>()?;
+    (decoded.len() == size.cell_count()).then_some(decoded)
+}
+
+/// 把可见内容还原为 Cell，供客户端复用棋盘打印
+pub(crate) fn visible_to_cell(visible: VisibleCell) -> Cell {
+    let mut cell = Cell::new();
+    match visible {
+        VisibleCell::Hidden => {}
+        VisibleCell::Flagged => cell.set_state(CellState::Flagged),
+        VisibleCell::Mine => {
+            cell.set_content(CellContent::Mine(1));
+            cell.set_state(CellState::Revealed);
+        }
+        VisibleCell::Number(n) => {
+            cell.set_content(CellContent::Number(n));
+            cell.set_state(CellState::Revealed);
+        }
+    }
+    cell
+}
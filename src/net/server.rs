@@ -0,0 +1,364 @@
+//! 联机对战服务器
+//!
+//! 服务器持有双方的 Board，校验每一次点击，只把可见内容发回给玩家本人，
+//! 并向双方广播进度。每个连接由一个读线程把消息转发到主循环，棋盘只在主循环中修改。
+
+use crate::config::network::MAX_LINE_BYTES;
+use crate::core::{Board, BoardConfig, ClickResult, Clock, Lives, Position, SystemClock};
+use crate::net::protocol::{ClientMessage, ServerMessage, encode_view};
+use crate::t;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// 对战的玩家人数
+pub const PLAYER_COUNT: usize = 2;
+
+#[derive(Clone, Copy, Debug)]
+pub struct VersusConfig {
+    /// 双方使用相同配置和种子的棋盘（只支持经典规则）
+    pub board_config: BoardConfig,
+    pub seed: u64,
+    pub lives: u32,
+}
+
+// 玩家的比赛状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Finish {
+    Playing,
+    Cleared(Duration),
+    /// 生命耗尽
+    Out,
+    /// 中途退出或断开，对手直接获胜
+    Forfeit,
+}
+
+struct Player {
+    name: String,
+    board: Board,
+    lives: Lives,
+    finish: Finish,
+    writer: TcpStream,
+}
+
+// 读线程转发给主循环的事件，M 为客户端消息类型
+pub(crate) enum Incoming<M> {
+    Message(usize, M),
+    Invalid(usize, String),
+    Disconnected(usize),
+}
+
+pub struct VersusServer {
+    listener: TcpListener,
+    config: VersusConfig,
+}
+
+impl VersusServer {
+    /// 只支持经典规则的棋盘，其他配置返回 InvalidInput 错误
+    pub fn bind(addr: impl ToSocketAddrs, config: VersusConfig) -> io::Result<Self> {
+        let board_config = config.board_config;
+        if board_config.mines_per_cell != 1 || board_config.negative_mines != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                t!("versus.classic_only"),
+            ));
+        }
+        Ok(VersusServer {
+            listener: TcpListener::bind(addr)?,
+            config,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// 等待两名玩家加入并进行一场比赛，返回获胜玩家的编号（平局为 None）
+    pub fn run(self) -> io::Result<Option<usize>> {
+        let (sender, receiver) = mpsc::channel();
+        let mut writers = Vec::with_capacity(PLAYER_COUNT);
+        for player in 0..PLAYER_COUNT {
+            let (stream, _) = self.listener.accept()?;
+            let mut writer = stream.try_clone()?;
+            send(
+                &mut writer,
+                &ServerMessage::Welcome {
+                    player,
+                    size: self.config.board_config.board_size,
+                    mines: self.config.board_config.mine_count,
+                    lives: self.config.lives,
+                },
+            )?;
+            spawn_reader(player, stream, sender.clone(), ClientMessage::parse);
+            writers.push(writer);
+        }
+        drop(sender);
+
+        let mut names: [Option<String>; PLAYER_COUNT] = Default::default();
+        while names.iter().any(Option::is_none) {
+            match receiver.recv() {
+                Ok(Incoming::Message(player, ClientMessage::Hello(name))) => {
+                    names[player] = Some(name)
+                }
+                Ok(Incoming::Message(player, _)) | Ok(Incoming::Invalid(player, _)) => {
+                    send(&mut writers[player], &error(t!("net.hello_first")))?
+                }
+                // 开赛前有人离开：另一方直接获胜
+                Ok(Incoming::Disconnected(player)) => {
+                    let winner = 1 - player;
+                    send_ignoring_errors(
+                        &mut writers[winner],
+                        &ServerMessage::Result {
+                            winner: Some(winner),
+                        },
+                    );
+                    return Ok(Some(winner));
+                }
+                Err(_) => return Ok(None),
+            }
+        }
+
+        let players: Vec<Player> = writers
+            .into_iter()
+            .zip(names)
+            .map(|(writer, name)| Player {
+                name: name.unwrap_or_default(),
+                board: Board::with_seed(self.config.board_config, self.config.seed),
+                lives: Lives::new(self.config.lives),
+                finish: Finish::Playing,
+                writer,
+            })
+            .collect();
+
+        Match {
+            players,
+            clock: SystemClock::new(),
+            receiver,
+        }
+        .play()
+    }
+}
+
+struct Match {
+    players: Vec<Player>,
+    clock: SystemClock,
+    receiver: Receiver<Incoming<ClientMessage>>,
+}
+
+impl Match {
+    fn play(&mut self) -> io::Result<Option<usize>> {
+        // 双方从同一个位置开局，种子相同的棋盘因此布局相同
+        let size = self.players[0].board.get_board_config().board_size;
+        let start = Position {
+            row: size.height / 2,
+            col: size.width / 2,
+        };
+        for player in 0..PLAYER_COUNT {
+            let _ = self.players[player].board.left_click(start);
+            let opponent = self.players[1 - player].name.clone();
+            self.send(player, &ServerMessage::Start { opponent });
+            self.send_view(player);
+        }
+        for player in 0..PLAYER_COUNT {
+            self.update_finish(player);
+            self.broadcast_progress(player);
+        }
+
+        loop {
+            if let Some(result) = self.result() {
+                self.broadcast(&ServerMessage::Result { winner: result });
+                return Ok(result);
+            }
+
+            let Ok(incoming) = self.receiver.recv() else {
+                return Ok(None);
+            };
+            match incoming {
+                Incoming::Message(player, message) => self.handle(player, message),
+                Incoming::Invalid(player, line) => {
+                    self.send(player, &error(&t!("net.bad_client_message", line)))
+                }
+                Incoming::Disconnected(player) => self.players[player].finish = Finish::Forfeit,
+            }
+        }
+    }
+
+    fn handle(&mut self, player: usize, message: ClientMessage) {
+        if self.players[player].finish != Finish::Playing {
+            self.send(player, &error(t!("versus.finished_wait")));
+            return;
+        }
+
+        let size = self.players[player].board.get_board_config().board_size;
+        let in_bounds = |pos: Position| pos.row < size.height && pos.col < size.width;
+        match message {
+            ClientMessage::Click(pos) if in_bounds(pos) => {
+                let state = &mut self.players[player];
+                match state.board.left_click(pos) {
+                    ClickResult::Invalid => {
+                        self.send(player, &error(t!("click.invalid")));
+                        return;
+                    }
+                    ClickResult::GameOver => {
+                        // 踩雷扣一条生命并自动标记，生命耗尽即出局
+                        state.board.flag_triggered_mine(pos);
+                        if !state.lives.lose_one() {
+                            state.finish = Finish::Out;
+                        }
+                    }
+                    ClickResult::Continue | ClickResult::Victory => {}
+                }
+            }
+            ClientMessage::Flag(pos) if in_bounds(pos) => {
+                if matches!(
+                    self.players[player].board.right_click(pos),
+                    ClickResult::Invalid
+                ) {
+                    self.send(player, &error(t!("flag.invalid")));
+                    return;
+                }
+            }
+            ClientMessage::Click(_) | ClientMessage::Flag(_) => {
+                self.send(player, &error(t!("net.out_of_range")));
+                return;
+            }
+            ClientMessage::Hello(_) => {
+                self.send(player, &error(t!("versus.already_started")));
+                return;
+            }
+            ClientMessage::Quit => {
+                self.players[player].finish = Finish::Forfeit;
+                return;
+            }
+        }
+
+        self.send_view(player);
+        self.update_finish(player);
+        self.broadcast_progress(player);
+    }
+
+    fn update_finish(&mut self, player: usize) {
+        let elapsed = self.clock.elapsed();
+        let state = &mut self.players[player];
+        if state.finish == Finish::Playing && state.board.check_victory() {
+            state.finish = Finish::Cleared(elapsed);
+        }
+    }
+
+    // 先清空棋盘者获胜；有人弃权时对手获胜；双方都出局时比较完成度
+    fn result(&self) -> Option<Option<usize>> {
+        if let Some(player) =
+            (0..PLAYER_COUNT).find(|&player| self.players[player].finish == Finish::Forfeit)
+        {
+            return Some(Some(1 - player));
+        }
+
+        let cleared = (0..PLAYER_COUNT)
+            .filter_map(|player| match self.players[player].finish {
+                Finish::Cleared(time) => Some((time, player)),
+                _ => None,
+            })
+            .min();
+        if let Some((_, player)) = cleared {
+            return Some(Some(player));
+        }
+
+        if self.players.iter().all(|state| state.finish == Finish::Out) {
+            let [first, second] = [0, 1].map(|player| self.percent(player));
+            return Some(match first.cmp(&second) {
+                std::cmp::Ordering::Greater => Some(0),
+                std::cmp::Ordering::Less => Some(1),
+                std::cmp::Ordering::Equal => None,
+            });
+        }
+        None
+    }
+
+    fn percent(&self, player: usize) -> u8 {
+        let board = &self.players[player].board;
+        let config = board.get_board_config();
+        let safe_cells = config.board_size.cell_count() - config.mine_count;
+        if safe_cells == 0 {
+            return 100;
+        }
+        (board.get_revealed_count() * 100 / safe_cells) as u8
+    }
+
+    fn send_view(&mut self, player: usize) {
+        let view = encode_view(&self.players[player].board);
+        self.send(player, &ServerMessage::View(view));
+    }
+
+    fn broadcast_progress(&mut self, player: usize) {
+        let state = &self.players[player];
+        let message = ServerMessage::Progress {
+            player,
+            percent: self.percent(player),
+            lives: state.lives.remaining(),
+            finish: match state.finish {
+                Finish::Cleared(time) => Some(time),
+                _ => None,
+            },
+        };
+        self.broadcast(&message);
+    }
+
+    fn broadcast(&mut self, message: &ServerMessage) {
+        for player in 0..PLAYER_COUNT {
+            self.send(player, message);
+        }
+    }
+
+    // 写入失败说明对方已断开，读线程会报告断开，这里忽略错误
+    fn send(&mut self, player: usize, message: &ServerMessage) {
+        send_ignoring_errors(&mut self.players[player].writer, message);
+    }
+}
+
+fn error(message: &str) -> ServerMessage {
+    ServerMessage::Error(message.to_string())
+}
+
+fn send(writer: &mut TcpStream, message: &ServerMessage) -> io::Result<()> {
+    writeln!(writer, "{}", message.to_line())
+}
+
+fn send_ignoring_errors(writer: &mut TcpStream, message: &ServerMessage) {
+    let _ = send(writer, message);
+}
+
+// 逐行读取客户端消息并转发给主循环；一行超过 MAX_LINE_BYTES 时视为无效消息并断开连接
+pub(crate) fn spawn_reader<M: Send + 'static>(
+    player: usize,
+    stream: TcpStream,
+    sender: Sender<Incoming<M>>,
+    parse: fn(&str) -> Option<M>,
+) {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(stream);
+        loop {
+            let mut line = String::new();
+            let limit = MAX_LINE_BYTES as u64;
+            match (&mut reader).take(limit).read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(read) if read == MAX_LINE_BYTES && !line.ends_with('\n') => {
+                    let _ = sender.send(Incoming::Invalid(player, line));
+                    let _ = reader.get_ref().shutdown(Shutdown::Both);
+                    break;
+                }
+                Ok(_) => {}
+            }
+            let line = line.trim_end_matches(['\r', '\n']).to_string();
+            let incoming = match parse(&line) {
+                Some(message) => Incoming::Message(player, message),
+                None => Incoming::Invalid(player, line),
+            };
+            if sender.send(incoming).is_err() {
+                return;
+            }
+        }
+        let _ = sender.send(Incoming::Disconnected(player));
+    });
+}
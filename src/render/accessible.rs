@@ -0,0 +1,166 @@
+//! 供读屏软件使用的线性文本输出
+//!
+//! 不画网格，也不含颜色代码和框线字符：棋盘的每一行写成一句话，内容相同的连续格子合并为一段。
+//! 另外提供操作后的变化播报、按行、列和相邻格子朗读以及局面统计，供游戏的无障碍模式使用。
+
+use super::{BoardView, Renderer};
+use crate::core::{Board, Cell, CellContent, CellState, ClickDetails, Position};
+use crate::t;
+use std::io::{self, Write};
+
+/// 每行一句话的线性输出，没有图例
+pub struct AccessibleRenderer;
+
+impl Renderer for AccessibleRenderer {
+    fn render(&self, view: &BoardView, out: &mut dyn Write) -> io::Result<()> {
+        for row in view.rows() {
+            writeln!(out, "{}", describe_row(view, row))?;
+        }
+        Ok(())
+    }
+}
+
+/// 格子的坐标，例如“第 3 行第 5 列”
+pub fn describe_position(pos: Position) -> String {
+    t!("a11y.position", pos.row, pos.col)
+}
+
+/// 格子的可见内容，例如“未翻开”“旗子”“空白”“2”
+pub fn describe_cell(cell: &Cell) -> String {
+    match cell.state() {
+        CellState::Hidden => t!("a11y.hidden").to_string(),
+        CellState::Flagged => describe_flags(cell.flag_count()),
+        CellState::Revealed => describe_content(cell.content()),
+    }
+}
+
+fn describe_flags(count: i8) -> String {
+    match count {
+        0 => t!("a11y.hidden").to_string(),
+        1 => t!("a11y.flag").to_string(),
+        count => t!("a11y.flags", count),
+    }
+}
+
+fn describe_content(content: CellContent) -> String {
+    match content {
+        CellContent::Mine(1) => t!("a11y.mine").to_string(),
+        CellContent::Mine(count) => t!("a11y.mines", count),
+        CellContent::Number(0) => t!("a11y.blank").to_string(),
+        CellContent::Number(n) => n.to_string(),
+    }
+}
+
+// “第 3 行第 5 列是 2”
+fn statement(pos: Position, description: String) -> String {
+    t!("a11y.cell", describe_position(pos), description)
+}
+
+// 把一行或一列的描述合并为连续相同的段，first 是第一个描述的坐标，
+// single 和 range 是单个格子和一段格子的坐标模板
+fn runs(
+    first: usize,
+    descriptions: Vec<String>,
+    single: &'static str,
+    range: &'static str,
+) -> String {
+    let mut items = Vec::new();
+    let mut start = 0;
+    for end in 1..=descriptions.len() {
+        if end < descriptions.len() && descriptions[end] == descriptions[start] {
+            continue;
+        }
+        let label = if end - start == 1 {
+            t!(single, first + start)
+        } else {
+            t!(range, first + start, first + end - 1)
+        };
+        items.push(t!("a11y.item", label, descriptions[start]));
+        start = end;
+    }
+    items.join(t!("a11y.separator"))
+}
+
+/// 朗读一行中要输出的列，例如“第 2 行：第 0 至 3 列 空白；第 4 列 1；第 5 至 8 列 未翻开”
+pub fn describe_row(view: &BoardView, row: usize) -> String {
+    let descriptions = view
+        .cols()
+        .map(|col| describe_cell(&view.cell(Position { row, col })))
+        .collect();
+    t!(
+        "a11y.row_line",
+        row,
+        runs(
+            view.cols().start,
+            descriptions,
+            "a11y.column",
+            "a11y.columns"
+        )
+    )
+}
+
+/// 朗读一列中要输出的行
+pub fn describe_column(view: &BoardView, col: usize) -> String {
+    let descriptions = view
+        .rows()
+        .map(|row| describe_cell(&view.cell(Position { row, col })))
+        .collect();
+    t!(
+        "a11y.column_line",
+        col,
+        runs(view.rows().start, descriptions, "a11y.row", "a11y.rows")
+    )
+}
+
+/// 朗读一个格子和它按相邻规则的每个相邻格子
+pub fn describe_neighbourhood(board: &Board, pos: Position) -> String {
+    let neighbours: Vec<String> = board
+        .neighbours(pos)
+        .map(|neighbour| {
+            t!(
+                "a11y.item",
+                describe_position(neighbour),
+                describe_cell(&board.cell_at(neighbour))
+            )
+        })
+        .collect();
+    t!(
+        "a11y.neighbourhood",
+        statement(pos, describe_cell(&board.cell_at(pos))),
+        neighbours.join(t!("a11y.separator"))
+    )
+}
+
+/// 一次操作后的变化，例如“翻开了 14 个格子，第 3 行第 5 列是 2”；没有变化时返回 None
+pub fn announce(details: &ClickDetails) -> Option<String> {
+    let mut sentences = Vec::new();
+    if let Some(&(pos, content)) = details.revealed.first() {
+        let clicked = statement(pos, describe_content(content));
+        sentences.push(match details.revealed.len() {
+            1 => clicked,
+            count => t!("a11y.revealed", count, clicked),
+        });
+    }
+    for change in &details.flags {
+        sentences.push(if change.to == 0 {
+            t!("a11y.unflagged", describe_position(change.pos))
+        } else {
+            statement(change.pos, describe_flags(change.to))
+        });
+    }
+    if sentences.is_empty() {
+        None
+    } else {
+        Some(sentences.join(t!("a11y.separator")))
+    }
+}
+
+/// 局面统计：剩余地雷、未翻开的格子和边界（与已翻开格子相邻的未翻开格子）的大小
+pub fn summary(board: &Board) -> String {
+    t!(
+        "a11y.summary",
+        board.remaining_mines(),
+        board.hidden_cells().count(),
+        board.frontier_cells().count()
+    )
+}
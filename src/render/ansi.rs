@@ -0,0 +1,204 @@
+//! 带颜色的终端输出
+
+use super::theme::{Color, Theme};
+use super::{
+    BoardView, Labels, Renderer, cell_badge, cell_sign, number_char, write_hex, write_square,
+};
+use crate::core::{BoardShape, Cell, CellContent, CellState, Position};
+use crate::t;
+use std::io::{self, Write};
+
+// ANSI 颜色常量
+pub(crate) const RESET: &str = "\x1b[0m";
+pub(crate) const DIM: &str = "\x1b[90m"; // 暗色（列/行号、隐藏）
+const RED: &str = "\x1b[31m"; // 红色（地雷、旗子）
+const BLUE: &str = "\x1b[34m"; // 1
+const GREEN: &str = "\x1b[32m"; // 2
+const YELLOW: &str = "\x1b[33m"; // 5（传统中为棕色，使用黄代替）
+const MAGENTA: &str = "\x1b[35m"; // 4
+const CYAN: &str = "\x1b[36m"; // 6
+const WHITE: &str = "\x1b[37m"; // 7
+const BRIGHT_BLACK: &str = "\x1b[90m"; // 8（浅灰）
+const NEGATIVE: &str = "\x1b[7m"; // 负数（反色）
+
+fn color_for_number(n: i16) -> &'static str {
+    match n {
+        1 => BLUE,
+        2 => GREEN,
+        3 => RED,
+        4 => MAGENTA,
+        5 => YELLOW,
+        6 => CYAN,
+        7 => WHITE,
+        8 => BRIGHT_BLACK,
+        _ => RESET,
+    }
+}
+
+// 单个格子的图标和颜色
+pub(crate) fn cell_glyph(cell: &Cell) -> (char, &'static str) {
+    match cell.state() {
+        // 隐藏：白框图标（暗色）
+        CellState::Hidden => ('□', DIM),
+        // 旗子：红色小旗，负雷旗为蓝色
+        CellState::Flagged if cell.flag_count() < 0 => ('⚑', BLUE),
+        CellState::Flagged => ('⚑', RED),
+        // 已翻开：数字按经典扫雷配色；0 显示为空格；地雷为红色图标，负雷为蓝色；
+        // 负数以反色显示，负号由 cell_sign 给出
+        CellState::Revealed => match cell.content() {
+            CellContent::Mine(count) if count < 0 => ('✹', BLUE),
+            CellContent::Mine(_) => ('✹', RED),
+            CellContent::Number(0) => (' ', RESET),
+            CellContent::Number(n) => (
+                number_char(n),
+                if n < 0 { NEGATIVE } else { color_for_number(n) },
+            ),
+        },
+    }
+}
+
+// 经典规则的图例，供不经过 Renderer 的棋盘使用
+pub(crate) fn print_legend() {
+    println!("{}", AnsiRenderer::default().basic_legend());
+}
+
+/// 带颜色的终端输出，配色、颜色深度和图标由主题决定
+#[derive(Default)]
+pub struct AnsiRenderer {
+    theme: Theme,
+}
+
+impl AnsiRenderer {
+    pub fn new(theme: Theme) -> Self {
+        AnsiRenderer { theme }
+    }
+
+    pub fn theme(&self) -> Theme {
+        self.theme
+    }
+
+    fn code(&self, color: Color) -> String {
+        self.theme.code(color)
+    }
+
+    // 图标和颜色代码；wide 表示图标占两列
+    fn glyph(&self, cell: &Cell) -> (String, String, bool) {
+        let colors = self.theme.palette.colors();
+        let glyphs = self.theme.glyphs.glyphs();
+        let icon = |glyph: &str, color: Color| (glyph.to_string(), self.code(color), glyphs.wide);
+        match cell.state() {
+            CellState::Hidden => icon(glyphs.hidden, colors.hidden),
+            CellState::Flagged if cell.flag_count() < 0 => icon(glyphs.flag, colors.anti),
+            CellState::Flagged => icon(glyphs.flag, colors.flag),
+            // 已翻开：0 显示为空格；负数以反色显示，负号由 cell_sign 给出
+            CellState::Revealed => match cell.content() {
+                CellContent::Mine(count) if count < 0 => icon(glyphs.mine, colors.anti),
+                CellContent::Mine(_) => icon(glyphs.mine, colors.mine),
+                CellContent::Number(0) => (" ".to_string(), RESET.to_string(), false),
+                CellContent::Number(n) => {
+                    let color = if n < 0 {
+                        colors.negative
+                    } else {
+                        colors.number(n)
+                    };
+                    (number_char(n).to_string(), self.code(color), false)
+                }
+            },
+        }
+    }
+
+    // 固定宽度的格子，颜色包裹不影响对齐；符号和角标占用图标两侧的空位，
+    // 两列宽的图标只保留角标或符号中的一个
+    fn cell(&self, view: &BoardView, pos: Position) -> String {
+        let cell = view.cell(pos);
+        let (glyph, color, wide) = self.glyph(&cell);
+        let color = view.color(pos).map_or(color, str::to_string);
+        let (sign, badge) = (cell_sign(&cell), cell_badge(&cell));
+        match (wide, badge) {
+            (false, _) => format!("{}{}{}{}{}", sign, color, glyph, badge, RESET),
+            (true, ' ') => format!("{}{}{}{}", sign, color, glyph, RESET),
+            (true, _) => format!("{}{}{}{}", color, glyph, RESET, badge),
+        }
+    }
+
+    fn labels(&self) -> Labels {
+        Labels {
+            start: self.code(self.theme.palette.colors().label),
+            end: RESET.to_string(),
+        }
+    }
+
+    fn basic_legend(&self) -> String {
+        let colors = self.theme.palette.colors();
+        let glyphs = self.theme.glyphs.glyphs();
+        t!(
+            "legend.basic",
+            self.code(colors.label),
+            RESET,
+            self.code(colors.hidden),
+            glyphs.hidden,
+            RESET,
+            self.code(colors.flag),
+            glyphs.flag,
+            RESET,
+            self.code(colors.mine),
+            glyphs.mine,
+            RESET
+        )
+    }
+}
+
+impl Renderer for AnsiRenderer {
+    fn render(&self, view: &BoardView, out: &mut dyn Write) -> io::Result<()> {
+        let labels = self.labels();
+        match view.shape {
+            BoardShape::Square => write_square(view, out, &labels, |pos| self.cell(view, pos)),
+            BoardShape::Hex => write_hex(view, out, &labels, |pos| self.cell(view, pos)),
+        }
+    }
+
+    fn legend(&self, view: &BoardView, out: &mut dyn Write) -> io::Result<()> {
+        let colors = self.theme.palette.colors();
+        let glyphs = self.theme.glyphs.glyphs();
+        let label = self.code(colors.label);
+        writeln!(out, "{}", self.basic_legend())?;
+        if view.shape == BoardShape::Hex {
+            writeln!(out, "{}", t!("legend.hex", label, RESET))?;
+        }
+        if view.negative_mines > 0 {
+            let anti = self.code(colors.anti);
+            writeln!(
+                out,
+                "{}",
+                t!(
+                    "legend.negative",
+                    label,
+                    RESET,
+                    anti,
+                    glyphs.mine,
+                    RESET,
+                    anti,
+                    glyphs.flag,
+                    RESET,
+                    self.code(colors.negative),
+                    RESET
+                )
+            )?;
+        }
+        if view.mines_per_cell > 1 {
+            writeln!(
+                out,
+                "{}",
+                t!(
+                    "legend.multi",
+                    label,
+                    RESET,
+                    view.mines_per_cell,
+                    glyphs.flag,
+                    glyphs.mine
+                )
+            )?;
+        }
+        Ok(())
+    }
+}
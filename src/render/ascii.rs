@@ -0,0 +1,61 @@
+//! 不含颜色代码的纯 ASCII 输出
+//!
+//! 布局与 ANSI 输出相同：隐藏 `#`、旗子 `F`、地雷 `*`，数字和两侧的符号、角标不变。
+
+use super::{
+    BoardView, Labels, Renderer, cell_badge, cell_sign, number_char, write_hex, write_square,
+};
+use crate::core::{BoardShape, Cell, CellContent, CellState, Position};
+use crate::t;
+use std::io::{self, Write};
+
+fn ascii_glyph(cell: &Cell) -> char {
+    match cell.state() {
+        CellState::Hidden => '#',
+        CellState::Flagged => 'F',
+        CellState::Revealed => match cell.content() {
+            CellContent::Mine(_) => '*',
+            CellContent::Number(0) => ' ',
+            CellContent::Number(n) => number_char(n),
+        },
+    }
+}
+
+/// 不含颜色代码的纯 ASCII 输出
+pub struct AsciiRenderer;
+
+impl AsciiRenderer {
+    fn cell(view: &BoardView, pos: Position) -> String {
+        let cell = view.cell(pos);
+        [cell_sign(&cell), ascii_glyph(&cell), cell_badge(&cell)]
+            .iter()
+            .collect()
+    }
+}
+
+impl Renderer for AsciiRenderer {
+    fn render(&self, view: &BoardView, out: &mut dyn Write) -> io::Result<()> {
+        let labels = Labels {
+            start: String::new(),
+            end: String::new(),
+        };
+        match view.shape {
+            BoardShape::Square => write_square(view, out, &labels, |pos| Self::cell(view, pos)),
+            BoardShape::Hex => write_hex(view, out, &labels, |pos| Self::cell(view, pos)),
+        }
+    }
+
+    fn legend(&self, view: &BoardView, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "{}", t!("legend.ascii"))?;
+        if view.shape == BoardShape::Hex {
+            writeln!(out, "{}", t!("legend.hex", "", ""))?;
+        }
+        if view.negative_mines > 0 {
+            writeln!(out, "{}", t!("legend.ascii_negative"))?;
+        }
+        if view.mines_per_cell > 1 {
+            writeln!(out, "{}", t!("legend.ascii_multi", view.mines_per_cell))?;
+        }
+        Ok(())
+    }
+}
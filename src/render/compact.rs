@@ -0,0 +1,41 @@
+//! 每格一个字符的紧凑输出
+//!
+//! 没有行列号、边框和图例：隐藏 `#`、旗子 `F`、地雷 `*`、空白 `.`，数字以 0-9、a-z 表示，
+//! 负数一律显示为 `-`。六边形棋盘的奇数行前面多一个空格。
+
+use super::{BoardView, Renderer, number_char};
+use crate::core::{BoardShape, Cell, CellContent, CellState, Position};
+use std::io::{self, Write};
+
+fn compact_char(cell: &Cell) -> char {
+    match cell.state() {
+        CellState::Hidden => '#',
+        CellState::Flagged => 'F',
+        CellState::Revealed => match cell.content() {
+            CellContent::Mine(_) => '*',
+            CellContent::Number(0) => '.',
+            CellContent::Number(n) if n < 0 => '-',
+            CellContent::Number(n) => number_char(n),
+        },
+    }
+}
+
+/// 每格一个字符的紧凑输出
+pub struct CompactRenderer;
+
+impl Renderer for CompactRenderer {
+    fn render(&self, view: &BoardView, out: &mut dyn Write) -> io::Result<()> {
+        for row in view.rows() {
+            let mut line = String::with_capacity(view.cols().len() + 1);
+            if view.shape == BoardShape::Hex && row % 2 == 1 {
+                line.push(' ');
+            }
+            line.extend(
+                view.cols()
+                    .map(|col| compact_char(&view.cell(Position { row, col }))),
+            );
+            writeln!(out, "{}", line)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,371 @@
+//! 棋盘的文本输出
+//!
+//! `BoardView` 描述要画的棋盘（尺寸、形状、每格内容和可选的覆盖颜色），`Renderer`
+//! 把它写到任意 `io::Write`，因此同一块棋盘可以输出到终端、字符串或其他前端，也可以做快照测试。
+//!
+//! - ansi: 带颜色的终端输出（默认）
+//! - theme: ANSI 输出的配色、颜色深度和图标
+//! - ascii: 不含颜色代码的纯 ASCII 输出，用于 `NO_COLOR` 和非 UTF-8 终端
+//! - compact: 每格一个字符、没有行列号和边框的紧凑输出
+//! - accessible: 供读屏软件使用、每行一句话的线性文本
+//! - viewport: 只输出大棋盘的一部分的视口和整个棋盘的小地图
+
+mod accessible;
+mod ansi;
+mod ascii;
+mod compact;
+mod theme;
+mod viewport;
+
+pub use accessible::{
+    AccessibleRenderer, announce, describe_cell, describe_column, describe_neighbourhood,
+    describe_position, describe_row, summary,
+};
+pub use ansi::AnsiRenderer;
+pub use ascii::AsciiRenderer;
+pub use compact::CompactRenderer;
+pub use theme::{
+    Color, ColorDepth, Colors, GlyphSet, Glyphs, Palette, Theme, ansi256_index, set_theme, theme,
+};
+
+pub use viewport::Viewport;
+
+pub(crate) use ansi::{DIM, RESET, cell_glyph, print_legend};
+
+use crate::core::{Board, BoardShape, BoardSize, Cell, CellContent, CellState, Position};
+use std::io::{self, Write};
+use std::ops::Range;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 要输出的棋盘
+pub struct BoardView<'a> {
+    pub size: BoardSize,
+    pub shape: BoardShape,
+    /// 每格地雷上限，大于 1 时图例说明角标
+    pub mines_per_cell: u8,
+    /// 负雷数，大于 0 时图例说明负号
+    pub negative_mines: usize,
+    cell_at: Box<dyn Fn(Position) -> Cell + 'a>,
+    color_at: Option<Box<dyn Fn(Position) -> Option<&'static str> + 'a>>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+}
+
+impl<'a> BoardView<'a> {
+    /// 棋盘当前的样子，隐藏格子的内容不会被输出
+    pub fn of(board: &'a Board) -> Self {
+        let config = board.get_board_config();
+        BoardView {
+            size: config.board_size,
+            shape: config.shape,
+            mines_per_cell: config.mines_per_cell,
+            negative_mines: config.negative_mines,
+            cell_at: Box::new(|pos| board.cell_at(pos)),
+            color_at: None,
+            rows: 0..config.board_size.height,
+            cols: 0..config.board_size.width,
+        }
+    }
+
+    /// 经典规则的方格棋盘，cell_at 按位置提供格子
+    pub fn grid(size: BoardSize, cell_at: impl Fn(Position) -> Cell + 'a) -> Self {
+        BoardView {
+            size,
+            shape: BoardShape::Square,
+            mines_per_cell: 1,
+            negative_mines: 0,
+            cell_at: Box::new(cell_at),
+            color_at: None,
+            rows: 0..size.height,
+            cols: 0..size.width,
+        }
+    }
+
+    /// color_at 返回 Some 时覆盖该格子的颜色（例如双人模式的玩家颜色），只对带颜色的输出有效
+    pub fn with_colors(mut self, color_at: impl Fn(Position) -> Option<&'static str> + 'a) -> Self {
+        self.color_at = Some(Box::new(color_at));
+        self
+    }
+
+    /// 只输出视口内的格子，行列号仍为棋盘上的坐标
+    pub fn with_viewport(mut self, viewport: &Viewport) -> Self {
+        self.rows = viewport.rows(self.size);
+        self.cols = viewport.cols(self.size);
+        self
+    }
+
+    /// 要输出的行，默认为整个棋盘
+    pub fn rows(&self) -> Range<usize> {
+        self.rows.clone()
+    }
+
+    /// 要输出的列，默认为整个棋盘
+    pub fn cols(&self) -> Range<usize> {
+        self.cols.clone()
+    }
+
+    /// 玩家能看到的格子：未翻开和已标记的格子不带内容，输出无法借此看到隐藏的地雷
+    pub fn cell(&self, pos: Position) -> Cell {
+        let mut cell = (self.cell_at)(pos);
+        if cell.state() != CellState::Revealed {
+            cell.set_content(CellContent::Number(0));
+        }
+        cell
+    }
+
+    pub fn color(&self, pos: Position) -> Option<&'static str> {
+        self.color_at.as_ref().and_then(|color_at| color_at(pos))
+    }
+}
+
+pub trait Renderer {
+    /// 输出棋盘本身
+    fn render(&self, view: &BoardView, out: &mut dyn Write) -> io::Result<()>;
+
+    /// 输出棋盘下方的图例，默认没有图例
+    fn legend(&self, _view: &BoardView, _out: &mut dyn Write) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderMode {
+    Ansi,
+    Ascii,
+    Compact,
+    Accessible,
+}
+
+impl RenderMode {
+    pub const ALL: [RenderMode; 4] = [
+        RenderMode::Ansi,
+        RenderMode::Ascii,
+        RenderMode::Compact,
+        RenderMode::Accessible,
+    ];
+
+    /// 命令行和设置文件中使用的名字
+    pub fn name(self) -> &'static str {
+        match self {
+            RenderMode::Ansi => "ansi",
+            RenderMode::Ascii => "ascii",
+            RenderMode::Compact => "compact",
+            RenderMode::Accessible => "accessible",
+        }
+    }
+
+    pub fn parse(text: &str) -> Option<RenderMode> {
+        let text = text.trim();
+        RenderMode::ALL
+            .into_iter()
+            .find(|mode| mode.name().eq_ignore_ascii_case(text))
+    }
+
+    /// 按终端环境选择：设置了 `NO_COLOR` 或终端不是 UTF-8 时使用纯 ASCII，否则使用 ANSI
+    pub fn detect() -> RenderMode {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty());
+        if no_color || locale.is_some_and(|locale| !is_utf8_locale(&locale)) {
+            RenderMode::Ascii
+        } else {
+            RenderMode::Ansi
+        }
+    }
+
+    pub fn renderer(self) -> Box<dyn Renderer> {
+        match self {
+            RenderMode::Ansi => Box::new(AnsiRenderer::new(theme())),
+            RenderMode::Ascii => Box::new(AsciiRenderer),
+            RenderMode::Compact => Box::new(CompactRenderer),
+            RenderMode::Accessible => Box::new(AccessibleRenderer),
+        }
+    }
+}
+
+/// 语言环境的字符集是否为 UTF-8，例如 `en_US.UTF-8`、`zh_CN.utf8`
+pub fn is_utf8_locale(locale: &str) -> bool {
+    let locale = locale.to_ascii_lowercase();
+    locale.contains("utf-8") || locale.contains("utf8")
+}
+
+// 0 表示未设置，按终端环境选择
+static MODE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_render_mode(mode: RenderMode) {
+    MODE.store(mode as u8 + 1, Ordering::Relaxed);
+}
+
+pub fn render_mode() -> RenderMode {
+    match MODE.load(Ordering::Relaxed) {
+        1 => RenderMode::Ansi,
+        2 => RenderMode::Ascii,
+        3 => RenderMode::Compact,
+        4 => RenderMode::Accessible,
+        _ => RenderMode::detect(),
+    }
+}
+
+/// 按优先级选择输出方式：命令行参数、设置文件，都没有时按终端环境选择
+pub fn select_render_mode(cli: Option<&str>, settings: Option<RenderMode>) -> RenderMode {
+    cli.and_then(RenderMode::parse)
+        .or(settings)
+        .unwrap_or_else(RenderMode::detect)
+}
+
+/// 用当前的输出方式把棋盘和图例打印到标准输出
+pub fn print(view: &BoardView) {
+    let renderer = render_mode().renderer();
+    let mut out = io::stdout().lock();
+    // 终端关闭时没有可以报告错误的地方
+    let _ = renderer
+        .render(view, &mut out)
+        .and_then(|_| renderer.legend(view, &mut out));
+}
+
+/// 同 print，但不打印图例
+pub(crate) fn print_grid(view: &BoardView) {
+    let _ = render_mode()
+        .renderer()
+        .render(view, &mut io::stdout().lock());
+}
+
+/// 渲染为字符串，便于测试和嵌入其他界面
+pub fn render_to_string(renderer: &dyn Renderer, view: &BoardView) -> String {
+    let mut out = Vec::new();
+    renderer
+        .render(view, &mut out)
+        .and_then(|_| renderer.legend(view, &mut out))
+        .expect("写入内存不会失败");
+    String::from_utf8(out).expect("渲染结果是 UTF-8")
+}
+
+// 行列号的颜色，无颜色的输出为空字符串
+struct Labels {
+    start: String,
+    end: String,
+}
+
+// 坐标的位数，至少两位以保持原有的布局
+fn label_width(count: usize) -> usize {
+    count.saturating_sub(1).to_string().len().max(2)
+}
+
+// 列号表头：两位以内写成一行，更宽的棋盘把列号按位竖排成多行，数字总是与格子中的图标对齐
+fn write_column_labels(
+    view: &BoardView,
+    out: &mut dyn Write,
+    labels: &Labels,
+    indent: usize,
+    cell_width: usize,
+) -> io::Result<()> {
+    let digits = label_width(view.size.width);
+    let padding = " ".repeat(cell_width - 2);
+    if digits == 2 {
+        write!(out, "{}", " ".repeat(indent))?;
+        for col in view.cols() {
+            write!(out, "{}{:>2}{}{}", labels.start, col, padding, labels.end)?;
+        }
+        return writeln!(out);
+    }
+    for place in (0..digits as u32).rev() {
+        write!(out, "{}", " ".repeat(indent))?;
+        for col in view.cols() {
+            let unit = 10usize.pow(place);
+            let digit = if col >= unit || place == 0 {
+                char::from_digit((col / unit % 10) as u32, 10).unwrap_or(' ')
+            } else {
+                ' '
+            };
+            write!(out, "{} {}{}{}", labels.start, digit, padding, labels.end)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+// 方格棋盘：列号表头、边框和行号，cell 返回每格三个字符宽的文本
+fn write_square(
+    view: &BoardView,
+    out: &mut dyn Write,
+    labels: &Labels,
+    cell: impl Fn(Position) -> String,
+) -> io::Result<()> {
+    let width = label_width(view.size.height);
+    write_column_labels(view, out, labels, width + 2, 3)?;
+
+    let border = format!(
+        "{}+{}+",
+        " ".repeat(width + 1),
+        "---".repeat(view.cols().len())
+    );
+    writeln!(out, "{}", border)?;
+    for row in view.rows() {
+        write!(out, "{}{:>width$}{} |", labels.start, row, labels.end)?;
+        for col in view.cols() {
+            write!(out, "{}", cell(Position { row, col }))?;
+        }
+        writeln!(out, "|")?;
+    }
+    writeln!(out, "{}", border)
+}
+
+// 六边形棋盘：每格占 4 列，奇数行右移半格（2 列），形成蜂窝状错位
+fn write_hex(
+    view: &BoardView,
+    out: &mut dyn Write,
+    labels: &Labels,
+    cell: impl Fn(Position) -> String,
+) -> io::Result<()> {
+    let width = label_width(view.size.height);
+    // 列号表头（偏移坐标的列）
+    write_column_labels(view, out, labels, width + 2, 4)?;
+
+    for row in view.rows() {
+        let indent = if row % 2 == 1 { "  " } else { "" };
+        write!(
+            out,
+            "{}{:>width$}{}  {}",
+            labels.start, row, labels.end, indent
+        )?;
+        for col in view.cols() {
+            write!(out, "{} ", cell(Position { row, col }))?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+// 图标左侧的符号位：负数、负雷和负雷旗显示负号，其他情况为空格
+fn cell_sign(cell: &Cell) -> char {
+    let negative = match cell.state() {
+        CellState::Flagged => cell.flag_count() < 0,
+        CellState::Revealed => match cell.content() {
+            CellContent::Mine(count) => count < 0,
+            CellContent::Number(n) => n < 0,
+        },
+        CellState::Hidden => false,
+    };
+    if negative { '-' } else { ' ' }
+}
+
+// 图标右侧的角标：多雷格子显示地雷数，多面旗子显示旗子数，其他情况为空格
+fn cell_badge(cell: &Cell) -> char {
+    let count = match cell.state() {
+        CellState::Flagged => cell.flag_count().unsigned_abs(),
+        CellState::Revealed => cell.mine_count().unsigned_abs(),
+        CellState::Hidden => 0,
+    };
+    if count > 1 {
+        char::from_digit(u32::from(count), 10).unwrap_or('+')
+    } else {
+        ' '
+    }
+}
+
+// 数字的字符：两位数（3D 等大邻域）以 a-z 表示 10-35，更大的数字显示为 +
+fn number_char(n: i16) -> char {
+    char::from_digit(u32::from(n.unsigned_abs()), 36).unwrap_or('+')
+}
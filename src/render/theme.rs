@@ -0,0 +1,373 @@
+//! ANSI 输出的主题：配色、颜色深度和图标
+//!
+//! 配色为每种用途（行列号、隐藏、旗子、地雷、负雷、数字 1-8、负数）给出 RGB 颜色和
+//! 16 色终端下的 SGR 参数，按颜色深度输出 16 色、256 色或真彩色的转义序列。
+
+use std::sync::RwLock;
+
+/// 一种用途的颜色；rgb 为 None 时只使用文字属性（粗体、反色等），与颜色深度无关
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Color {
+    rgb: Option<(u8, u8, u8)>,
+    basic: &'static str,
+    bold: bool,
+}
+
+const fn rgb(r: u8, g: u8, b: u8, basic: &'static str) -> Color {
+    Color {
+        rgb: Some((r, g, b)),
+        basic,
+        bold: false,
+    }
+}
+
+const fn bold(r: u8, g: u8, b: u8, basic: &'static str) -> Color {
+    Color {
+        rgb: Some((r, g, b)),
+        basic,
+        bold: true,
+    }
+}
+
+const fn attr(basic: &'static str) -> Color {
+    Color {
+        rgb: None,
+        basic,
+        bold: false,
+    }
+}
+
+impl Color {
+    /// 该颜色在指定深度下的转义序列；没有颜色也没有属性时为空字符串
+    pub fn code(self, depth: ColorDepth) -> String {
+        let weight = if self.bold { "1;" } else { "" };
+        match (self.rgb, depth) {
+            (None, _) if self.basic.is_empty() => String::new(),
+            (None, _) | (Some(_), ColorDepth::Basic) => format!("\x1b[{}m", self.basic),
+            (Some(rgb), ColorDepth::Ansi256) => {
+                format!("\x1b[{}38;5;{}m", weight, ansi256_index(rgb))
+            }
+            (Some((r, g, b)), ColorDepth::TrueColor) => {
+                format!("\x1b[{}38;2;{};{};{}m", weight, r, g, b)
+            }
+        }
+    }
+}
+
+/// RGB 颜色在 256 色表中最接近的编号：灰色使用 232-255 的灰阶，其他使用 6x6x6 色块
+pub fn ansi256_index((r, g, b): (u8, u8, u8)) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..=7 => 16,
+            249..=255 => 231,
+            v => 232 + ((v - 8) / 10).min(23),
+        };
+    }
+    let level = |c: u8| ((u16::from(c) * 5 + 127) / 255) as u8;
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+/// 每种用途的颜色
+pub struct Colors {
+    pub label: Color,
+    pub hidden: Color,
+    pub flag: Color,
+    pub mine: Color,
+    /// 负雷和负雷旗
+    pub anti: Color,
+    /// 数字 1-8，更大的数字使用 8 的颜色
+    pub numbers: [Color; 8],
+    /// 负数
+    pub negative: Color,
+}
+
+impl Colors {
+    pub fn number(&self, n: i16) -> Color {
+        match n {
+            1..=8 => self.numbers[n as usize - 1],
+            _ => self.numbers[7],
+        }
+    }
+}
+
+const CLASSIC: Colors = Colors {
+    label: rgb(128, 128, 128, "90"),
+    hidden: rgb(128, 128, 128, "90"),
+    flag: rgb(205, 49, 49, "31"),
+    mine: rgb(205, 49, 49, "31"),
+    anti: rgb(36, 114, 200, "34"),
+    numbers: [
+        rgb(64, 128, 255, "34"),
+        rgb(0, 170, 0, "32"),
+        rgb(230, 40, 40, "31"),
+        rgb(170, 60, 200, "35"),
+        rgb(200, 150, 0, "33"),
+        rgb(0, 170, 170, "36"),
+        rgb(220, 220, 220, "37"),
+        rgb(128, 128, 128, "90"),
+    ],
+    negative: attr("7"),
+};
+
+const HIGH_CONTRAST: Colors = Colors {
+    label: rgb(255, 255, 255, "97"),
+    hidden: rgb(255, 255, 255, "97"),
+    flag: bold(255, 60, 60, "1;91"),
+    mine: bold(255, 60, 60, "1;91"),
+    anti: bold(90, 170, 255, "1;94"),
+    numbers: [
+        bold(90, 170, 255, "1;94"),
+        bold(80, 255, 80, "1;92"),
+        bold(255, 80, 80, "1;91"),
+        bold(255, 100, 255, "1;95"),
+        bold(255, 255, 0, "1;93"),
+        bold(0, 255, 255, "1;96"),
+        bold(255, 255, 255, "1;97"),
+        bold(200, 200, 200, "1;37"),
+    ],
+    negative: attr("1;7"),
+};
+
+// Okabe-Ito 配色：红绿色弱（deuteranopia / protanopia）也能区分，不把红和绿作为对比
+const COLOR_BLIND: Colors = Colors {
+    label: rgb(128, 128, 128, "90"),
+    hidden: rgb(128, 128, 128, "90"),
+    flag: bold(213, 94, 0, "1;33"),
+    mine: bold(213, 94, 0, "1;33"),
+    anti: rgb(86, 180, 233, "96"),
+    numbers: [
+        rgb(0, 114, 178, "34"),
+        rgb(230, 159, 0, "33"),
+        rgb(204, 121, 167, "35"),
+        rgb(86, 180, 233, "94"),
+        rgb(240, 228, 66, "93"),
+        rgb(0, 158, 115, "36"),
+        rgb(220, 220, 220, "37"),
+        rgb(128, 128, 128, "90"),
+    ],
+    negative: attr("7"),
+};
+
+// 只使用文字属性：暗淡的行列号、粗体的旗子和地雷、反色的负数
+const MONOCHROME: Colors = Colors {
+    label: attr("2"),
+    hidden: attr("2"),
+    flag: attr("1"),
+    mine: attr("1"),
+    anti: attr("1;4"),
+    numbers: [attr(""); 8],
+    negative: attr("7"),
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Palette {
+    #[default]
+    Classic,
+    HighContrast,
+    /// 红绿色弱友好
+    ColorBlind,
+    Monochrome,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 4] = [
+        Palette::Classic,
+        Palette::HighContrast,
+        Palette::ColorBlind,
+        Palette::Monochrome,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Palette::Classic => "classic",
+            Palette::HighContrast => "high-contrast",
+            Palette::ColorBlind => "colorblind",
+            Palette::Monochrome => "monochrome",
+        }
+    }
+
+    /// 识别名字，`deuteranopia` 和 `protanopia` 都对应色弱配色
+    pub fn parse(text: &str) -> Option<Palette> {
+        match text.trim().to_ascii_lowercase().as_str() {
+            "deuteranopia" | "protanopia" => Some(Palette::ColorBlind),
+            text => Palette::ALL
+                .into_iter()
+                .find(|palette| palette.name() == text),
+        }
+    }
+
+    pub fn colors(self) -> &'static Colors {
+        match self {
+            Palette::Classic => &CLASSIC,
+            Palette::HighContrast => &HIGH_CONTRAST,
+            Palette::ColorBlind => &COLOR_BLIND,
+            Palette::Monochrome => &MONOCHROME,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// 16 色
+    #[default]
+    Basic,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorDepth {
+    pub const ALL: [ColorDepth; 3] = [
+        ColorDepth::Basic,
+        ColorDepth::Ansi256,
+        ColorDepth::TrueColor,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorDepth::Basic => "16",
+            ColorDepth::Ansi256 => "256",
+            ColorDepth::TrueColor => "truecolor",
+        }
+    }
+
+    pub fn parse(text: &str) -> Option<ColorDepth> {
+        match text.trim().to_ascii_lowercase().as_str() {
+            "24bit" => Some(ColorDepth::TrueColor),
+            text => ColorDepth::ALL
+                .into_iter()
+                .find(|depth| depth.name() == text),
+        }
+    }
+
+    /// 按 `COLORTERM`（truecolor、24bit）和 `TERM`（含 256color）判断终端支持的颜色深度
+    pub fn detect() -> ColorDepth {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        let term = std::env::var("TERM").unwrap_or_default();
+        ColorDepth::from_env(&colorterm, &term)
+    }
+
+    pub fn from_env(colorterm: &str, term: &str) -> ColorDepth {
+        let colorterm = colorterm.to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Basic
+        }
+    }
+}
+
+/// 一套图标；wide 为 true 时图标占两列
+pub struct Glyphs {
+    pub hidden: &'static str,
+    pub flag: &'static str,
+    pub mine: &'static str,
+    pub wide: bool,
+}
+
+const UNICODE_GLYPHS: Glyphs = Glyphs {
+    hidden: "□",
+    flag: "⚑",
+    mine: "✹",
+    wide: false,
+};
+
+const EMOJI_GLYPHS: Glyphs = Glyphs {
+    hidden: "🔲",
+    flag: "🚩",
+    mine: "💣",
+    wide: true,
+};
+
+const ASCII_GLYPHS: Glyphs = Glyphs {
+    hidden: "#",
+    flag: "F",
+    mine: "*",
+    wide: false,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GlyphSet {
+    #[default]
+    Unicode,
+    Emoji,
+    Ascii,
+}
+
+impl GlyphSet {
+    pub const ALL: [GlyphSet; 3] = [GlyphSet::Unicode, GlyphSet::Emoji, GlyphSet::Ascii];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GlyphSet::Unicode => "unicode",
+            GlyphSet::Emoji => "emoji",
+            GlyphSet::Ascii => "ascii",
+        }
+    }
+
+    pub fn parse(text: &str) -> Option<GlyphSet> {
+        let text = text.trim();
+        GlyphSet::ALL
+            .into_iter()
+            .find(|set| set.name().eq_ignore_ascii_case(text))
+    }
+
+    pub fn glyphs(self) -> &'static Glyphs {
+        match self {
+            GlyphSet::Unicode => &UNICODE_GLYPHS,
+            GlyphSet::Emoji => &EMOJI_GLYPHS,
+            GlyphSet::Ascii => &ASCII_GLYPHS,
+        }
+    }
+}
+
+/// 配色、颜色深度和图标的组合
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Theme {
+    pub palette: Palette,
+    pub glyphs: GlyphSet,
+    pub depth: ColorDepth,
+}
+
+impl Theme {
+    /// 经典配色和 Unicode 图标，颜色深度按终端环境判断
+    pub fn detect() -> Theme {
+        Theme {
+            depth: ColorDepth::detect(),
+            ..Theme::default()
+        }
+    }
+
+    /// 用一个名字修改主题：可以是配色、图标或颜色深度的名字；无法识别时返回 false
+    pub fn apply(&mut self, name: &str) -> bool {
+        if let Some(palette) = Palette::parse(name) {
+            self.palette = palette;
+        } else if let Some(glyphs) = GlyphSet::parse(name) {
+            self.glyphs = glyphs;
+        } else if let Some(depth) = ColorDepth::parse(name) {
+            self.depth = depth;
+        } else {
+            return false;
+        }
+        true
+    }
+
+    pub fn code(&self, color: Color) -> String {
+        color.code(self.depth)
+    }
+}
+
+// None 表示未设置，按终端环境选择
+static THEME: RwLock<Option<Theme>> = RwLock::new(None);
+
+pub fn set_theme(theme: Theme) {
+    *THEME.write().unwrap_or_else(|err| err.into_inner()) = Some(theme);
+}
+
+pub fn theme() -> Theme {
+    THEME
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .unwrap_or_else(Theme::detect)
+}
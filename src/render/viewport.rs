@@ -0,0 +1,187 @@
+//! 大棋盘的视口和小地图
+//!
+//! 视口是棋盘上的一个矩形区域，输出时只画视口内的格子，行列号仍为棋盘上的坐标。
+//! 棋盘放不进视口时，小地图把整个棋盘缩小成几行字符，并标出视口的位置。
+
+use super::BoardView;
+use crate::config::viewport::{
+    DEFAULT_HEIGHT, DEFAULT_WIDTH, MIN_SIZE, MINIMAP_HEIGHT, MINIMAP_WIDTH, RESERVED_COLUMNS,
+    RESERVED_LINES,
+};
+use crate::core::{BoardShape, BoardSize, CellContent, CellState, Position};
+use std::ops::Range;
+
+/// 棋盘上要输出的区域：左上角的坐标和宽高（格子数）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Viewport {
+    pub top: usize,
+    pub left: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Viewport {
+    /// 左上角位于棋盘原点、指定宽高的视口
+    pub fn new(width: usize, height: usize) -> Self {
+        Viewport {
+            top: 0,
+            left: 0,
+            width: width.max(MIN_SIZE),
+            height: height.max(MIN_SIZE),
+        }
+    }
+
+    /// 按 `COLUMNS` 和 `LINES` 环境变量估计终端能放下的格子数，没有时使用默认大小
+    pub fn detect(shape: BoardShape) -> Self {
+        let columns = std::env::var("COLUMNS")
+            .ok()
+            .and_then(|value| value.parse().ok());
+        let lines = std::env::var("LINES")
+            .ok()
+            .and_then(|value| value.parse().ok());
+        Viewport::for_terminal(shape, columns, lines)
+    }
+
+    /// 终端有 columns 列、lines 行时能放下的视口；方格每格占 3 列，六边形每格占 4 列
+    pub fn for_terminal(shape: BoardShape, columns: Option<usize>, lines: Option<usize>) -> Self {
+        let cell_width = match shape {
+            BoardShape::Square => 3,
+            BoardShape::Hex => 4,
+        };
+        let width = columns.map_or(DEFAULT_WIDTH, |columns| {
+            columns.saturating_sub(RESERVED_COLUMNS) / cell_width
+        });
+        let height = lines.map_or(DEFAULT_HEIGHT, |lines| lines.saturating_sub(RESERVED_LINES));
+        Viewport::new(width, height)
+    }
+
+    /// 视口是否能放下整个棋盘
+    pub fn covers(&self, size: BoardSize) -> bool {
+        self.width >= size.width && self.height >= size.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        (self.top..self.top + self.height).contains(&pos.row)
+            && (self.left..self.left + self.width).contains(&pos.col)
+    }
+
+    /// 视口内的行，不超出棋盘
+    pub fn rows(&self, size: BoardSize) -> Range<usize> {
+        let top = self.top.min(size.height);
+        top..(self.top + self.height).min(size.height)
+    }
+
+    /// 视口内的列，不超出棋盘
+    pub fn cols(&self, size: BoardSize) -> Range<usize> {
+        let left = self.left.min(size.width);
+        left..(self.left + self.width).min(size.width)
+    }
+
+    /// 把视口移回棋盘内
+    pub fn clamp(&mut self, size: BoardSize) {
+        self.top = self.top.min(size.height.saturating_sub(self.height));
+        self.left = self.left.min(size.width.saturating_sub(self.width));
+    }
+
+    /// 向下移动 rows 行、向右移动 cols 列，负数表示向上、向左；不会移出棋盘
+    pub fn pan(&mut self, rows: isize, cols: isize, size: BoardSize) {
+        self.top = self.top.saturating_add_signed(rows);
+        self.left = self.left.saturating_add_signed(cols);
+        self.clamp(size);
+    }
+
+    /// 改变视口大小，保持左上角不变
+    pub fn resize(&mut self, width: usize, height: usize, size: BoardSize) {
+        self.width = width.max(MIN_SIZE);
+        self.height = height.max(MIN_SIZE);
+        self.clamp(size);
+    }
+
+    /// 把视口的中心移动到指定格子
+    pub fn center_on(&mut self, pos: Position, size: BoardSize) {
+        self.top = pos.row.saturating_sub(self.height / 2);
+        self.left = pos.col.saturating_sub(self.width / 2);
+        self.clamp(size);
+    }
+
+    /// 格子不在视口内时把视口中心移到它上面，用于自动跟随最近的操作
+    pub fn follow(&mut self, pos: Position, size: BoardSize) {
+        if !self.contains(pos) {
+            self.center_on(pos, size);
+        }
+    }
+
+    /// 整个棋盘的小地图，每个字符代表一块格子：
+    /// `*` 有翻开的地雷、`F` 有旗子、`#` 全部未翻开、`.` 全部已翻开、`+` 部分翻开。
+    /// 与视口相交的行末尾标 `<`，最后一行用 `^` 标出与视口相交的列。
+    /// 返回小地图的各行以及每个字符代表的宽和高
+    pub fn minimap(&self, view: &BoardView) -> (Vec<String>, usize, usize) {
+        let size = view.size;
+        let block_width = size.width.div_ceil(MINIMAP_WIDTH).max(1);
+        let block_height = size.height.div_ceil(MINIMAP_HEIGHT).max(1);
+        let (rows, cols) = (self.rows(size), self.cols(size));
+        let overlaps = |block: Range<usize>, visible: &Range<usize>| {
+            block.start < visible.end && visible.start < block.end
+        };
+
+        let mut lines = Vec::new();
+        for top in (0..size.height).step_by(block_height) {
+            let block_rows = top..(top + block_height).min(size.height);
+            let mut line: String = (0..size.width)
+                .step_by(block_width)
+                .map(|left| {
+                    let block_cols = left..(left + block_width).min(size.width);
+                    minimap_char(view, block_rows.clone(), block_cols)
+                })
+                .collect();
+            if overlaps(block_rows, &rows) {
+                line.push_str(" <");
+            }
+            lines.push(line);
+        }
+        lines.push(
+            (0..size.width)
+                .step_by(block_width)
+                .map(|left| {
+                    let block_cols = left..(left + block_width).min(size.width);
+                    if overlaps(block_cols, &cols) {
+                        '^'
+                    } else {
+                        ' '
+                    }
+                })
+                .collect::<String>()
+                .trim_end()
+                .to_string(),
+        );
+        (lines, block_width, block_height)
+    }
+}
+
+// 一块格子在小地图上的字符
+fn minimap_char(view: &BoardView, rows: Range<usize>, cols: Range<usize>) -> char {
+    let (mut revealed, mut total, mut flagged) = (0, 0, false);
+    for row in rows {
+        for col in cols.clone() {
+            let cell = view.cell(Position { row, col });
+            total += 1;
+            match cell.state() {
+                CellState::Revealed if matches!(cell.content(), CellContent::Mine(_)) => {
+                    return '*';
+                }
+                CellState::Revealed => revealed += 1,
+                CellState::Flagged => flagged = true,
+                CellState::Hidden => {}
+            }
+        }
+    }
+    if flagged {
+        'F'
+    } else if revealed == 0 {
+        '#'
+    } else if revealed == total {
+        '.'
+    } else {
+        '+'
+    }
+}
@@ -0,0 +1,115 @@
+// 无障碍输出测试
+//
+// 验证线性文本中连续相同格子的合并、按行列和相邻格子朗读、操作后的播报、局面统计和边界大小，
+// 以及输出中没有颜色代码和框线字符
+//
+// 测试不切换语言，文本来自默认的简体中文消息表
+
+use crate::support::{played_board, small_config};
+use crate::config::multi_mine_board_config;
+use crate::core::{Board, Position};
+use crate::render::{
+    AccessibleRenderer, BoardView, RenderMode, announce, describe_column,
+    describe_neighbourhood, describe_row, render_to_string, summary,
+};
+
+#[test]
+fn test_rows_merge_runs() {
+    let board = played_board();
+    let view = BoardView::of(&board);
+    assert_eq!(
+        describe_row(&view, 0),
+        "第 0 行：第 0 至 1 列 空白；第 2 列 1；第 3 列 未翻开"
+    );
+    assert_eq!(describe_row(&view, 2), "第 2 行：第 0 列 旗子；第 1 至 3 列 空白");
+}
+
+#[test]
+fn test_column() {
+    let board = played_board();
+    assert_eq!(
+        describe_column(&BoardView::of(&board), 3),
+        "第 3 列：第 0 行 未翻开；第 1 行 1；第 2 行 空白"
+    );
+}
+
+#[test]
+fn test_neighbourhood() {
+    let board = played_board();
+    assert_eq!(
+        describe_neighbourhood(&board, Position { row: 0, col: 3 }),
+        "第 0 行第 3 列是未翻开，相邻格子：第 0 行第 2 列 1；第 1 行第 2 列 1；第 1 行第 3 列 1"
+    );
+}
+
+#[test]
+fn test_announce_reveal_and_flags() {
+    let mut board = Board::new(small_config(4, 3));
+    board.place_mines_for_test(&[Position { row: 0, col: 3 }]);
+
+    let details = board.left_click_detailed(Position { row: 2, col: 3 });
+    assert_eq!(
+        announce(&details).as_deref(),
+        Some("翻开了 11 个格子，第 2 行第 3 列是空白")
+    );
+
+    let single = board.left_click_detailed(Position { row: 0, col: 3 });
+    assert_eq!(announce(&single).as_deref(), Some("第 0 行第 3 列是地雷"));
+
+    let mut board = Board::new(small_config(4, 3));
+    board.place_mines_for_test(&[Position { row: 0, col: 3 }]);
+    let flag = board.right_click_detailed(Position { row: 0, col: 3 });
+    assert_eq!(announce(&flag).as_deref(), Some("第 0 行第 3 列是旗子"));
+    let unflag = board.right_click_detailed(Position { row: 0, col: 3 });
+    assert_eq!(announce(&unflag).as_deref(), Some("第 0 行第 3 列取消了旗子"));
+}
+
+#[test]
+fn test_announce_nothing_changed() {
+    let mut board = played_board();
+    let details = board.left_click_detailed(Position { row: 1, col: 1 });
+    assert_eq!(announce(&details), None);
+}
+
+#[test]
+fn test_multi_flags() {
+    let mut board = Board::new(multi_mine_board_config());
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 0 }]);
+    board.right_click(Position { row: 0, col: 0 });
+    let details = board.right_click_detailed(Position { row: 0, col: 0 });
+    assert_eq!(announce(&details).as_deref(), Some("第 0 行第 0 列是2面旗子"));
+}
+
+#[test]
+fn test_summary_and_frontier() {
+    let board = played_board();
+    assert_eq!(board.frontier_cells().collect::<Vec<_>>(), [Position {
+        row: 0,
+        col: 3
+    }]);
+    assert_eq!(summary(&board), "剩余地雷 0，未翻开 1 格，边界 1 格");
+
+    let fresh = Board::new(small_config(4, 3));
+    assert_eq!(fresh.frontier_cells().count(), 0);
+    assert_eq!(summary(&fresh), "剩余地雷 10，未翻开 12 格，边界 0 格");
+}
+
+#[test]
+fn test_renderer_is_linear_text() {
+    let board = played_board();
+    let output = render_to_string(&AccessibleRenderer, &BoardView::of(&board));
+    assert_eq!(output.lines().count(), 3);
+    assert!(output.starts_with("第 0 行："));
+    for forbidden in ['\x1b', '|', '+', '□', '⚑', '✹'] {
+        assert!(!output.contains(forbidden), "包含 {:?}: {}", forbidden, output);
+    }
+}
+
+#[test]
+fn test_render_mode_accessible() {
+    assert_eq!(
+        RenderMode::parse("Accessible"),
+        Some(RenderMode::Accessible)
+    );
+    assert_eq!(RenderMode::Accessible.name(), "accessible");
+}
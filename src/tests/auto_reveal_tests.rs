@@ -90,7 +90,7 @@ fn find_safe_hidden_cell(board: &Board) -> Option<Position> {
         for col in 0..config.board_size.width {
             let pos = Position { row, col };
             if matches!(board.get_cell_state(pos), CellState::Hidden)
-                && !matches!(board.get_cell_content(pos), CellContent::Mine)
+                && !matches!(board.spoiler().content(pos), CellContent::Mine(_))
             {
                 return Some(pos);
             }
@@ -105,7 +105,7 @@ fn find_empty_safe_cell(board: &Board) -> Option<Position> {
         for col in 0..config.board_size.width {
             let pos = Position { row, col };
             if matches!(board.get_cell_state(pos), CellState::Hidden)
-                && matches!(board.get_cell_content(pos), CellContent::Number(0))
+                && matches!(board.spoiler().content(pos), CellContent::Number(0))
             {
                 return Some(pos);
             }
@@ -121,7 +121,7 @@ fn verify_no_mines_revealed(board: &Board) {
             let pos = Position { row, col };
             if matches!(board.get_cell_state(pos), CellState::Revealed) {
                 assert!(
-                    !matches!(board.get_cell_content(pos), CellContent::Mine),
+                    !matches!(board.spoiler().content(pos), CellContent::Mine(_)),
                     "地雷不应该被意外揭开 ({}, {})",
                     row,
                     col
@@ -137,7 +137,7 @@ fn verify_revealed_cells_are_valid(board: &Board) {
         for col in 0..config.board_size.width {
             let pos = Position { row, col };
             if matches!(board.get_cell_state(pos), CellState::Revealed) {
-                let content = board.get_cell_content(pos);
+                let content = board.spoiler().content(pos);
                 assert!(
                     matches!(content, CellContent::Number(_)),
                     "被揭开的格子应该是数字格子，位置({}, {})，内容: {:?}",
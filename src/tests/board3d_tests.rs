@@ -0,0 +1,145 @@
+// 三维棋盘测试
+//
+// 验证 26 邻域、跨层数字计算、首次点击安全和跨层自动展开
+
+use crate::config::{cube, cube_board_config};
+use crate::core::board3d::get_adjacent_positions_3d;
+use crate::core::{
+    Board3D, BoardConfig3D, BoardSize3D, CellContent, CellState, ClickResult, Position3D,
+};
+
+fn small_config() -> BoardConfig3D {
+    BoardConfig3D {
+        board_size: BoardSize3D {
+            width: 3,
+            height: 3,
+            depth: 3,
+        },
+        mine_count: 1,
+    }
+}
+
+#[test]
+fn test_adjacent_positions_3d_counts() {
+    let size = small_config().board_size;
+
+    // 中心格子有 26 个邻居
+    let center = Position3D {
+        layer: 1,
+        row: 1,
+        col: 1,
+    };
+    assert_eq!(get_adjacent_positions_3d(center, size, true).len(), 26);
+    assert_eq!(get_adjacent_positions_3d(center, size, false).len(), 27);
+
+    // 角落只有 7 个邻居
+    let corner = Position3D {
+        layer: 0,
+        row: 0,
+        col: 0,
+    };
+    assert_eq!(get_adjacent_positions_3d(corner, size, true).len(), 7);
+}
+
+#[test]
+fn test_numbers_count_mines_across_layers() {
+    let mut board = Board3D::new(small_config());
+    board.place_mines_for_test(&[Position3D {
+        layer: 0,
+        row: 0,
+        col: 0,
+    }]);
+
+    // 下一层的斜对角也算邻居
+    assert!(matches!(
+        board.spoiler().content(Position3D {
+            layer: 1,
+            row: 1,
+            col: 1
+        }),
+        CellContent::Number(1)
+    ));
+    // 隔了一层就不再相邻
+    assert!(matches!(
+        board.spoiler().content(Position3D {
+            layer: 2,
+            row: 0,
+            col: 0
+        }),
+        CellContent::Number(0)
+    ));
+}
+
+#[test]
+fn test_first_click_is_safe_and_places_mines() {
+    let mut board = Board3D::new(cube_board_config());
+    let first = Position3D {
+        layer: 2,
+        row: 2,
+        col: 2,
+    };
+
+    let result = board.left_click(first);
+    assert!(!matches!(result, ClickResult::GameOver | ClickResult::Invalid));
+    assert!(board.are_mines_placed());
+    assert_eq!(board.get_cell_state(first), CellState::Revealed);
+
+    // 地雷数量正确，且首次点击的 3x3x3 区域内没有地雷
+    let mut mines = 0;
+    for layer in 0..cube::DEPTH {
+        for row in 0..cube::HEIGHT {
+            for col in 0..cube::WIDTH {
+                let pos = Position3D { layer, row, col };
+                if matches!(board.spoiler().content(pos), CellContent::Mine(_)) {
+                    mines += 1;
+                }
+            }
+        }
+    }
+    assert_eq!(mines, cube::MINES);
+    for adj in get_adjacent_positions_3d(first, cube_board_config().board_size, false) {
+        assert!(!matches!(board.spoiler().content(adj), CellContent::Mine(_)));
+    }
+}
+
+#[test]
+fn test_flood_fill_crosses_layers_to_victory() {
+    let mut board = Board3D::new(small_config());
+    board.place_mines_for_test(&[Position3D {
+        layer: 0,
+        row: 0,
+        col: 0,
+    }]);
+
+    let result = board.left_click(Position3D {
+        layer: 2,
+        row: 2,
+        col: 2,
+    });
+    assert!(matches!(result, ClickResult::Victory));
+    assert_eq!(board.get_revealed_count(), 26);
+}
+
+#[test]
+fn test_flag_and_invalid_positions_3d() {
+    let mut board = Board3D::new(small_config());
+    let pos = Position3D {
+        layer: 1,
+        row: 0,
+        col: 2,
+    };
+
+    assert!(matches!(board.right_click(pos), ClickResult::Continue));
+    assert_eq!(board.get_cell_state(pos), CellState::Flagged);
+    assert!(matches!(board.left_click(pos), ClickResult::Invalid));
+    assert!(matches!(board.right_click(pos), ClickResult::Continue));
+    assert_eq!(board.get_cell_state(pos), CellState::Hidden);
+
+    let outside = Position3D {
+        layer: 3,
+        row: 0,
+        col: 0,
+    };
+    assert!(matches!(board.left_click(outside), ClickResult::Invalid));
+    assert!(matches!(board.right_click(outside), ClickResult::Invalid));
+}
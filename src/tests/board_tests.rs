@@ -125,6 +125,48 @@ fn test_board_size_calculations() {
     assert_eq!(size.cell_count(), 256); // 16 * 16
 }
 
+#[test]
+fn test_chord_reveals_unflagged_neighbours() {
+    let config = difficulty_to_board_config(Difficulty::Easy);
+    let mut board = Board::new(config);
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 2 }]);
+
+    // (1,1) 周围有两颗雷；只插了一面旗时不能双击
+    assert!(matches!(
+        board.left_click(Position { row: 1, col: 1 }),
+        ClickResult::Continue
+    ));
+    board.right_click(Position { row: 0, col: 0 });
+    assert!(matches!(
+        board.chord(Position { row: 1, col: 1 }),
+        ClickResult::Invalid
+    ));
+
+    board.right_click(Position { row: 0, col: 2 });
+    assert!(!matches!(
+        board.chord(Position { row: 1, col: 1 }),
+        ClickResult::Invalid
+    ));
+    assert_eq!(
+        board.get_cell_state(Position { row: 0, col: 1 }),
+        CellState::Revealed
+    );
+    assert_eq!(
+        board.get_cell_state(Position { row: 0, col: 0 }),
+        CellState::Flagged
+    );
+
+    // 旗子插错时双击会翻开地雷
+    let mut board = Board::new(config);
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    board.left_click(Position { row: 1, col: 1 });
+    board.right_click(Position { row: 0, col: 1 });
+    assert!(matches!(
+        board.chord(Position { row: 1, col: 1 }),
+        ClickResult::GameOver
+    ));
+}
+
 // 辅助函数
 fn verify_initial_board_state(board: &Board) {
     let size = &board.get_board_config().board_size;
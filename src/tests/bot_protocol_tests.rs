@@ -0,0 +1,181 @@
+// 机器协议测试
+//
+// 验证 JSON 的解析和输出，以及 JSON-lines 会话的命令、变化列表、状态和错误代码
+
+use crate::bot::json::MAX_DEPTH;
+use crate::bot::{BotSession, Json};
+
+fn send(session: &mut BotSession, line: &str) -> Json {
+    // 响应总是合法的单行 JSON
+    let response = session.handle_line(line).to_string();
+    assert!(!response.contains('\n'));
+    Json::parse(&response).unwrap()
+}
+
+fn text<'a>(response: &'a Json, key: &str) -> &'a str {
+    response.get(key).and_then(Json::as_str).unwrap()
+}
+
+fn number(response: &Json, key: &str) -> u64 {
+    response.get(key).and_then(Json::as_u64).unwrap()
+}
+
+fn board_rows(response: &Json) -> Vec<String> {
+    response
+        .get("board")
+        .and_then(Json::as_array)
+        .unwrap()
+        .iter()
+        .map(|row| row.as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_json_round_trip() {
+    let text = r#"{"name":"扫雷 \"bot\"\n","list":[1,-2.5,true,null,{}],"empty":[]}"#;
+    let value = Json::parse(text).unwrap();
+    assert_eq!(value.get("name").and_then(Json::as_str), Some("扫雷 \"bot\"\n"));
+    assert_eq!(value.to_string(), text);
+
+    let spaced = Json::parse(" { \"a\" : [ 1 , 2 ] , \"b\" : \"\\u0041\" } ").unwrap();
+    assert_eq!(spaced.to_string(), r#"{"a":[1,2],"b":"A"}"#);
+
+    for invalid in ["", "{", "[1,]", r#"{"a" 1}"#, "tru", "\"open", "1 2"] {
+        assert!(Json::parse(invalid).is_err(), "{} 应当解析失败", invalid);
+    }
+
+    // 嵌套层数有上限，过深的输入返回错误而不是耗尽栈空间
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(Json::parse(&nested(MAX_DEPTH)).is_ok());
+    assert!(Json::parse(&nested(MAX_DEPTH + 1)).is_err());
+    assert!(Json::parse(&"[{\"a\":".repeat(200_000)).is_err());
+}
+
+#[test]
+fn test_reveal_reports_changed_cells_and_counts() {
+    let mut session = BotSession::new();
+    let started = send(
+        &mut session,
+        r#"{"cmd":"new","width":6,"height":5,"mines":4,"seed":3,"id":"a1"}"#,
+    );
+    assert_eq!(text(&started, "id"), "a1");
+    assert_eq!(started.get("ok"), Some(&Json::Bool(true)));
+    assert_eq!(text(&started, "status"), "playing");
+    assert_eq!(board_rows(&started), vec!["######"; 5]);
+
+    let revealed = send(&mut session, r#"{"cmd":"reveal","row":2,"col":3}"#);
+    let changed = revealed.get("changed").and_then(Json::as_array).unwrap();
+    assert_eq!(changed.len() as u64, number(&revealed, "revealed"));
+    let rows = board_rows(&revealed);
+    for cell in changed {
+        let row = cell.get("row").and_then(Json::as_u64).unwrap() as usize;
+        let col = cell.get("col").and_then(Json::as_u64).unwrap() as usize;
+        let shown = rows[row].chars().nth(col).unwrap().to_string();
+        assert_eq!(cell.get("cell").and_then(Json::as_str), Some(shown.as_str()));
+    }
+
+    // 相同种子、相同的首次点击得到相同的棋盘
+    let mut other = BotSession::new();
+    send(&mut other, r#"{"cmd":"new","width":6,"height":5,"mines":4,"seed":3}"#);
+    let same = send(&mut other, r#"{"cmd":"reveal","row":2,"col":3}"#);
+    assert_eq!(board_rows(&same), rows);
+}
+
+#[test]
+fn test_flag_toggles_and_counts() {
+    let mut session = BotSession::new();
+    send(&mut session, r#"{"cmd":"new","difficulty":"easy","seed":1}"#);
+
+    let flagged = send(&mut session, r#"{"cmd":"flag","row":0,"col":0}"#);
+    assert_eq!(number(&flagged, "flags"), 1);
+    assert!(board_rows(&flagged)[0].starts_with('F'));
+
+    let unflagged = send(&mut session, r#"{"cmd":"flag","row":0,"col":0}"#);
+    assert_eq!(number(&unflagged, "flags"), 0);
+    let changed = unflagged.get("changed").and_then(Json::as_array).unwrap();
+    assert_eq!(changed.len(), 1);
+
+    let state = send(&mut session, r#"{"cmd":"state"}"#);
+    assert_eq!(state.get("changed"), None);
+    assert_eq!(number(&state, "width"), 9);
+    assert_eq!(number(&state, "mines"), 10);
+}
+
+#[test]
+fn test_losing_ends_the_game() {
+    let mut session = BotSession::new();
+    send(&mut session, r#"{"cmd":"new","width":4,"height":4,"mines":7,"seed":5}"#);
+    let opened = send(&mut session, r#"{"cmd":"reveal","row":0,"col":0}"#);
+
+    // 任选一个隐藏格子一直点，直到踩雷或获胜
+    let mut response = opened;
+    while text(&response, "status") == "playing" {
+        let rows = board_rows(&response);
+        let (row, col) = rows
+            .iter()
+            .enumerate()
+            .find_map(|(row, cells)| cells.find('#').map(|col| (row, col)))
+            .unwrap();
+        response = send(
+            &mut session,
+            &format!(r#"{{"cmd":"reveal","row":{},"col":{}}}"#, row, col),
+        );
+    }
+    let status = text(&response, "status").to_string();
+    assert!(status == "lost" || status == "won");
+    if status == "lost" {
+        assert!(board_rows(&response).concat().contains('*'));
+    }
+
+    let after = send(&mut session, r#"{"cmd":"reveal","row":3,"col":3}"#);
+    assert_eq!(text(&after, "error"), "game_over");
+    assert_eq!(text(&after, "status"), status);
+}
+
+#[test]
+fn test_error_codes() {
+    let mut session = BotSession::new();
+    let cases = [
+        (r#"{"cmd":"reveal","row":0,"col":0}"#, "no_game"),
+        ("not json", "invalid_json"),
+        (r#"{"row":0}"#, "missing_field"),
+        (r#"{"cmd":"jump"}"#, "unknown_command"),
+        (r#"{"cmd":"new","difficulty":"extreme"}"#, "invalid_field"),
+        (r#"{"cmd":"new","width":3,"height":3,"mines":1}"#, "invalid_config"),
+        (
+            r#"{"cmd":"new","width":9,"height":9,"mines":18446744073709551615}"#,
+            "invalid_config",
+        ),
+    ];
+    for (line, code) in cases {
+        let response = send(&mut session, line);
+        assert_eq!(response.get("ok"), Some(&Json::Bool(false)), "{}", line);
+        assert_eq!(text(&response, "error"), code, "{}", line);
+    }
+
+    send(&mut session, r#"{"cmd":"new","width":5,"height":5,"mines":3,"seed":2}"#);
+    let cases = [
+        (r#"{"cmd":"reveal","row":5,"col":0}"#, "out_of_bounds"),
+        (r#"{"cmd":"flag","row":1}"#, "missing_field"),
+        (r#"{"cmd":"flag","row":-1,"col":0}"#, "invalid_field"),
+        (r#"{"cmd":"chord","row":2,"col":2}"#, "invalid_move"),
+    ];
+    for (line, code) in cases {
+        let response = send(&mut session, line);
+        assert_eq!(text(&response, "error"), code, "{}", line);
+        // 出错时仍然附带当前局面
+        assert_eq!(text(&response, "status"), "playing");
+    }
+}
+
+#[test]
+fn test_run_reads_lines_until_end() {
+    let input = "{\"cmd\":\"new\",\"seed\":9}\n\n{\"cmd\":\"reveal\",\"row\":4,\"col\":4}\n";
+    let mut output = Vec::new();
+    BotSession::new().run(input.as_bytes(), &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(|line| Json::parse(line).is_ok()));
+}
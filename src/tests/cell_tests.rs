@@ -28,8 +28,8 @@ fn test_cell_content_operations() {
     assert!(!cell.is_mine());
 
     // 测试设置地雷内容
-    cell.set_content(CellContent::Mine);
-    assert!(matches!(cell.content(), CellContent::Mine));
+    cell.set_content(CellContent::Mine(1));
+    assert!(matches!(cell.content(), CellContent::Mine(_)));
     assert!(cell.is_mine());
 
     // 测试设置不同数字
@@ -71,7 +71,7 @@ fn test_is_mine_functionality() {
     }
 
     // 地雷格子是地雷
-    cell.set_content(CellContent::Mine);
+    cell.set_content(CellContent::Mine(1));
     assert!(cell.is_mine());
 }
 
@@ -106,7 +106,7 @@ fn test_cell_clone_functionality() {
     assert!(!cloned.is_mine());
 
     // 修改原始对象不应该影响克隆
-    original.set_content(CellContent::Mine);
+    original.set_content(CellContent::Mine(1));
     assert!(matches!(cloned.content(), CellContent::Number(5)));
     assert!(original.is_mine());
     assert!(!cloned.is_mine());
@@ -121,11 +121,11 @@ fn test_cell_content_copy_semantics() {
     assert!(matches!(content1, CellContent::Number(3)));
     assert!(matches!(content2, CellContent::Number(3)));
 
-    let mine_content = CellContent::Mine;
+    let mine_content = CellContent::Mine(1);
     let mine_copy = mine_content; // Copy
 
-    assert!(matches!(mine_content, CellContent::Mine));
-    assert!(matches!(mine_copy, CellContent::Mine));
+    assert!(matches!(mine_content, CellContent::Mine(_)));
+    assert!(matches!(mine_copy, CellContent::Mine(_)));
 }
 
 #[test]
@@ -163,7 +163,7 @@ fn test_comprehensive_cell_workflow() {
     assert!(matches!(cell.content(), CellContent::Number(0)));
 
     // 2. 设置为地雷
-    cell.set_content(CellContent::Mine);
+    cell.set_content(CellContent::Mine(1));
     assert!(cell.is_mine());
 
     // 3. 玩家标记
@@ -226,10 +226,10 @@ fn test_cell_content_after_mine_generation() {
     for row in 0..board_config.board_size.height {
         for col in 0..board_config.board_size.width {
             let pos = Position { row, col };
-            let content = board.get_cell_content(pos);
+            let content = board.spoiler().content(pos);
 
             match content {
-                CellContent::Mine => {
+                CellContent::Mine(_) => {
                     // 地雷格子验证通过
                 }
                 CellContent::Number(n) => {
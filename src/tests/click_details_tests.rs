@@ -0,0 +1,106 @@
+// 详细点击结果测试
+//
+// 验证翻开的格子按展开顺序列出并带有内容、旗子变化的前后值、双击和踩雷的结果，
+// 以及详细结果与订阅的观察者互不影响
+
+use crate::config::{Difficulty, difficulty_to_board_config};
+use crate::core::{
+    Board, BoardEvent, CellContent, CellState, ClickResult, FlagChange, Position,
+};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn test_first_click_lists_flood_fill_in_order() {
+    let mut board = Board::with_seed(difficulty_to_board_config(Difficulty::Medium), 11);
+    let start = Position { row: 8, col: 8 };
+    let details = board.left_click_detailed(start);
+
+    assert!(matches!(details.result, ClickResult::Continue));
+    assert!(details.mines_placed);
+    assert!(details.flags.is_empty());
+    assert_eq!(details.revealed[0].0, start);
+    assert_eq!(details.revealed.len(), board.get_revealed_count());
+    for (i, &(pos, content)) in details.revealed.iter().enumerate() {
+        assert!(!details.revealed[..i].iter().any(|&(seen, _)| seen == pos));
+        assert_eq!(board.get_cell_state(pos), CellState::Revealed);
+        assert!(matches!(
+            (content, board.spoiler().content(pos)),
+            (CellContent::Number(a), CellContent::Number(b)) if a == b
+        ));
+    }
+
+    // 之后的点击不再布雷，无效的点击没有变化
+    let again = board.left_click_detailed(start);
+    assert!(matches!(again.result, ClickResult::Invalid));
+    assert!(!again.mines_placed);
+    assert!(again.revealed.is_empty());
+}
+
+#[test]
+fn test_flag_changes_record_previous_count() {
+    let mut board = Board::new(difficulty_to_board_config(Difficulty::Easy));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    let pos = Position { row: 0, col: 0 };
+
+    let flagged = board.right_click_detailed(pos);
+    assert!(matches!(flagged.result, ClickResult::Continue));
+    assert!(flagged.revealed.is_empty());
+    assert_eq!(flagged.flags, vec![FlagChange { pos, from: 0, to: 1 }]);
+
+    let unflagged = board.right_click_detailed(pos);
+    assert_eq!(unflagged.flags, vec![FlagChange { pos, from: 1, to: 0 }]);
+}
+
+#[test]
+fn test_chord_and_game_over_details() {
+    let config = difficulty_to_board_config(Difficulty::Easy);
+    let mut board = Board::new(config);
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 2 }]);
+    board.left_click(Position { row: 1, col: 1 });
+    board.right_click(Position { row: 0, col: 0 });
+    board.right_click(Position { row: 0, col: 2 });
+
+    let details = board.chord_detailed(Position { row: 1, col: 1 });
+    assert!(!matches!(details.result, ClickResult::Invalid));
+    assert!(
+        details
+            .revealed
+            .iter()
+            .any(|&(pos, _)| pos == Position { row: 0, col: 1 })
+    );
+    assert_eq!(details.revealed.len() + 1, board.get_revealed_count());
+
+    // 踩雷：翻开的地雷带着地雷内容
+    let mut board = Board::new(config);
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    let details = board.left_click_detailed(Position { row: 0, col: 0 });
+    assert!(matches!(details.result, ClickResult::GameOver));
+    assert!(matches!(
+        details.revealed.as_slice(),
+        [(pos, CellContent::Mine(1))] if *pos == Position { row: 0, col: 0 }
+    ));
+}
+
+#[test]
+fn test_details_and_observers_see_the_same_changes() {
+    let mut board = Board::with_seed(difficulty_to_board_config(Difficulty::Easy), 2);
+    let events = Arc::new(Mutex::new(0));
+    let sink = Arc::clone(&events);
+    board.subscribe(move |event: &BoardEvent| {
+        if matches!(event, BoardEvent::CellRevealed { .. }) {
+            *sink.lock().unwrap() += 1;
+        }
+    });
+
+    let details = board.left_click_detailed(Position { row: 4, col: 4 });
+    assert_eq!(*events.lock().unwrap(), details.revealed.len());
+
+    // 普通点击照常通知观察者
+    let hidden = (0..9)
+        .flat_map(|row| (0..9).map(move |col| Position { row, col }))
+        .find(|&pos| board.get_cell_state(pos) == CellState::Hidden)
+        .unwrap();
+    board.right_click(hidden);
+    board.right_click(hidden);
+    assert_eq!(*events.lock().unwrap(), details.revealed.len());
+}
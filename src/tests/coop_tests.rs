@@ -0,0 +1,206 @@
+// 合作模式测试
+//
+// 验证操作者记录、光标、基于版本号的冲突检测、全队失败和胜利，本机端口上的多人对局，
+// 以及拒绝视图编码无法表示的非经典棋盘
+
+use crate::core::{
+    BoardConfig, BoardShape, BoardSize, CellState, CoopAction, CoopEvent, CoopOutcome,
+    CoopSession, Neighbourhood, Position,
+};
+use crate::net::{CoopClientMessage, CoopConfig, CoopServer, CoopServerMessage, decode_owners};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::thread;
+
+fn config(size: usize, mine_count: usize) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize {
+            width: size,
+            height: size,
+        },
+        mine_count,
+        shape: BoardShape::Square,
+        neighbourhood: Neighbourhood::Moore,
+        mines_per_cell: 1,
+        negative_mines: 0,
+    }
+}
+
+fn pos(row: usize, col: usize) -> Position {
+    Position { row, col }
+}
+
+// 4x4 棋盘，地雷在 (0,0) 和 (3,3)
+fn session(players: usize) -> CoopSession {
+    let mut session = CoopSession::new(config(4, 2), players);
+    session.place_mines_for_test(&[pos(0, 0), pos(3, 3)]);
+    session
+}
+
+#[test]
+fn test_reveals_and_flags_are_attributed() {
+    let mut session = session(2);
+
+    assert_eq!(
+        session.apply(1, CoopAction::Reveal(pos(0, 1)), 0),
+        CoopEvent::Revealed { cells: 1 }
+    );
+    assert_eq!(session.actor(pos(0, 1)), Some(1));
+    assert_eq!(session.cursor(1), Some(pos(0, 1)));
+
+    let seen = session.revision();
+    assert_eq!(
+        session.apply(0, CoopAction::Flag(pos(0, 0)), seen),
+        CoopEvent::Flagged
+    );
+    assert_eq!(session.actor(pos(0, 0)), Some(0));
+    assert_eq!(session.actor(pos(2, 2)), None);
+
+    // 只移动光标不改动棋盘
+    assert_eq!(
+        session.apply(1, CoopAction::Cursor(pos(2, 2)), 0),
+        CoopEvent::Moved
+    );
+    assert_eq!(session.cursor(1), Some(pos(2, 2)));
+    assert_eq!(session.revision(), seen + 1);
+}
+
+#[test]
+fn test_stale_action_on_changed_cell_is_conflict() {
+    let mut session = session(2);
+
+    // 两名玩家都在版本 0 时决定标记同一格
+    assert_eq!(
+        session.apply(0, CoopAction::Flag(pos(3, 3)), 0),
+        CoopEvent::Flagged
+    );
+    assert_eq!(
+        session.apply(1, CoopAction::Flag(pos(3, 3)), 0),
+        CoopEvent::Conflict { by: 0 }
+    );
+    assert_eq!(session.board().get_cell_state(pos(3, 3)), CellState::Flagged);
+
+    // 看到最新版本后再操作则照常执行；自己的改动不算冲突
+    let seen = session.revision();
+    assert_eq!(
+        session.apply(1, CoopAction::Flag(pos(3, 3)), seen),
+        CoopEvent::Unflagged
+    );
+    assert_eq!(
+        session.apply(1, CoopAction::Flag(pos(3, 3)), seen),
+        CoopEvent::Flagged
+    );
+    assert_eq!(session.actor(pos(3, 3)), Some(1));
+}
+
+#[test]
+fn test_any_mine_hit_loses_for_everyone() {
+    let mut session = session(3);
+    session.apply(0, CoopAction::Reveal(pos(1, 1)), 0);
+
+    let seen = session.revision();
+    assert_eq!(
+        session.apply(2, CoopAction::Reveal(pos(3, 3)), seen),
+        CoopEvent::Lost { player: 2 }
+    );
+    assert_eq!(session.outcome(), Some(CoopOutcome::Lost { player: 2 }));
+    assert_eq!(
+        session.apply(1, CoopAction::Reveal(pos(2, 2)), seen + 1),
+        CoopEvent::Invalid
+    );
+}
+
+#[test]
+fn test_shared_board_win_over_network() {
+    let coop_config = CoopConfig {
+        board_config: config(4, 2),
+        seed: 7,
+        players: 2,
+    };
+    let server = CoopServer::bind("127.0.0.1:0", coop_config).unwrap();
+    let addr = server.local_addr().unwrap();
+    let handle = thread::spawn(move || server.run().unwrap());
+
+    let mut clients: Vec<(BufReader<TcpStream>, TcpStream)> = ["甲", "乙"]
+        .iter()
+        .map(|name| {
+            let mut writer = TcpStream::connect(addr).unwrap();
+            writeln!(writer, "{}", CoopClientMessage::Hello(name.to_string()).to_line()).unwrap();
+            (BufReader::new(writer.try_clone().unwrap()), writer)
+        })
+        .collect();
+    let next = |reader: &mut BufReader<TcpStream>| {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        CoopServerMessage::parse(&line).unwrap()
+    };
+
+    // 等所有人都收到开局棋盘后再操作，否则操作会因为还有人未加入而被拒绝
+    for (reader, _) in &mut clients {
+        while !matches!(next(reader), CoopServerMessage::Owners(_)) {}
+    }
+
+    // 参照棋盘：相同种子，首次点击同一格，布局相同
+    let mut reference = CoopSession::with_seed(coop_config.board_config, 2, 7);
+    reference.apply(0, CoopAction::Reveal(pos(0, 0)), 0);
+
+    // 轮流翻开安全格子，每次都基于最新版本号
+    let mut seen = 0;
+    let mut turn = 0;
+    let mut last = None;
+    let cells: Vec<Position> = (0..4).flat_map(|row| (0..4).map(move |col| pos(row, col))).collect();
+    'cells: for &cell in &cells {
+        let hidden = reference.board().get_cell_state(cell) == CellState::Hidden;
+        let is_mine = matches!(
+            reference.board().spoiler().content(cell),
+            crate::core::CellContent::Mine(_)
+        );
+        if cell != pos(0, 0) && (!hidden || is_mine) {
+            continue;
+        }
+        let (reader, writer) = &mut clients[turn];
+        let message = CoopClientMessage::Reveal { pos: cell, seen };
+        writeln!(writer, "{}", message.to_line()).unwrap();
+        // 另一位玩家的连接里还积压着之前的广播，读到自己的操作结果为止
+        let mut acted = false;
+        loop {
+            match next(reader) {
+                CoopServerMessage::View { revision, .. } => seen = revision,
+                CoopServerMessage::Owners(owners) => {
+                    last = decode_owners(&owners, coop_config.board_config.board_size);
+                    if acted {
+                        break;
+                    }
+                }
+                CoopServerMessage::Revealed { player, .. } if player == turn => acted = true,
+                CoopServerMessage::Conflict { .. } => panic!("最新版本上的操作不应冲突"),
+                CoopServerMessage::Result(_) => break 'cells,
+                CoopServerMessage::Error(_) => break,
+                _ => {}
+            }
+        }
+        turn = 1 - turn;
+    }
+
+    assert_eq!(handle.join().unwrap(), Some(CoopOutcome::Won));
+    let owners = last.unwrap();
+    assert!(owners.contains(&Some(0)));
+    assert!(owners.contains(&Some(1)));
+}
+
+#[test]
+fn test_bind_rejects_non_classic_boards() {
+    for (mines_per_cell, negative_mines) in [(2, 0), (1, 1)] {
+        let coop_config = CoopConfig {
+            board_config: BoardConfig {
+                mines_per_cell,
+                negative_mines,
+                ..config(4, 2)
+            },
+            seed: 7,
+            players: 2,
+        };
+        let err = CoopServer::bind("127.0.0.1:0", coop_config).err().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,199 @@
+// gym 风格环境测试
+//
+// 验证观测编码、奖励设置、动作掩码、结束后的处理，以及批量环境的自动重开和可复现性
+
+use crate::bot::env::{FLAG_PLANE, MINE_PLANE, NUMBER_PLANE};
+use crate::bot::{EnvOptions, MinesweeperEnv, PLANES, Rewards, VecEnv};
+use crate::config::{Difficulty, difficulty_to_board_config};
+use crate::core::{BoardConfig, BoardSize, Position};
+
+fn small_config() -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize {
+            width: 5,
+            height: 4,
+        },
+        mine_count: 3,
+        ..difficulty_to_board_config(Difficulty::Easy)
+    }
+}
+
+fn index(pos: Position, width: usize) -> usize {
+    pos.row * width + pos.col
+}
+
+#[test]
+fn test_observation_is_one_hot_with_fixed_shape() {
+    let config = small_config();
+    let mut env = MinesweeperEnv::new(EnvOptions::default(), 3, config);
+    let observation = env.reset(3, config);
+    assert_eq!(observation.shape(), [PLANES, 4, 5]);
+    assert_eq!(observation.data.len(), PLANES * 20);
+    // 开局全部隐藏
+    assert!(observation.data[..20].iter().all(|&value| value == 1.0));
+
+    let step = env.step(index(Position { row: 2, col: 2 }, 5));
+    let observation = step.observation;
+    assert_eq!(observation.shape(), [PLANES, 4, 5]);
+    for row in 0..4 {
+        for col in 0..5 {
+            let pos = Position { row, col };
+            let hot: f32 = (0..PLANES).map(|plane| observation.get(plane, pos)).sum();
+            assert_eq!(hot, 1.0);
+        }
+    }
+    // 点击的格子周围不布雷，因此是数字 0
+    assert_eq!(observation.get(NUMBER_PLANE, Position { row: 2, col: 2 }), 1.0);
+    assert!(step.info.revealed >= 9);
+}
+
+#[test]
+fn test_rewards_follow_configuration() {
+    let config = small_config();
+    let options = EnvOptions {
+        rewards: Rewards {
+            per_cell: 0.5,
+            step: -0.01,
+            invalid: -2.0,
+            ..Rewards::default()
+        },
+        ..EnvOptions::default()
+    };
+    let mut env = MinesweeperEnv::new(options, 1, config);
+    let first = env.step(index(Position { row: 2, col: 2 }, 5));
+    let expected = -0.01 + 0.5 * first.info.revealed as f32
+        + if first.info.won { 1.0 } else { 0.0 };
+    assert!((first.reward - expected).abs() < 1e-5);
+    if first.done {
+        return;
+    }
+
+    // 再翻开同一格是无效动作，不改变棋盘
+    let again = env.step(index(Position { row: 2, col: 2 }, 5));
+    assert!(again.info.invalid);
+    assert_eq!(again.info.revealed, 0);
+    assert!(!again.done);
+    assert!((again.reward - (-2.01)).abs() < 1e-5);
+    assert_eq!(again.info.steps, 2);
+}
+
+#[test]
+fn test_action_mask_and_flags() {
+    let config = small_config();
+    let options = EnvOptions {
+        allow_flags: true,
+        ..EnvOptions::default()
+    };
+    let mut env = MinesweeperEnv::new(options, 5, config);
+    assert_eq!(env.action_count(), 40);
+    assert!(env.action_mask().iter().all(|&valid| valid));
+
+    let step = env.step(index(Position { row: 2, col: 2 }, 5));
+    if step.done {
+        return;
+    }
+    let mask = env.action_mask();
+    let view = &step.observation;
+    for cell in 0..20 {
+        let pos = Position {
+            row: cell / 5,
+            col: cell % 5,
+        };
+        let hidden = view.get(0, pos) == 1.0;
+        assert_eq!(mask[cell], hidden);
+        assert_eq!(mask[20 + cell], hidden);
+    }
+
+    // 插旗后该格不能翻开，但可以拔旗
+    let hidden = (0..20).find(|&cell| mask[cell]).unwrap();
+    let flagged = env.step(20 + hidden);
+    assert!(!flagged.info.invalid);
+    let pos = Position {
+        row: hidden / 5,
+        col: hidden % 5,
+    };
+    assert_eq!(flagged.observation.get(FLAG_PLANE, pos), 1.0);
+    let mask = env.action_mask();
+    assert!(!mask[hidden]);
+    assert!(mask[20 + hidden]);
+}
+
+#[test]
+fn test_game_end_and_actions_after_it() {
+    let config = small_config();
+    let mut env = MinesweeperEnv::new(EnvOptions::default(), 9, config);
+    env.step(index(Position { row: 2, col: 2 }, 5));
+
+    // 依次翻开隐藏格子直到结束，必然在有限步内结束
+    let mut last = None;
+    while !env.is_done() {
+        let action = env.action_mask().iter().position(|&valid| valid).unwrap();
+        last = Some(env.step(action));
+    }
+    let last = last.unwrap();
+    assert!(last.done);
+    if last.info.won {
+        assert!((last.reward - 1.0).abs() < 1e-5);
+    } else {
+        assert!((last.reward + 1.0).abs() < 1e-5);
+        let mines: f32 = (0..20)
+            .map(|cell| {
+                last.observation.get(
+                    MINE_PLANE,
+                    Position {
+                        row: cell / 5,
+                        col: cell % 5,
+                    },
+                )
+            })
+            .sum();
+        assert!(mines >= 1.0);
+    }
+
+    assert!(env.action_mask().iter().all(|&valid| !valid));
+    let after = env.step(0);
+    assert!(after.done);
+    assert!(after.info.invalid);
+}
+
+#[test]
+fn test_vec_env_steps_in_parallel_and_resets() {
+    let config = small_config();
+    let options = EnvOptions {
+        end_on_invalid: true,
+        ..EnvOptions::default()
+    };
+    let mut envs = VecEnv::new(40, options, 0, config);
+    assert_eq!(envs.len(), 40);
+
+    // 同一种子和动作序列的单个环境给出相同的结果
+    let centre = index(Position { row: 2, col: 2 }, 5);
+    let steps = envs.step(&[centre; 40]);
+    for (i, vec_step) in steps.iter().enumerate() {
+        let mut single = MinesweeperEnv::new(options, i as u64, config);
+        let expected = single.step(centre);
+        assert_eq!(vec_step.step.reward, expected.reward);
+        assert_eq!(vec_step.step.done, expected.done);
+        match &vec_step.terminal {
+            Some(terminal) => assert_eq!(*terminal, expected.observation),
+            None => assert_eq!(vec_step.step.observation, expected.observation),
+        }
+    }
+
+    // 第一步后还在进行的环境中心已经翻开，再翻开是无效动作，结束并以新种子重开；
+    // 第一步就结束的环境已经重开，这一步是新局的第一下
+    let finished: Vec<bool> = steps.iter().map(|vec_step| vec_step.step.done).collect();
+    assert!(finished.iter().any(|&done| !done));
+    let steps = envs.step(&[centre; 40]);
+    let observations = envs.observations();
+    for (i, vec_step) in steps.iter().enumerate() {
+        if finished[i] {
+            assert!(!vec_step.step.info.invalid);
+            continue;
+        }
+        assert!(vec_step.step.info.invalid);
+        assert!(vec_step.step.done);
+        assert!(vec_step.terminal.is_some());
+        assert!(observations[i].data[..20].iter().all(|&value| value == 1.0));
+    }
+}
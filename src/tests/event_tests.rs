@@ -0,0 +1,128 @@
+// 棋盘事件测试
+//
+// 验证布雷、翻开、展开、插旗、踩雷和胜利的事件顺序，以及多个观察者和取消订阅
+
+use crate::config::{Difficulty, difficulty_to_board_config};
+use crate::core::{Board, BoardConfig, BoardEvent, BoardSize, CellContent, Position};
+use std::sync::{Arc, Mutex};
+
+fn small_config() -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize {
+            width: 4,
+            height: 4,
+        },
+        mine_count: 1,
+        ..difficulty_to_board_config(Difficulty::Easy)
+    }
+}
+
+// 订阅一个把事件记到共享列表的观察者
+fn record(board: &mut Board) -> Arc<Mutex<Vec<BoardEvent>>> {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&events);
+    board.subscribe(move |event: &BoardEvent| sink.lock().unwrap().push(*event));
+    events
+}
+
+fn revealed(events: &[BoardEvent]) -> Vec<Position> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            BoardEvent::CellRevealed { pos, .. } => Some(*pos),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_first_click_places_mines_then_reveals() {
+    let mut board = Board::with_seed(difficulty_to_board_config(Difficulty::Easy), 4);
+    let events = record(&mut board);
+    let start = Position { row: 4, col: 4 };
+    board.left_click(start);
+
+    let events = events.lock().unwrap();
+    assert!(matches!(
+        events[0],
+        BoardEvent::MinesPlaced { first_click } if first_click == start
+    ));
+    assert!(matches!(
+        events[1],
+        BoardEvent::CellRevealed { pos, content: CellContent::Number(0) } if pos == start
+    ));
+    // 每个翻开的格子恰好通知一次，与翻开计数一致
+    let cells = revealed(&events);
+    assert_eq!(cells.len(), board.get_revealed_count());
+    for (i, pos) in cells.iter().enumerate() {
+        assert!(!cells[..i].contains(pos));
+    }
+}
+
+#[test]
+fn test_flood_fill_and_victory_events() {
+    let mut board = Board::new(small_config());
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    let events = record(&mut board);
+    let result = board.left_click(Position { row: 3, col: 3 });
+    assert!(matches!(result, crate::core::ClickResult::Victory));
+
+    let events = events.lock().unwrap();
+    // 布雷已在测试中完成，不再通知
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, BoardEvent::MinesPlaced { .. }))
+    );
+    assert_eq!(revealed(&events).len(), 15);
+    assert!(matches!(events.last(), Some(BoardEvent::GameWon)));
+    // 展开的数字格子带着内容
+    assert!(events.iter().any(|event| matches!(
+        event,
+        BoardEvent::CellRevealed { pos, content: CellContent::Number(1) }
+            if *pos == Position { row: 1, col: 1 }
+    )));
+}
+
+#[test]
+fn test_flags_and_mine_triggered() {
+    let mut board = Board::new(small_config());
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    let events = record(&mut board);
+    let mine = Position { row: 0, col: 0 };
+
+    board.right_click(mine);
+    board.right_click(mine);
+    // 无效操作不产生事件
+    board.left_click(Position { row: 9, col: 9 });
+    board.left_click(mine);
+    board.reveal_all_mines();
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 4);
+    assert!(matches!(events[0], BoardEvent::FlagToggled { pos, flags: 1, .. } if pos == mine));
+    assert!(matches!(events[1], BoardEvent::FlagToggled { pos, flags: 0, previous: 1 } if pos == mine));
+    assert!(matches!(
+        events[2],
+        BoardEvent::CellRevealed { pos, content: CellContent::Mine(1) } if pos == mine
+    ));
+    assert!(matches!(events[3], BoardEvent::MineTriggered { pos } if pos == mine));
+}
+
+#[test]
+fn test_observers_are_independent_and_can_unsubscribe() {
+    let mut board = Board::new(small_config());
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    let first = record(&mut board);
+    let count = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&count);
+    let id = board.subscribe(move |_: &BoardEvent| *counter.lock().unwrap() += 1);
+
+    board.right_click(Position { row: 3, col: 3 });
+    assert!(board.unsubscribe(id));
+    assert!(!board.unsubscribe(id));
+    board.right_click(Position { row: 3, col: 3 });
+
+    assert_eq!(first.lock().unwrap().len(), 2);
+    assert_eq!(*count.lock().unwrap(), 1);
+}
@@ -0,0 +1,195 @@
+// 图片导出测试
+//
+// 验证 SVG 中地雷和错旗只在对应选项打开时出现、PNG 文件的块结构和校验和、
+// 光栅化后格子的颜色、概率叠加、六边形的半格偏移、最小格子边长以及按扩展名选择格式
+
+use crate::support::{played_board, small_config};
+use crate::config::export::MIN_CELL_SIZE;
+use crate::core::{Board, BoardConfig, BoardShape};
+use crate::export::{ExportError, ExportOptions, Format, rasterize, save, to_png, to_svg};
+use std::path::Path;
+
+// 默认 24 像素的格子：左侧留 22 像素的行号，上方留 24 像素的列号
+fn cell_center(row: u32, col: u32) -> (u32, u32) {
+    (22 + 24 * col + 12, 24 + 24 * row + 12)
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+// 拆出 PNG 的各个块（类型、内容），同时检查每块的 CRC
+fn chunks(png: &[u8]) -> Vec<(String, Vec<u8>)> {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    let mut chunks = Vec::new();
+    let mut at = 8;
+    while at < png.len() {
+        let length = u32::from_be_bytes(png[at..at + 4].try_into().unwrap()) as usize;
+        let body = &png[at + 4..at + 8 + length];
+        let crc = u32::from_be_bytes(png[at + 8 + length..at + 12 + length].try_into().unwrap());
+        assert_eq!(crc32(body), crc, "块的 CRC 错误");
+        chunks.push((
+            String::from_utf8(body[..4].to_vec()).unwrap(),
+            body[4..].to_vec(),
+        ));
+        at += 12 + length;
+    }
+    chunks
+}
+
+#[test]
+fn test_svg_hides_mines_by_default() {
+    let board = played_board();
+    let svg = to_svg(&board, &ExportOptions::default());
+    assert!(svg.starts_with("<svg "), "{}", svg);
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains(r#"width="124" height="102""#), "{}", svg);
+    assert!(!svg.contains("<circle"), "默认不显示隐藏的地雷");
+    assert!(!svg.contains("<path"), "默认不标出错旗");
+    assert!(svg.contains("<polygon"), "旗子总是显示");
+}
+
+#[test]
+fn test_svg_spoiler_reveals_mines_and_wrong_flags() {
+    let board = played_board();
+    let svg = to_svg(&board, &ExportOptions::spoiler());
+    assert_eq!(svg.matches("<circle").count(), 1);
+    assert_eq!(svg.matches("<path").count(), 1);
+}
+
+#[test]
+fn test_png_structure() {
+    let board = played_board();
+    let options = ExportOptions::default();
+    let image = rasterize(&board, &options);
+    assert_eq!((image.width(), image.height()), (124, 102));
+
+    let chunks = chunks(&to_png(&board, &options));
+    let kinds: Vec<&str> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+    assert_eq!(kinds, ["IHDR", "IDAT", "IEND"]);
+
+    // 宽、高、8 位深度、RGB、无隔行
+    let header = &chunks[0].1;
+    assert_eq!(&header[..8], &[0, 0, 0, 124, 0, 0, 0, 102]);
+    assert_eq!(&header[8..], &[8, 2, 0, 0, 0]);
+
+    // zlib 尾部的 Adler-32 与逐行加上过滤字节的像素数据一致
+    let idat = &chunks[1].1;
+    assert_eq!(&idat[..2], &[0x78, 0x01]);
+    let mut raw = Vec::new();
+    for y in 0..image.height() {
+        raw.push(0);
+        for x in 0..image.width() {
+            let (r, g, b) = image.pixel(x, y).unwrap();
+            raw.extend([r, g, b]);
+        }
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in &raw {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    assert_eq!(idat[idat.len() - 4..], ((b << 16) | a).to_be_bytes());
+}
+
+#[test]
+fn test_rasterized_cell_colours() {
+    let board = played_board();
+    let image = rasterize(&board, &ExportOptions::default());
+    let pixel = |(x, y): (u32, u32)| image.pixel(x, y).unwrap();
+    assert_eq!(pixel(cell_center(0, 0)), (226, 226, 226), "空白格");
+    assert_eq!(pixel(cell_center(0, 3)), (172, 181, 194), "未翻开的格子");
+    assert_eq!(image.pixel(124, 0), None);
+
+    let spoiler = rasterize(&board, &ExportOptions::spoiler());
+    assert_eq!(spoiler.pixel(106, 36), Some((30, 30, 30)), "显示的地雷");
+}
+
+#[test]
+fn test_probability_overlay() {
+    let board = played_board();
+    let corner = (22 + 24 * 3 + 3, 24 + 3);
+    let plain = rasterize(&board, &ExportOptions::default());
+    let odds = rasterize(
+        &board,
+        &ExportOptions {
+            probabilities: true,
+            ..ExportOptions::default()
+        },
+    );
+    assert_ne!(plain.pixel(corner.0, corner.1), odds.pixel(corner.0, corner.1));
+    // 已翻开的格子不叠加概率
+    let (x, y) = cell_center(0, 0);
+    assert_eq!(plain.pixel(x, y), odds.pixel(x, y));
+
+    let svg = to_svg(
+        &board,
+        &ExportOptions {
+            probabilities: true,
+            ..ExportOptions::default()
+        },
+    );
+    assert!(svg.contains(">100%</text>"), "{}", svg);
+}
+
+#[test]
+fn test_hex_rows_shift_half_a_cell() {
+    let board = Board::new(BoardConfig {
+        shape: BoardShape::Hex,
+        ..small_config(4, 3)
+    });
+    let image = rasterize(&board, &ExportOptions::default());
+    assert_eq!(image.width(), 124 + 12);
+
+    let svg = to_svg(&board, &ExportOptions::default());
+    assert!(svg.contains(r#"<rect x="22" y="24" "#), "{}", svg);
+    assert!(svg.contains(r#"<rect x="34" y="48" "#), "奇数行右移半格");
+    assert!(svg.contains(r#"<rect x="22" y="72" "#));
+}
+
+#[test]
+fn test_cell_size_has_minimum() {
+    let board = played_board();
+    let tiny = rasterize(
+        &board,
+        &ExportOptions {
+            cell_size: 1,
+            ..ExportOptions::default()
+        },
+    );
+    let minimum = rasterize(
+        &board,
+        &ExportOptions {
+            cell_size: MIN_CELL_SIZE,
+            ..ExportOptions::default()
+        },
+    );
+    assert_eq!(tiny, minimum);
+}
+
+#[test]
+fn test_format_from_extension() {
+    assert_eq!(Format::from_path(Path::new("a.svg")), Some(Format::Svg));
+    assert_eq!(Format::from_path(Path::new("dir/B.PNG")), Some(Format::Png));
+    assert_eq!(Format::from_path(Path::new("a.gif")), None);
+    assert_eq!(Format::from_path(Path::new("png")), None);
+
+    let path = std::env::temp_dir().join("minesweeper_export_test.gif");
+    let result = save(&played_board(), &path, &ExportOptions::default());
+    assert!(matches!(result, Err(ExportError::UnsupportedFormat(_))));
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("minesweeper_export_test.gif"), "{}", message);
+    assert!(message.contains(".svg"), "{}", message);
+    assert!(!path.exists());
+}
@@ -0,0 +1,101 @@
+// 双人对战测试
+//
+// 验证认领地雷、交换回合、过半数获胜、平局和电脑玩家的选择
+
+use crate::config::flags_board_config;
+use crate::core::{
+    BoardConfig, BoardShape, BoardSize, FlagsEvent, FlagsMatch, Neighbourhood, Position,
+};
+
+fn config(size: usize, mine_count: usize) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize {
+            width: size,
+            height: size,
+        },
+        mine_count,
+        shape: BoardShape::Square,
+        neighbourhood: Neighbourhood::Moore,
+        mines_per_cell: 1,
+        negative_mines: 0,
+    }
+}
+
+fn pos(row: usize, col: usize) -> Position {
+    Position { row, col }
+}
+
+#[test]
+fn test_claiming_mine_scores_and_keeps_turn() {
+    let mut flags = FlagsMatch::new(config(4, 3));
+    flags.place_mines_for_test(&[pos(0, 0), pos(0, 3), pos(3, 0)]);
+
+    assert_eq!(
+        flags.click(pos(0, 0)),
+        FlagsEvent::Claimed {
+            player: 0,
+            score: 1
+        }
+    );
+    assert_eq!(flags.current_player(), 0);
+    assert_eq!(flags.owner(pos(0, 0)), Some(0));
+
+    assert_eq!(flags.click(pos(1, 1)), FlagsEvent::Revealed { next: 1 });
+    assert_eq!(flags.click(pos(1, 1)), FlagsEvent::Invalid);
+    assert_eq!(flags.current_player(), 1);
+    assert_eq!(flags.scores(), [1, 0]);
+}
+
+#[test]
+fn test_majority_wins() {
+    let mut flags = FlagsMatch::new(config(4, 3));
+    flags.place_mines_for_test(&[pos(0, 0), pos(0, 3), pos(3, 0)]);
+    assert_eq!(flags.majority(), 2);
+
+    let _ = flags.click(pos(1, 1));
+    assert!(matches!(flags.click(pos(0, 3)), FlagsEvent::Claimed { player: 1, .. }));
+    assert_eq!(flags.click(pos(3, 0)), FlagsEvent::Won { player: 1 });
+    assert!(flags.is_finished());
+    assert_eq!(flags.click(pos(0, 0)), FlagsEvent::Invalid);
+}
+
+#[test]
+fn test_even_split_is_a_draw() {
+    let mut flags = FlagsMatch::new(config(4, 2));
+    flags.place_mines_for_test(&[pos(0, 0), pos(3, 3)]);
+
+    let _ = flags.click(pos(0, 0));
+    let _ = flags.click(pos(1, 1));
+    assert_eq!(flags.click(pos(3, 3)), FlagsEvent::Draw);
+    assert_eq!(flags.scores(), [1, 1]);
+}
+
+#[test]
+fn test_computer_claims_deduced_mine() {
+    let mut flags = FlagsMatch::new(config(3, 1));
+    flags.place_mines_for_test(&[pos(0, 0)]);
+    let _ = flags.click(pos(2, 2));
+
+    assert_eq!(flags.computer_move(), Some(pos(0, 0)));
+}
+
+#[test]
+fn test_computer_plays_a_full_match() {
+    let mut flags = FlagsMatch::with_seed(flags_board_config(), 11);
+    let mut moves = 0;
+    while !flags.is_finished() {
+        let choice = flags.computer_move().expect("未结束时总有可点击的格子");
+        assert_ne!(flags.click(choice), FlagsEvent::Invalid);
+        moves += 1;
+        assert!(moves <= 256);
+    }
+    assert!(flags.scores().iter().any(|&score| score >= flags.majority()));
+}
+
+#[test]
+#[should_panic]
+fn test_multi_mine_config_is_rejected() {
+    let mut board_config = config(4, 3);
+    board_config.mines_per_cell = 2;
+    let _ = FlagsMatch::new(board_config);
+}
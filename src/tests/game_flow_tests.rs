@@ -78,8 +78,8 @@ fn test_mine_generation_properties() {
 
     // 验证首次点击位置是安全的
     assert!(!matches!(
-        board.get_cell_content(first_pos),
-        CellContent::Mine
+        board.spoiler().content(first_pos),
+        CellContent::Mine(_)
     ));
 }
 
@@ -109,7 +109,7 @@ fn count_mines(board: &Board) -> usize {
     for row in 0..config.board_size.height {
         for col in 0..config.board_size.width {
             let pos = Position { row, col };
-            if matches!(board.get_cell_content(pos), CellContent::Mine) {
+            if matches!(board.spoiler().content(pos), CellContent::Mine(_)) {
                 count += 1;
             }
         }
@@ -0,0 +1,167 @@
+// 图结构棋盘测试
+//
+// 验证图文件解析，以及数字、自动展开、首次点击安全和胜利判定都沿图的边进行
+
+use crate::config::graph::MAX_DISPLAY_COORDINATE;
+use crate::config::{graph, graph_mine_count};
+use crate::core::{
+    BoardGraph, CellContent, CellState, ClickResult, GraphBoard, GraphLoadError, Position,
+};
+
+// 0-1-2-3 的一条链，外加一个孤立的三角形 4-5-6
+const PATH_AND_TRIANGLE: &str = "
+# 链和三角形
+node 0
+node 1
+node 2
+node 3
+node 4
+node 5
+node 6
+edge 0 1
+edge 1 2
+edge 2 3
+edge 4 5
+edge 5 6
+edge 6 4
+edge 1 0   # 重复的边只记录一次
+";
+
+fn parse(text: &str) -> BoardGraph {
+    BoardGraph::parse(text).expect("graph should parse")
+}
+
+fn parse_error_line(text: &str) -> usize {
+    match BoardGraph::parse(text) {
+        Err(GraphLoadError::Parse { line, .. }) => line,
+        other => panic!("expected parse error, got {:?}", other.map(|g| g.node_count())),
+    }
+}
+
+#[test]
+fn test_parse_nodes_and_edges() {
+    let graph = parse(PATH_AND_TRIANGLE);
+
+    assert_eq!(graph.node_count(), 7);
+    assert_eq!(graph.neighbours(0), &[1]);
+    assert_eq!(graph.neighbours(1), &[0, 2]);
+    assert_eq!(graph.neighbours(4), &[5, 6]);
+    assert_eq!(graph.mine_count(), None);
+    assert!(!graph.has_display());
+}
+
+#[test]
+fn test_parse_errors_report_line() {
+    // 边引用未声明的节点
+    assert_eq!(parse_error_line("node 0\nedge 0 1\n"), 2);
+    // 节点编号不连续
+    assert_eq!(parse_error_line("node 0\nnode 2\n"), 2);
+    // 自环
+    assert_eq!(parse_error_line("node 0\nnode 1\n\nedge 1 1\n"), 4);
+    // 无法识别的指令
+    assert_eq!(parse_error_line("node 0\nvertex 1\n"), 2);
+    // 部分节点缺少坐标
+    assert!(BoardGraph::parse("node 0 0 0\nnode 1\n").is_err());
+    // 地雷数不少于节点数
+    assert!(BoardGraph::parse("node 0\nnode 1\nmines 2\n").is_err());
+    // 两个节点使用同一个显示坐标
+    assert_eq!(parse_error_line("node 0 1 1\nnode 1 1 1\n"), 2);
+    // 显示坐标超出网格上限
+    let far = MAX_DISPLAY_COORDINATE + 1;
+    assert_eq!(parse_error_line(&format!("node 0 0 0\nnode 1 {} 0\n", far)), 2);
+    assert_eq!(parse_error_line(&format!("node 0 0 {}\nnode 1 0 0\n", far)), 1);
+    // 节点太少，放不下地雷和安全的节点
+    assert_eq!(parse_error_line(""), 0);
+    assert_eq!(parse_error_line("node 0\n"), 0);
+}
+
+#[test]
+fn test_display_coordinates_lookup() {
+    let graph = parse("node 0 0 0\nnode 1 0 2\nnode 2 3 1\nedge 0 1\nmines 1\n");
+
+    assert!(graph.has_display());
+    assert_eq!(graph.mine_count(), Some(1));
+    assert_eq!(graph.node_at(Position { row: 3, col: 1 }), Some(2));
+    assert_eq!(graph.node_at(Position { row: 0, col: 1 }), None);
+
+    // 上限处的坐标仍然有效
+    let max = MAX_DISPLAY_COORDINATE;
+    let corner = parse(&format!("node 0 0 0\nnode 1 {} {}\n", max, max));
+    assert_eq!(corner.node_at(Position { row: max, col: max }), Some(1));
+}
+
+#[test]
+fn test_numbers_follow_edges() {
+    let mut board = GraphBoard::new(parse(PATH_AND_TRIANGLE), 1);
+    board.place_mines_for_test(&[2]);
+
+    assert!(matches!(board.spoiler().content(1), CellContent::Number(1)));
+    assert!(matches!(board.spoiler().content(3), CellContent::Number(1)));
+    assert!(matches!(board.spoiler().content(0), CellContent::Number(0)));
+    assert!(matches!(board.spoiler().content(5), CellContent::Number(0)));
+}
+
+#[test]
+fn test_flood_fill_stays_in_component() {
+    let mut board = GraphBoard::new(parse(PATH_AND_TRIANGLE), 1);
+    board.place_mines_for_test(&[3]);
+
+    // 三角形与链不连通，点击三角形只展开三角形
+    let result = board.left_click(4);
+    assert!(matches!(result, ClickResult::Continue));
+    assert_eq!(board.get_revealed_count(), 3);
+    assert_eq!(board.get_cell_state(0), CellState::Hidden);
+
+    // 点击链的一端，沿边展开到挨着地雷的节点为止，此时所有安全节点都已翻开
+    let result = board.left_click(0);
+    assert!(matches!(result, ClickResult::Victory));
+    assert_eq!(board.get_cell_state(2), CellState::Revealed);
+    assert_eq!(board.get_cell_state(3), CellState::Hidden);
+}
+
+#[test]
+fn test_first_click_protects_graph_neighbours() {
+    let text = "node 0\nnode 1\nnode 2\nnode 3\nnode 4\nnode 5\nedge 0 1\nedge 0 2\n";
+    for _ in 0..20 {
+        // 6 个节点、3 个地雷：只有节点 0 及其两个邻居之外的位置可以放雷
+        let mut board = GraphBoard::new(parse(text), 3);
+        let result = board.left_click(0);
+
+        assert!(matches!(result, ClickResult::Victory));
+        for node in [3, 4, 5] {
+            assert!(matches!(board.spoiler().content(node), CellContent::Mine(_)));
+        }
+    }
+}
+
+#[test]
+fn test_small_graph_falls_back_to_protecting_clicked_node() {
+    // 星形图：中心与所有节点相邻，禁区放不下地雷时只保护被点击的节点
+    let text = "node 0\nnode 1\nnode 2\nnode 3\nedge 0 1\nedge 0 2\nedge 0 3\n";
+    let mut board = GraphBoard::new(parse(text), 2);
+    let result = board.left_click(0);
+
+    assert!(!matches!(result, ClickResult::GameOver));
+    assert!(matches!(board.spoiler().content(0), CellContent::Number(2)));
+}
+
+#[test]
+fn test_flag_and_invalid_node() {
+    let mut board = GraphBoard::new(parse(PATH_AND_TRIANGLE), 1);
+    board.place_mines_for_test(&[2]);
+
+    assert!(matches!(board.right_click(2), ClickResult::Continue));
+    assert_eq!(board.get_cell_state(2), CellState::Flagged);
+    assert!(matches!(board.left_click(2), ClickResult::Invalid));
+    assert!(matches!(board.left_click(99), ClickResult::Invalid));
+    assert!(matches!(board.right_click(99), ClickResult::Invalid));
+}
+
+#[test]
+fn test_default_graph_file_loads() {
+    let graph = BoardGraph::load(graph::DEFAULT_FILE).expect("bundled graph should load");
+
+    assert_eq!(graph.node_count(), 20);
+    assert!((0..20).all(|node| graph.neighbours(node).len() == 3));
+    assert_eq!(graph_mine_count(&graph), 4);
+}
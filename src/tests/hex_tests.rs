@@ -0,0 +1,114 @@
+// 六边形棋盘测试
+//
+// 验证六边形相邻规则、轴向坐标转换以及基于 6 邻域的数字和自动展开
+
+use crate::config::{Difficulty, difficulty_to_hex_board_config};
+use crate::core::board::get_hex_adjacent_positions;
+use crate::core::{Board, BoardSize, CellContent, CellState, ClickResult, Position};
+
+#[test]
+fn test_hex_neighbours_interior_even_and_odd_rows() {
+    let size = BoardSize {
+        width: 5,
+        height: 5,
+    };
+
+    // 偶数行：左上、上、左、右、左下、下
+    let even = get_hex_adjacent_positions(Position { row: 2, col: 2 }, size, true);
+    assert_eq!(even.len(), 6);
+    for expected in [
+        Position { row: 1, col: 1 },
+        Position { row: 1, col: 2 },
+        Position { row: 2, col: 1 },
+        Position { row: 2, col: 3 },
+        Position { row: 3, col: 1 },
+        Position { row: 3, col: 2 },
+    ] {
+        assert!(even.contains(&expected), "缺少邻居 {:?}", expected);
+    }
+
+    // 奇数行：上、右上、左、右、下、右下
+    let odd = get_hex_adjacent_positions(Position { row: 1, col: 2 }, size, true);
+    assert_eq!(odd.len(), 6);
+    for expected in [
+        Position { row: 0, col: 2 },
+        Position { row: 0, col: 3 },
+        Position { row: 2, col: 3 },
+        Position { row: 2, col: 2 },
+    ] {
+        assert!(odd.contains(&expected), "缺少邻居 {:?}", expected);
+    }
+}
+
+#[test]
+fn test_hex_neighbours_at_corner_and_with_center() {
+    let size = BoardSize {
+        width: 4,
+        height: 4,
+    };
+
+    let corner = get_hex_adjacent_positions(Position { row: 0, col: 0 }, size, true);
+    assert_eq!(corner.len(), 2); // 右、下
+
+    let with_center = get_hex_adjacent_positions(Position { row: 0, col: 0 }, size, false);
+    assert_eq!(with_center.len(), 3);
+    assert!(with_center.contains(&Position { row: 0, col: 0 }));
+}
+
+#[test]
+fn test_axial_round_trip() {
+    for row in 0..6 {
+        for col in 0..6 {
+            let pos = Position { row, col };
+            let (q, r) = pos.to_axial();
+            assert_eq!(Position::from_axial(q, r), Some(pos));
+        }
+    }
+
+    // 超出棋盘左侧或上方的轴向坐标无效
+    assert_eq!(Position::from_axial(0, -1), None);
+    assert_eq!(Position::from_axial(-2, 2), None);
+}
+
+#[test]
+fn test_hex_numbers_count_six_neighbours() {
+    let config = difficulty_to_hex_board_config(Difficulty::Easy);
+    let mut board = Board::new(config);
+
+    // (1, 1) 在奇数行，(0, 0) 不是它的邻居，(0, 2) 是
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 2 }]);
+
+    assert!(matches!(
+        board.spoiler().content(Position { row: 1, col: 1 }),
+        CellContent::Number(1)
+    ));
+    assert!(matches!(
+        board.spoiler().content(Position { row: 0, col: 1 }),
+        CellContent::Number(2)
+    ));
+}
+
+#[test]
+fn test_hex_flood_fill_stops_at_numbers() {
+    let config = difficulty_to_hex_board_config(Difficulty::Easy);
+    let mut board = Board::new(config);
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+
+    // 只有一个地雷时，一次点击即可展开全部安全格子
+    let result = board.left_click(Position { row: 8, col: 8 });
+    assert!(matches!(result, ClickResult::Victory));
+
+    // 地雷的两个邻居是数字，会被翻开；地雷本身保持隐藏
+    assert!(matches!(
+        board.get_cell_state(Position { row: 0, col: 1 }),
+        CellState::Revealed
+    ));
+    assert!(matches!(
+        board.get_cell_state(Position { row: 1, col: 0 }),
+        CellState::Revealed
+    ));
+    assert!(matches!(
+        board.get_cell_state(Position { row: 0, col: 0 }),
+        CellState::Hidden
+    ));
+}
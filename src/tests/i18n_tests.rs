@@ -0,0 +1,125 @@
+// 多语言测试
+//
+// 验证各语言的消息表键相同、占位符个数一致，以及语言代码解析、选择优先级、参数替换和设置文件解析
+//
+// 这里不调用 set_locale：当前语言是全局状态，测试并行运行时会互相影响
+
+use crate::config::Settings;
+use crate::i18n::{Locale, fill, select_locale};
+use crate::render::RenderMode;
+use std::collections::HashSet;
+
+#[test]
+fn test_every_locale_has_the_same_keys() {
+    let reference: HashSet<&str> = Locale::ZhCn.keys().collect();
+    for locale in Locale::ALL {
+        let keys: HashSet<&str> = locale.keys().collect();
+        let missing: Vec<_> = reference.difference(&keys).collect();
+        let extra: Vec<_> = keys.difference(&reference).collect();
+        assert!(missing.is_empty(), "{} 缺少键: {:?}", locale.code(), missing);
+        assert!(extra.is_empty(), "{} 多出键: {:?}", locale.code(), extra);
+    }
+}
+
+#[test]
+fn test_keys_are_not_duplicated() {
+    for locale in Locale::ALL {
+        let mut seen = HashSet::new();
+        for key in locale.keys() {
+            assert!(seen.insert(key), "{} 中键 {} 重复", locale.code(), key);
+        }
+    }
+}
+
+#[test]
+fn test_placeholder_counts_match() {
+    for key in Locale::ZhCn.keys() {
+        let expected = Locale::ZhCn.lookup(key).unwrap().matches("{}").count();
+        for locale in Locale::ALL {
+            let text = locale.lookup(key).unwrap();
+            assert_eq!(
+                text.matches("{}").count(),
+                expected,
+                "{} 中 {} 的占位符个数不同",
+                locale.code(),
+                key
+            );
+        }
+    }
+}
+
+#[test]
+fn test_lookup_missing_key() {
+    assert_eq!(Locale::En.lookup("no.such.key"), None);
+    assert_eq!(Locale::En.lookup("difficulty.easy"), Some("Easy"));
+    assert_eq!(Locale::ZhCn.lookup("difficulty.easy"), Some("简单"));
+}
+
+#[test]
+fn test_parse_locale_codes() {
+    assert_eq!(Locale::parse("zh"), Some(Locale::ZhCn));
+    assert_eq!(Locale::parse("zh-CN"), Some(Locale::ZhCn));
+    assert_eq!(Locale::parse("zh_CN.UTF-8"), Some(Locale::ZhCn));
+    assert_eq!(Locale::parse("en"), Some(Locale::En));
+    assert_eq!(Locale::parse(" EN_us.utf8 "), Some(Locale::En));
+    assert_eq!(Locale::parse("en@euro"), Some(Locale::En));
+    assert_eq!(Locale::parse("fr_FR"), None);
+    assert_eq!(Locale::parse(""), None);
+    for locale in Locale::ALL {
+        assert_eq!(Locale::parse(locale.code()), Some(locale));
+    }
+}
+
+#[test]
+fn test_select_locale_priority() {
+    assert_eq!(select_locale(Some("en"), Some(Locale::ZhCn)), Locale::En);
+    assert_eq!(select_locale(Some("zh"), Some(Locale::En)), Locale::ZhCn);
+    assert_eq!(select_locale(None, Some(Locale::En)), Locale::En);
+    // 无法识别的命令行参数不会覆盖设置文件
+    assert_eq!(select_locale(Some("xx"), Some(Locale::En)), Locale::En);
+    assert_eq!(
+        select_locale(None, None),
+        Locale::from_env().unwrap_or(Locale::ZhCn)
+    );
+}
+
+#[test]
+fn test_fill_placeholders_in_order() {
+    assert_eq!(fill("{}x{}, {} mines", &[&9, &9, &10]), "9x9, 10 mines");
+    assert_eq!(fill("no placeholders", &[&1]), "no placeholders");
+    // 参数不足时保留多余的占位符
+    assert_eq!(fill("{} and {}", &[&"a"]), "a and {}");
+    assert_eq!(fill("{}{}", &[&"中", &"文"]), "中文");
+}
+
+#[test]
+fn test_settings_parse() {
+    let (settings, unrecognised) = Settings::parse("# 注释\n\nlang = en\n");
+    assert_eq!(settings.lang, Some(Locale::En));
+    assert!(unrecognised.is_empty());
+
+    let (settings, unrecognised) = Settings::parse("lang=zh_CN\ncolour = red\nlang = xx\nbad line\n");
+    assert_eq!(settings.lang, Some(Locale::ZhCn), "无效的值不覆盖之前的设置");
+    assert_eq!(
+        unrecognised,
+        vec![
+            (2, "colour = red".to_string()),
+            (3, "lang = xx".to_string()),
+            (4, "bad line".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_settings_render_mode() {
+    let (settings, unrecognised) = Settings::parse("render = compact\nrender = svg\n");
+    assert_eq!(settings.render, Some(RenderMode::Compact));
+    assert_eq!(unrecognised, vec![(2, "render = svg".to_string())]);
+}
+
+#[test]
+fn test_settings_missing_file_is_default() {
+    let (settings, unrecognised) = Settings::load("/nonexistent/minesweeper.conf");
+    assert_eq!(settings, Settings::default());
+    assert!(unrecognised.is_empty());
+}
@@ -0,0 +1,232 @@
+// 无限棋盘测试
+//
+// 验证区块的确定性生成、首次点击安全、跨区块洪水填充、得分统计、世界边界，以及视口在大坐标下的行列号对齐
+
+use crate::config::infinite::{CHUNK_SIZE, MAX_COORDINATE};
+use crate::core::{
+    CellContent, CellState, ChunkCoord, ClickResult, InfiniteBoard, InfiniteBoardConfig,
+    WorldPosition,
+};
+
+fn config(seed: u64, density: f64) -> InfiniteBoardConfig {
+    InfiniteBoardConfig { seed, density }
+}
+
+#[test]
+fn test_chunk_coordinates_handle_negative_positions() {
+    let size = CHUNK_SIZE as i64;
+    assert_eq!(
+        WorldPosition { row: 0, col: 0 }.chunk(),
+        ChunkCoord { row: 0, col: 0 }
+    );
+    assert_eq!(
+        WorldPosition { row: -1, col: size }.chunk(),
+        ChunkCoord { row: -1, col: 1 }
+    );
+    assert_eq!(
+        WorldPosition {
+            row: -size,
+            col: -size - 1
+        }
+        .chunk(),
+        ChunkCoord { row: -1, col: -2 }
+    );
+}
+
+#[test]
+fn test_same_seed_generates_same_world() {
+    let a = InfiniteBoard::new(config(42, 0.2));
+    let b = InfiniteBoard::new(config(42, 0.2));
+    let c = InfiniteBoard::new(config(43, 0.2));
+
+    let mut differs = false;
+    for row in -40..40 {
+        for col in -40..40 {
+            let pos = WorldPosition { row, col };
+            assert_eq!(a.spoiler().is_mine(pos), b.spoiler().is_mine(pos));
+            differs |= a.spoiler().is_mine(pos) != c.spoiler().is_mine(pos);
+        }
+    }
+    assert!(differs, "不同种子应该生成不同的地雷分布");
+}
+
+#[test]
+fn test_chunks_are_generated_on_demand() {
+    let mut board = InfiniteBoard::new(config(7, 0.2));
+    assert_eq!(board.generated_chunk_count(), 0);
+
+    // 查询不会生成区块
+    let _ = board.spoiler().content(WorldPosition { row: 100, col: 100 });
+    assert_eq!(board.generated_chunk_count(), 0);
+
+    let _ = board.left_click(WorldPosition { row: 0, col: 0 });
+    assert!(board.generated_chunk_count() > 0);
+}
+
+#[test]
+fn test_first_click_is_safe() {
+    for seed in 0..20 {
+        let mut board = InfiniteBoard::new(config(seed, 0.4));
+        let first = WorldPosition { row: 5, col: -3 };
+        let result = board.left_click(first);
+        assert!(matches!(result, ClickResult::Continue));
+        assert!(matches!(
+            board.spoiler().content(first),
+            CellContent::Number(0)
+        ));
+        assert!(board.score() >= 1);
+    }
+}
+
+#[test]
+fn test_flood_fill_crosses_chunk_boundaries() {
+    // 没有地雷时，一次点击会一直展开到单次上限，跨越大量区块
+    let mut board = InfiniteBoard::new(config(1, 0.0));
+    let result = board.left_click(WorldPosition { row: 0, col: 0 });
+    assert!(matches!(result, ClickResult::Continue));
+
+    let far = CHUNK_SIZE as i64 * 3;
+    assert_eq!(
+        board.get_cell_state(WorldPosition {
+            row: -far,
+            col: far
+        }),
+        CellState::Revealed
+    );
+    assert!(board.generated_chunk_count() > 9);
+}
+
+#[test]
+fn test_numbers_match_neighbouring_mines() {
+    let mut board = InfiniteBoard::new(config(99, 0.25));
+    let _ = board.left_click(WorldPosition { row: 0, col: 0 });
+
+    for row in -20..20 {
+        for col in -20..20 {
+            let pos = WorldPosition { row, col };
+            if board.get_cell_state(pos) != CellState::Revealed {
+                continue;
+            }
+            let expected = pos.neighbours().filter(|&adj| board.spoiler().is_mine(adj)).count();
+            match board.spoiler().content(pos) {
+                CellContent::Number(n) => assert_eq!(n as usize, expected),
+                CellContent::Mine(_) => panic!("自动展开不应揭示地雷 {:?}", pos),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hitting_a_mine_ends_game_and_keeps_score() {
+    let mut board = InfiniteBoard::new(config(3, 0.3));
+    let _ = board.left_click(WorldPosition { row: 0, col: 0 });
+    let score = board.score();
+
+    // 找一个不在安全区内的地雷
+    let mine = (-30..30)
+        .flat_map(|row| (-30..30).map(move |col| WorldPosition { row, col }))
+        .find(|&pos| board.spoiler().is_mine(pos))
+        .expect("密度 30% 时附近一定有地雷");
+
+    assert!(matches!(board.left_click(mine), ClickResult::GameOver));
+    assert_eq!(board.score(), score);
+}
+
+#[test]
+fn test_flags_on_infinite_board() {
+    let mut board = InfiniteBoard::new(config(5, 0.2));
+    let pos = WorldPosition {
+        row: -1000,
+        col: 2000,
+    };
+
+    assert!(matches!(board.right_click(pos), ClickResult::Continue));
+    assert_eq!(board.get_cell_state(pos), CellState::Flagged);
+    assert!(matches!(board.left_click(pos), ClickResult::Invalid));
+    assert!(matches!(board.right_click(pos), ClickResult::Continue));
+    assert_eq!(board.get_cell_state(pos), CellState::Hidden);
+}
+
+#[test]
+fn test_world_edge_rejects_extreme_coordinates() {
+    let mut board = InfiniteBoard::new(config(3, 0.1));
+    let far = WorldPosition {
+        row: i64::MAX,
+        col: 0,
+    };
+    assert!(!far.in_bounds());
+    assert!(matches!(board.left_click(far), ClickResult::Invalid));
+    assert!(matches!(board.right_click(far), ClickResult::Invalid));
+    assert_eq!(board.generated_chunk_count(), 0);
+
+    // 边界上的格子可以点击，相邻格子不越过边界
+    let edge = WorldPosition {
+        row: MAX_COORDINATE,
+        col: -MAX_COORDINATE,
+    };
+    assert!(!matches!(board.left_click(edge), ClickResult::Invalid));
+    assert_eq!(edge.neighbours().count(), 3);
+    assert!(edge.neighbours().all(WorldPosition::in_bounds));
+    assert_eq!(far.neighbours().count(), 0);
+}
+
+#[test]
+fn test_offset_saturates_at_world_edge() {
+    let origin = WorldPosition { row: 0, col: 0 };
+    assert_eq!(origin.offset(-3, 5), WorldPosition { row: -3, col: 5 });
+    assert_eq!(
+        origin.offset(i64::MAX, i64::MIN),
+        WorldPosition {
+            row: MAX_COORDINATE,
+            col: -MAX_COORDINATE,
+        }
+    );
+    let edge = origin.offset(i64::MAX, 0);
+    assert_eq!(edge.offset(i64::MAX, 0), edge);
+}
+
+// 去掉颜色代码后按行拆开视口输出
+fn viewport_lines(board: &InfiniteBoard, top_left: WorldPosition) -> Vec<String> {
+    let mut out = Vec::new();
+    board.write_viewport(&mut out, top_left, 4, 3).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    let mut plain = String::new();
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            plain.push(ch);
+        }
+    }
+    plain.lines().map(str::to_string).collect()
+}
+
+#[test]
+fn test_viewport_labels_align_near_world_edge() {
+    let board = InfiniteBoard::new(config(1, 0.2));
+    for top_left in [
+        WorldPosition { row: -2, col: -2 },
+        WorldPosition {
+            row: -MAX_COORDINATE,
+            col: MAX_COORDINATE - 3,
+        },
+    ] {
+        let lines = viewport_lines(&board, top_left);
+        let (header, grid) = lines.split_at(lines.len() - 3);
+        let width = grid[0].chars().count();
+        assert!(grid.iter().all(|line| line.chars().count() == width), "{:?}", lines);
+
+        // 每列坐标的最后一个字符与该列的图标在同一列
+        let bottom: Vec<char> = header.last().unwrap().chars().collect();
+        let icons: Vec<char> = grid[0].chars().collect();
+        for dc in 0..4 {
+            let column = width - 1 - 4 * (3 - dc);
+            let col = top_left.col + dc as i64;
+            assert_eq!(Some(icons[column]), Some('□'));
+            assert_eq!(bottom.get(column), col.to_string().chars().last().as_ref());
+        }
+        // 行号完整显示
+        assert!(grid[0].trim_start().starts_with(&top_left.row.to_string()));
+    }
+}
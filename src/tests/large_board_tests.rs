@@ -0,0 +1,157 @@
+// 紧凑存储与大棋盘测试
+//
+// 验证扁平存储下的增量数字计算、两种布雷抽样方式、内部/边缘两条相邻路径结果一致，
+// 以及稠密大棋盘上的布雷和展开（较慢，默认忽略）
+
+use crate::core::{
+    Board, BoardConfig, BoardShape, BoardSize, CellContent, CellState, ClickResult, Neighbourhood,
+    Position,
+};
+
+fn config(width: usize, height: usize, mine_count: usize, shape: BoardShape) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize { width, height },
+        mine_count,
+        shape,
+        neighbourhood: Neighbourhood::Moore,
+        mines_per_cell: 1,
+        negative_mines: 0,
+    }
+}
+
+// 用最朴素的方式重新数一遍，和增量计算的结果对比
+fn assert_numbers_match_brute_force(board: &Board) {
+    let config = *board.get_board_config();
+    for row in 0..config.board_size.height {
+        for col in 0..config.board_size.width {
+            let pos = Position { row, col };
+            if let CellContent::Number(n) = board.spoiler().content(pos) {
+                let expected = config
+                    .adjacent_positions(pos, true)
+                    .into_iter()
+                    .filter(|&adj| matches!(board.spoiler().content(adj), CellContent::Mine(_)))
+                    .count();
+                assert_eq!(n as usize, expected, "位置 ({}, {}) 的数字错误", row, col);
+            }
+        }
+    }
+}
+
+fn assert_first_click_area_is_safe(board: &Board, first: Position) {
+    for adj in board.get_board_config().adjacent_positions(first, false) {
+        assert!(!matches!(board.spoiler().content(adj), CellContent::Mine(_)));
+    }
+}
+
+#[test]
+fn test_neighbours_iterator_matches_adjacent_positions() {
+    let config = config(7, 5, 0, BoardShape::Hex);
+    for row in 0..5 {
+        for col in 0..7 {
+            let pos = Position { row, col };
+            let collected: Vec<Position> = config.neighbours(pos, true).collect();
+            assert_eq!(collected, config.adjacent_positions(pos, true));
+        }
+    }
+}
+
+#[test]
+fn test_sparse_placement_numbers_and_mine_count() {
+    for shape in [BoardShape::Square, BoardShape::Hex] {
+        let mut board = Board::new(config(120, 80, 300, shape));
+        let first = Position { row: 0, col: 119 };
+        let _ = board.left_click(first);
+
+        assert_eq!(board.count_mines(), 300);
+        assert_first_click_area_is_safe(&board, first);
+        assert_numbers_match_brute_force(&board);
+    }
+}
+
+#[test]
+fn test_dense_placement_numbers_and_mine_count() {
+    for shape in [BoardShape::Square, BoardShape::Hex] {
+        let mut board = Board::new(config(40, 30, 1000, shape));
+        let first = Position { row: 15, col: 20 };
+        let result = board.left_click(first);
+
+        assert!(!matches!(result, ClickResult::GameOver));
+        assert_eq!(board.count_mines(), 1000);
+        assert_first_click_area_is_safe(&board, first);
+        assert_numbers_match_brute_force(&board);
+    }
+}
+
+#[test]
+fn test_board_filled_except_first_click_area() {
+    // 只有首次点击的 3x3 区域是安全的
+    let mut board = Board::new(config(10, 10, 91, BoardShape::Square));
+    let result = board.left_click(Position { row: 5, col: 5 });
+
+    assert!(matches!(result, ClickResult::Victory));
+    assert_eq!(board.get_revealed_count(), 9);
+}
+
+#[test]
+fn test_large_board_flood_fill() {
+    let mut board = Board::new(config(1000, 1000, 0, BoardShape::Square));
+    let result = board.left_click(Position { row: 500, col: 500 });
+
+    // 没有地雷时一次点击翻开整块棋盘
+    assert!(matches!(result, ClickResult::Victory));
+    assert_eq!(board.get_revealed_count(), 1_000_000);
+    assert_eq!(
+        board.get_cell_state(Position { row: 999, col: 0 }),
+        CellState::Revealed
+    );
+}
+
+#[test]
+fn test_flood_fill_skips_flags_on_flat_storage() {
+    let mut board = Board::new(config(20, 20, 0, BoardShape::Square));
+    let flagged = Position { row: 0, col: 19 };
+    let _ = board.right_click(flagged);
+
+    let result = board.left_click(Position { row: 10, col: 10 });
+    assert!(matches!(result, ClickResult::Continue));
+    assert_eq!(board.get_cell_state(flagged), CellState::Flagged);
+    assert_eq!(board.get_revealed_count(), 399);
+}
+
+#[test]
+#[ignore = "大棋盘上逐格校验较慢，用 cargo test -- --ignored 运行"]
+fn test_dense_large_board_placement_and_flood_fill() {
+    // 400 万格、160 万个雷，走稠密抽样路径
+    let mut board = Board::new(config(2000, 2000, 1_600_000, BoardShape::Square));
+    let first = Position { row: 1000, col: 1000 };
+    let result = board.left_click(first);
+
+    assert!(!matches!(result, ClickResult::GameOver));
+    assert_eq!(board.count_mines(), 1_600_000);
+    assert_first_click_area_is_safe(&board, first);
+    assert_numbers_match_brute_force(&board);
+
+    // 展开停在数字上：翻开的空白格周围全部翻开，且没有翻开任何地雷
+    let config = *board.get_board_config();
+    let mut revealed = 0;
+    for row in 0..2000 {
+        for col in 0..2000 {
+            let pos = Position { row, col };
+            if board.get_cell_state(pos) != CellState::Revealed {
+                continue;
+            }
+            revealed += 1;
+            match board.spoiler().content(pos) {
+                CellContent::Mine(_) => panic!("位置 ({}, {}) 的地雷被翻开", row, col),
+                CellContent::Number(0) => {
+                    for adj in config.neighbours(pos, true) {
+                        assert_eq!(board.get_cell_state(adj), CellState::Revealed);
+                    }
+                }
+                CellContent::Number(_) => {}
+            }
+        }
+    }
+    assert_eq!(revealed, board.get_revealed_count());
+    assert!(revealed >= 9);
+}
@@ -0,0 +1,128 @@
+// 生命模式测试
+//
+// 验证生命计数、踩雷后的自动标记、标记后的地雷不影响胜利判定且保持锁定，
+// 以及游戏在生命耗尽时才结束
+
+use crate::support::small_config;
+use crate::config::{Difficulty, difficulty_to_board_config};
+use crate::core::{Board, CellState, ClickResult, Game, Lives, Position};
+
+#[test]
+fn test_lives_count_down_to_zero() {
+    let mut lives = Lives::new(3);
+    assert_eq!(lives.remaining(), 3);
+
+    assert!(lives.lose_one());
+    assert!(lives.lose_one());
+    assert_eq!(lives.spent(), 2);
+    assert_eq!(lives.remaining(), 1);
+
+    // 最后一条生命用完即失败，之后不会再多扣
+    assert!(!lives.lose_one());
+    assert!(!lives.lose_one());
+    assert_eq!(lives.spent(), 3);
+    assert_eq!(lives.remaining(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_lives_is_rejected() {
+    let _ = Lives::new(0);
+}
+
+#[test]
+fn test_game_lives_default_to_one() {
+    let game = Game::new(Difficulty::Easy);
+    assert_eq!(game.lives(), Lives::new(1));
+
+    let game = Game::with_lives(difficulty_to_board_config(Difficulty::Easy), 5);
+    assert_eq!(game.lives().total(), 5);
+    assert_eq!(game.lives().spent(), 0);
+}
+
+#[test]
+fn test_triggered_mine_is_flagged() {
+    let mut board = Board::new(small_config(3, 3));
+    let mine = Position { row: 0, col: 0 };
+    board.place_mines_for_test(&[mine]);
+
+    assert!(matches!(board.left_click(mine), ClickResult::GameOver));
+    board.flag_triggered_mine(mine);
+
+    assert_eq!(board.get_cell_state(mine), CellState::Flagged);
+    assert_eq!(board.get_flag_count(mine), 1);
+    // 已标记的地雷不能再次点击
+    assert!(matches!(board.left_click(mine), ClickResult::Invalid));
+    // 也不能拔旗
+    assert!(matches!(board.right_click(mine), ClickResult::Invalid));
+    assert_eq!(board.get_cell_state(mine), CellState::Flagged);
+}
+
+#[test]
+fn test_flag_triggered_mine_ignores_safe_and_hidden_cells() {
+    let mut board = Board::new(small_config(3, 3));
+    let mine = Position { row: 0, col: 0 };
+    board.place_mines_for_test(&[mine]);
+
+    // 尚未踩中的地雷和翻开的数字格子都保持原状
+    board.flag_triggered_mine(mine);
+    assert_eq!(board.get_cell_state(mine), CellState::Hidden);
+
+    let safe = Position { row: 1, col: 1 };
+    let _ = board.left_click(safe);
+    board.flag_triggered_mine(safe);
+    assert_eq!(board.get_cell_state(safe), CellState::Revealed);
+}
+
+#[test]
+fn test_victory_after_surviving_a_mine_hit() {
+    let mut board = Board::new(small_config(3, 3));
+    let mine = Position { row: 0, col: 0 };
+    board.place_mines_for_test(&[mine]);
+
+    assert!(matches!(board.left_click(mine), ClickResult::GameOver));
+    board.flag_triggered_mine(mine);
+
+    assert!(matches!(
+        board.left_click(Position { row: 2, col: 2 }),
+        ClickResult::Victory
+    ));
+    assert_eq!(board.get_revealed_count(), 8);
+}
+
+#[test]
+fn test_game_continues_until_lives_run_out() {
+    let mut game = Game::with_lives(small_config(3, 3), 2);
+    let first = Position { row: 0, col: 0 };
+    let second = Position { row: 0, col: 1 };
+    game.place_mines_for_test(&[first, second]);
+
+    // 第一次踩雷：自动插旗、扣一条生命，游戏继续
+    assert!(game.command_for_test("click 0 0"));
+    assert!(!game.is_over_for_test());
+    assert_eq!(game.lives().remaining(), 1);
+    assert_eq!(game.board_for_test().get_cell_state(first), CellState::Flagged);
+
+    // 生命用完时游戏结束
+    assert!(game.command_for_test("click 0 1"));
+    assert!(game.is_over_for_test());
+    assert_eq!(game.lives().remaining(), 0);
+    assert_eq!(game.board_for_test().get_cell_state(second), CellState::Revealed);
+}
+
+#[test]
+fn test_triggered_mine_stays_locked() {
+    let mut game = Game::with_lives(small_config(3, 3), 3);
+    let mine = Position { row: 0, col: 0 };
+    game.place_mines_for_test(&[mine, Position { row: 2, col: 2 }]);
+
+    assert!(game.command_for_test("click 0 0"));
+    assert_eq!(game.lives().remaining(), 2);
+
+    // 拔旗和再次点击都无效，不会因为同一颗地雷再扣生命
+    assert!(game.command_for_test("flag 0 0"));
+    assert_eq!(game.board_for_test().get_cell_state(mine), CellState::Flagged);
+    assert!(game.command_for_test("click 0 0"));
+    assert_eq!(game.lives().remaining(), 2);
+    assert!(!game.is_over_for_test());
+}
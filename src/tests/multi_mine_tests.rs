@@ -0,0 +1,139 @@
+// 多雷格子测试
+//
+// 验证每格多颗地雷时的数字求和、布雷上限、旗子数循环和胜利判定
+
+use crate::config::multi_mine_board_config;
+use crate::core::{
+    Board, BoardConfig, BoardShape, BoardSize, CellContent, CellState, ClickResult,
+    Neighbourhood, Position,
+};
+
+fn config(size: usize, mine_count: usize, mines_per_cell: u8) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize {
+            width: size,
+            height: size,
+        },
+        mine_count,
+        shape: BoardShape::Square,
+        neighbourhood: Neighbourhood::Moore,
+        mines_per_cell,
+        negative_mines: 0,
+    }
+}
+
+fn mines_at(board: &Board, pos: Position) -> usize {
+    match board.spoiler().content(pos) {
+        CellContent::Mine(count) => count as usize,
+        CellContent::Number(_) => 0,
+    }
+}
+
+fn all_positions(size: usize) -> impl Iterator<Item = Position> {
+    (0..size).flat_map(move |row| (0..size).map(move |col| Position { row, col }))
+}
+
+#[test]
+fn test_numbers_sum_stacked_mines() {
+    let mut board = Board::new(config(4, 3, 3));
+    let stacked = Position { row: 0, col: 0 };
+    board.place_mines_for_test(&[stacked, stacked, Position { row: 0, col: 2 }]);
+
+    assert!(matches!(board.spoiler().content(stacked), CellContent::Mine(2)));
+    assert!(matches!(
+        board.spoiler().content(Position { row: 1, col: 1 }),
+        CellContent::Number(3)
+    ));
+    assert!(matches!(
+        board.spoiler().content(Position { row: 1, col: 0 }),
+        CellContent::Number(2)
+    ));
+}
+
+#[test]
+fn test_random_placement_respects_limits() {
+    for _ in 0..20 {
+        let mut board = Board::new(config(8, 40, 3));
+        let first = Position { row: 4, col: 4 };
+        let result = board.left_click(first);
+        assert!(!matches!(result, ClickResult::GameOver));
+
+        let total: usize = all_positions(8).map(|pos| mines_at(&board, pos)).sum();
+        assert_eq!(total, 40);
+        assert!(all_positions(8).all(|pos| mines_at(&board, pos) <= 3));
+
+        let board_config = *board.get_board_config();
+        for adj in board_config.neighbours(first, false) {
+            assert_eq!(mines_at(&board, adj), 0);
+        }
+        for pos in all_positions(8) {
+            if let CellContent::Number(n) = board.spoiler().content(pos) {
+                let expected: usize = board_config
+                    .neighbours(pos, true)
+                    .map(|adj| mines_at(&board, adj))
+                    .sum();
+                assert_eq!(n as usize, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_every_slot_filled_when_mines_reach_capacity() {
+    // 5x5 棋盘去掉 3x3 安全区剩 16 格，每格 2 颗地雷正好 32 颗
+    let mut board = Board::new(config(5, 32, 2));
+    let result = board.left_click(Position { row: 2, col: 2 });
+
+    assert!(matches!(result, ClickResult::Victory));
+    assert!(matches!(
+        board.spoiler().content(Position { row: 0, col: 0 }),
+        CellContent::Mine(2)
+    ));
+    assert!(matches!(
+        board.spoiler().content(Position { row: 2, col: 2 }),
+        CellContent::Number(0)
+    ));
+}
+
+#[test]
+fn test_right_click_cycles_flag_counts() {
+    let mut board = Board::new(config(3, 1, 3));
+    let pos = Position { row: 1, col: 1 };
+
+    for expected in [1, 2, 3] {
+        assert!(matches!(board.right_click(pos), ClickResult::Continue));
+        assert_eq!(board.get_cell_state(pos), CellState::Flagged);
+        assert_eq!(board.get_flag_count(pos), expected);
+    }
+
+    // 超过上限后回到未标记
+    assert!(matches!(board.right_click(pos), ClickResult::Continue));
+    assert_eq!(board.get_cell_state(pos), CellState::Hidden);
+    assert_eq!(board.get_flag_count(pos), 0);
+}
+
+#[test]
+fn test_victory_counts_mine_cells_not_mines() {
+    let mut board = Board::new(config(3, 3, 3));
+    let corner = Position { row: 0, col: 0 };
+    board.place_mines_for_test(&[corner, corner, corner]);
+
+    // 3 颗地雷都在一个格子里，翻开其余 8 格即胜利
+    let result = board.left_click(Position { row: 2, col: 2 });
+    assert!(matches!(result, ClickResult::Victory));
+    assert_eq!(board.get_revealed_count(), 8);
+}
+
+#[test]
+fn test_multi_mine_config_allows_larger_numbers() {
+    let board_config = multi_mine_board_config();
+
+    assert_eq!(board_config.mines_per_cell, 3);
+    assert_eq!(board_config.max_adjacent_mines(), 24);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_mines_per_cell_is_rejected() {
+    let _ = Board::new(config(3, 1, 0));
+}
@@ -0,0 +1,166 @@
+// 负雷模式测试
+//
+// 验证负雷让数字变小、数字为 0 时的展开规则、正负旗循环和随机布雷
+
+use crate::config::negative_mine_board_config;
+use crate::core::{
+    Board, BoardConfig, BoardShape, BoardSize, CellContent, CellState, ClickResult,
+    Neighbourhood, Position,
+};
+
+fn config(size: usize, mine_count: usize, negative_mines: usize) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize {
+            width: size,
+            height: size,
+        },
+        mine_count,
+        shape: BoardShape::Square,
+        neighbourhood: Neighbourhood::Moore,
+        mines_per_cell: 1,
+        negative_mines,
+    }
+}
+
+fn number_at(board: &Board, row: usize, col: usize) -> i16 {
+    match board.spoiler().content(Position { row, col }) {
+        CellContent::Number(n) => n,
+        CellContent::Mine(_) => panic!("({}, {}) 是地雷", row, col),
+    }
+}
+
+#[test]
+fn test_negative_mines_subtract_from_numbers() {
+    let mut board = Board::new(config(4, 0, 0));
+    board.place_negative_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 2 }]);
+
+    assert!(matches!(
+        board.spoiler().content(Position { row: 0, col: 0 }),
+        CellContent::Mine(-1)
+    ));
+    assert_eq!(number_at(&board, 1, 1), -2);
+    assert_eq!(number_at(&board, 1, 3), -1);
+    assert_eq!(number_at(&board, 3, 3), 0);
+}
+
+#[test]
+fn test_positive_and_negative_mines_cancel_out() {
+    let mut board = Board::new(config(3, 1, 0));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    board.place_negative_mines_for_test(&[Position { row: 0, col: 2 }]);
+
+    // (0, 1) 和 (1, 1) 周围一正一负，数字为 0
+    assert_eq!(number_at(&board, 0, 1), 0);
+    assert_eq!(number_at(&board, 1, 1), 0);
+    assert_eq!(number_at(&board, 1, 0), 1);
+    assert_eq!(number_at(&board, 1, 2), -1);
+
+    // 数字为 0 但周围有地雷：只翻开自己，不自动展开
+    let result = board.left_click(Position { row: 1, col: 1 });
+    assert!(matches!(result, ClickResult::Continue));
+    assert_eq!(board.get_revealed_count(), 1);
+
+    // 真正的空白格子仍然会展开，只剩 (0, 1) 未翻开
+    let result = board.left_click(Position { row: 2, col: 1 });
+    assert!(matches!(result, ClickResult::Continue));
+    assert_eq!(board.get_revealed_count(), 6);
+    assert_eq!(
+        board.get_cell_state(Position { row: 0, col: 1 }),
+        CellState::Hidden
+    );
+}
+
+#[test]
+fn test_right_click_cycles_positive_then_negative_flag() {
+    let mut board = Board::new(config(3, 2, 1));
+    let pos = Position { row: 1, col: 1 };
+
+    for expected in [1, -1] {
+        assert!(matches!(board.right_click(pos), ClickResult::Continue));
+        assert_eq!(board.get_cell_state(pos), CellState::Flagged);
+        assert_eq!(board.get_flag_count(pos), expected);
+    }
+
+    assert!(matches!(board.right_click(pos), ClickResult::Continue));
+    assert_eq!(board.get_cell_state(pos), CellState::Hidden);
+    assert_eq!(board.get_flag_count(pos), 0);
+}
+
+#[test]
+fn test_classic_right_click_has_no_negative_flag() {
+    let mut board = Board::new(config(3, 1, 0));
+    let pos = Position { row: 1, col: 1 };
+
+    assert!(matches!(board.right_click(pos), ClickResult::Continue));
+    assert_eq!(board.get_flag_count(pos), 1);
+    assert!(matches!(board.right_click(pos), ClickResult::Continue));
+    assert_eq!(board.get_cell_state(pos), CellState::Hidden);
+}
+
+#[test]
+fn test_random_placement_marks_negative_mines() {
+    for _ in 0..20 {
+        let mut board = Board::new(config(10, 30, 8));
+        let first = Position { row: 5, col: 5 };
+        let result = board.left_click(first);
+        assert!(!matches!(result, ClickResult::GameOver));
+
+        let board_config = *board.get_board_config();
+        let mut positive = 0;
+        let mut negative = 0;
+        for row in 0..10 {
+            for col in 0..10 {
+                let pos = Position { row, col };
+                match board.spoiler().content(pos) {
+                    CellContent::Mine(count) if count < 0 => negative += 1,
+                    CellContent::Mine(_) => positive += 1,
+                    CellContent::Number(n) => {
+                        let expected: i16 = board_config
+                            .neighbours(pos, true)
+                            .map(|adj| match board.spoiler().content(adj) {
+                                CellContent::Mine(count) => i16::from(count),
+                                CellContent::Number(_) => 0,
+                            })
+                            .sum();
+                        assert_eq!(n, expected);
+                    }
+                }
+            }
+        }
+        assert_eq!(positive, 22);
+        assert_eq!(negative, 8);
+    }
+}
+
+#[test]
+fn test_victory_requires_avoiding_negative_mines() {
+    let corner = Position { row: 0, col: 0 };
+
+    // 负雷同样是地雷，踩中即失败
+    let mut lost = Board::new(config(3, 0, 0));
+    lost.place_negative_mines_for_test(&[corner]);
+    assert!(matches!(lost.left_click(corner), ClickResult::GameOver));
+
+    let mut board = Board::new(config(3, 0, 0));
+    board.place_negative_mines_for_test(&[corner]);
+    // 对角的空白格子展开后翻开全部 8 个安全格子
+    assert!(matches!(
+        board.left_click(Position { row: 2, col: 2 }),
+        ClickResult::Victory
+    ));
+    assert_eq!(board.get_revealed_count(), 8);
+}
+
+#[test]
+fn test_negative_mine_config() {
+    let board_config = negative_mine_board_config();
+
+    assert_eq!(board_config.negative_mines, 12);
+    assert!(board_config.negative_mines <= board_config.mine_count);
+}
+
+#[test]
+#[should_panic]
+fn test_more_negative_mines_than_mines_is_rejected() {
+    let _ = Board::new(config(3, 1, 2));
+}
@@ -0,0 +1,148 @@
+// 自定义相邻规则测试
+//
+// 验证正交、马步、半径 2 和自定义偏移下的数字、自动展开与首次点击安全区
+
+use crate::core::{
+    Board, BoardConfig, BoardShape, BoardSize, CellContent, CellState, ClickResult,
+    CustomOffsets, Neighbourhood, Position,
+};
+
+fn config(size: usize, mine_count: usize, neighbourhood: Neighbourhood) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize {
+            width: size,
+            height: size,
+        },
+        mine_count,
+        shape: BoardShape::Square,
+        neighbourhood,
+        mines_per_cell: 1,
+        negative_mines: 0,
+    }
+}
+
+fn number_at(board: &Board, row: usize, col: usize) -> i16 {
+    match board.spoiler().content(Position { row, col }) {
+        CellContent::Number(n) => n,
+        CellContent::Mine(_) => panic!("({}, {}) 是地雷", row, col),
+    }
+}
+
+#[test]
+fn test_orthogonal_numbers_ignore_diagonals() {
+    let mut board = Board::new(config(3, 1, Neighbourhood::Orthogonal));
+    board.place_mines_for_test(&[Position { row: 1, col: 1 }]);
+
+    assert_eq!(number_at(&board, 0, 1), 1);
+    assert_eq!(number_at(&board, 1, 2), 1);
+    assert_eq!(number_at(&board, 0, 0), 0);
+    assert_eq!(number_at(&board, 2, 2), 0);
+}
+
+#[test]
+fn test_knight_numbers_and_flood_fill() {
+    let mut board = Board::new(config(3, 1, Neighbourhood::Knight));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+
+    assert_eq!(number_at(&board, 1, 2), 1);
+    assert_eq!(number_at(&board, 2, 1), 1);
+    assert_eq!(number_at(&board, 0, 1), 0);
+
+    // 3x3 棋盘的中心没有马步邻居，空白格子也不会展开到别处
+    let result = board.left_click(Position { row: 1, col: 1 });
+    assert!(matches!(result, ClickResult::Continue));
+    assert_eq!(board.get_revealed_count(), 1);
+
+    // (0, 1) 是空白格子：沿马步一路展开，翻开全部剩余的安全格子
+    let result = board.left_click(Position { row: 0, col: 1 });
+    assert!(matches!(result, ClickResult::Victory));
+    assert_eq!(
+        board.get_cell_state(Position { row: 2, col: 2 }),
+        CellState::Revealed
+    );
+}
+
+#[test]
+fn test_radius2_supports_numbers_up_to_24() {
+    let board_config = config(5, 24, Neighbourhood::Radius2);
+    assert_eq!(board_config.max_adjacent_mines(), 24);
+
+    let center = Position { row: 2, col: 2 };
+    let mines: Vec<Position> = (0..5)
+        .flat_map(|row| (0..5).map(move |col| Position { row, col }))
+        .filter(|&pos| pos != center)
+        .collect();
+    let mut board = Board::new(board_config);
+    board.place_mines_for_test(&mines);
+
+    assert_eq!(number_at(&board, 2, 2), 24);
+    assert!(matches!(board.left_click(center), ClickResult::Victory));
+}
+
+#[test]
+fn test_first_click_zone_follows_radius2() {
+    // 7x7 棋盘、24 个地雷：中心的 5x5 安全区之外恰好 24 个格子
+    for _ in 0..10 {
+        let mut board = Board::new(config(7, 24, Neighbourhood::Radius2));
+        let result = board.left_click(Position { row: 3, col: 3 });
+
+        assert!(matches!(result, ClickResult::Victory));
+        assert_eq!(board.get_revealed_count(), 25);
+    }
+}
+
+#[test]
+fn test_custom_offsets_construction() {
+    let custom = CustomOffsets::parse("-1,0 1,0 0,0 -1,0 0,-2 0,2").expect("valid offsets");
+    // 中心和重复项被忽略
+    assert_eq!(custom.neighbour_count(), 4);
+    assert_eq!(Neighbourhood::Custom(custom).max_neighbours(), 4);
+
+    assert!(CustomOffsets::parse("").is_none());
+    assert!(CustomOffsets::parse("0,0").is_none());
+    assert!(CustomOffsets::parse("1;0").is_none());
+
+    let too_many: Vec<(isize, isize)> = (1..=49).map(|dc| (0, dc)).collect();
+    assert!(CustomOffsets::new(&too_many).is_none());
+    assert!(CustomOffsets::new(&too_many[..48]).is_some());
+}
+
+#[test]
+fn test_custom_offsets_are_directional() {
+    // 只看右边两格：数字不对称
+    let custom = CustomOffsets::new(&[(0, 1), (0, 2)]).unwrap();
+    let mut board = Board::new(config(4, 1, Neighbourhood::Custom(custom)));
+    board.place_mines_for_test(&[Position { row: 0, col: 2 }]);
+
+    assert_eq!(number_at(&board, 0, 0), 1);
+    assert_eq!(number_at(&board, 0, 1), 1);
+    assert_eq!(number_at(&board, 0, 3), 0);
+}
+
+#[test]
+fn test_numbers_match_brute_force_for_every_rule() {
+    let custom = CustomOffsets::new(&[(-3, 0), (3, 0), (0, -3), (0, 3), (1, 1)]).unwrap();
+    for neighbourhood in [
+        Neighbourhood::Orthogonal,
+        Neighbourhood::Knight,
+        Neighbourhood::Radius2,
+        Neighbourhood::Custom(custom),
+    ] {
+        let mut board = Board::new(config(30, 200, neighbourhood));
+        let _ = board.left_click(Position { row: 15, col: 15 });
+        let board_config = *board.get_board_config();
+
+        for row in 0..30 {
+            for col in 0..30 {
+                let pos = Position { row, col };
+                if let CellContent::Number(n) = board.spoiler().content(pos) {
+                    let expected = board_config
+                        .neighbours(pos, true)
+                        .filter(|&adj| matches!(board.spoiler().content(adj), CellContent::Mine(_)))
+                        .count();
+                    assert_eq!(n as usize, expected, "{:?} ({}, {})", neighbourhood, row, col);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// 只读查询测试
+//
+// 验证查询只返回可见信息、旗子和剩余地雷计数、相邻格子，Spoiler 给出的真实布局和插错的旗，
+// 以及保留兼容的 get_cell_content 与 Spoiler 一致
+
+use crate::config::{Difficulty, difficulty_to_board_config, multi_mine_board_config};
+use crate::core::{Board, BoardConfig, BoardShape, CellContent, CellState, Position, VisibleCell};
+
+fn mined_board() -> Board {
+    let mut board = Board::new(difficulty_to_board_config(Difficulty::Easy));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 2 }]);
+    board
+}
+
+#[test]
+fn test_cells_only_show_visible_content() {
+    let mut board = mined_board();
+    // 未翻开时看不出地雷
+    assert!(
+        board
+            .cells()
+            .all(|(_, state, cell)| state == CellState::Hidden && cell == VisibleCell::Hidden)
+    );
+    assert_eq!(board.hidden_cells().count(), 81);
+
+    board.left_click(Position { row: 1, col: 1 });
+    board.right_click(Position { row: 0, col: 0 });
+    let cells: Vec<_> = board.cells().collect();
+    assert_eq!(cells.len(), 81);
+    assert_eq!(cells[0].0, Position { row: 0, col: 0 });
+    assert_eq!(cells[0].2, VisibleCell::Flagged);
+    assert_eq!(cells[2].2, VisibleCell::Hidden);
+    assert_eq!(
+        board.get_visible_cell(Position { row: 1, col: 1 }),
+        VisibleCell::Number(2)
+    );
+    assert_eq!(board.hidden_cells().count(), 79);
+    assert_eq!(
+        board.flagged_cells().collect::<Vec<_>>(),
+        vec![Position { row: 0, col: 0 }]
+    );
+}
+
+#[test]
+fn test_flag_and_remaining_mine_counts() {
+    let mut board = mined_board();
+    assert_eq!(board.flags_placed(), 0);
+    assert_eq!(board.remaining_mines(), 2);
+
+    board.right_click(Position { row: 0, col: 0 });
+    board.right_click(Position { row: 5, col: 5 });
+    board.right_click(Position { row: 6, col: 6 });
+    assert_eq!(board.flags_placed(), 3);
+    // 插旗过多时为负数
+    assert_eq!(board.remaining_mines(), -1);
+
+    // 多雷格子按旗子数计
+    let mut board = Board::new(multi_mine_board_config());
+    let pos = Position { row: 3, col: 3 };
+    board.right_click(pos);
+    board.right_click(pos);
+    assert_eq!(board.flags_placed(), 2);
+    assert_eq!(
+        board.remaining_mines(),
+        multi_mine_board_config().mine_count as isize - 2
+    );
+}
+
+#[test]
+fn test_neighbours_follow_board_shape() {
+    let board = Board::new(difficulty_to_board_config(Difficulty::Easy));
+    assert_eq!(board.neighbours(Position { row: 0, col: 0 }).count(), 3);
+    assert_eq!(board.neighbours(Position { row: 4, col: 4 }).count(), 8);
+    assert!(
+        !board
+            .neighbours(Position { row: 4, col: 4 })
+            .any(|pos| pos == Position { row: 4, col: 4 })
+    );
+
+    let hex = Board::new(BoardConfig {
+        shape: BoardShape::Hex,
+        ..difficulty_to_board_config(Difficulty::Easy)
+    });
+    assert_eq!(hex.neighbours(Position { row: 4, col: 4 }).count(), 6);
+}
+
+#[test]
+fn test_spoiler_reveals_layout_and_wrong_flags() {
+    let mut board = mined_board();
+    let spoiler = board.spoiler();
+    assert!(spoiler.is_mine(Position { row: 0, col: 0 }));
+    assert!(!spoiler.is_mine(Position { row: 0, col: 1 }));
+    assert_eq!(
+        spoiler.mines().collect::<Vec<_>>(),
+        vec![
+            (Position { row: 0, col: 0 }, 1),
+            (Position { row: 0, col: 2 }, 1)
+        ]
+    );
+
+    board.right_click(Position { row: 0, col: 0 });
+    board.right_click(Position { row: 0, col: 1 });
+    assert_eq!(
+        board.spoiler().wrong_flags().collect::<Vec<_>>(),
+        vec![Position { row: 0, col: 1 }]
+    );
+
+    // 布雷之前没有地雷
+    let board = Board::new(difficulty_to_board_config(Difficulty::Easy));
+    assert_eq!(board.spoiler().mines().count(), 0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_cell_content_matches_spoiler() {
+    let board = mined_board();
+    assert!(matches!(
+        board.get_cell_content(Position { row: 0, col: 0 }),
+        CellContent::Mine(1)
+    ));
+    assert!(matches!(
+        board.get_cell_content(Position { row: 0, col: 1 }),
+        CellContent::Number(2)
+    ));
+}
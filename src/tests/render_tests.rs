@@ -0,0 +1,192 @@
+// 棋盘输出测试
+//
+// 以快照验证 ASCII 和紧凑输出的布局，ANSI 输出去掉颜色代码后与 Unicode 布局一致，
+// 覆盖颜色、六边形、多雷和负雷的图例、视图不暴露未翻开的内容，以及输出方式的解析和选择
+//
+// 图例来自当前语言的消息表，这里只检查不依赖语言的部分
+
+use crate::support::{played_board, small_config};
+use crate::config::{Difficulty, difficulty_to_board_config, difficulty_to_hex_board_config};
+use crate::core::{Board, BoardConfig, BoardSize, Cell, CellState, Position};
+use crate::render::{
+    AnsiRenderer, AsciiRenderer, BoardView, CompactRenderer, RenderMode, Renderer,
+    is_utf8_locale, render_to_string, select_render_mode,
+};
+
+fn render(renderer: &dyn Renderer, view: &BoardView) -> String {
+    let mut out = Vec::new();
+    renderer.render(view, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' {
+            // 跳过到 'm' 为止的颜色代码
+            for ch in chars.by_ref() {
+                if ch == 'm' {
+                    break;
+                }
+            }
+        } else {
+            result.push(ch);
+        }
+    }
+    result
+}
+
+#[test]
+fn test_ascii_snapshot() {
+    let board = played_board();
+    let expected = concat!(
+        "     0  1  2  3 \n",
+        "   +------------+\n",
+        " 0 |       1  # |\n",
+        " 1 |       1  1 |\n",
+        " 2 | F          |\n",
+        "   +------------+\n",
+    );
+    assert_eq!(render(&AsciiRenderer, &BoardView::of(&board)), expected);
+}
+
+#[test]
+fn test_compact_snapshot() {
+    let board = played_board();
+    assert_eq!(
+        render(&CompactRenderer, &BoardView::of(&board)),
+        "..1#\n..11\nF...\n"
+    );
+}
+
+#[test]
+fn test_compact_shows_mines_after_game_over() {
+    let mut board = Board::new(small_config(3, 1));
+    board.place_mines_for_test(&[Position { row: 0, col: 2 }]);
+    board.left_click(Position { row: 0, col: 2 });
+    assert_eq!(render(&CompactRenderer, &BoardView::of(&board)), "##*\n");
+}
+
+#[test]
+fn test_view_hides_unrevealed_contents() {
+    let mut board = played_board();
+    let mine = Position { row: 0, col: 3 };
+    assert!(!BoardView::of(&board).cell(mine).is_mine());
+    board.right_click(mine);
+    assert!(!BoardView::of(&board).cell(mine).is_mine());
+
+    board.reveal_all_mines();
+    assert!(BoardView::of(&board).cell(mine).is_mine());
+}
+
+#[test]
+fn test_ansi_matches_unicode_layout() {
+    let board = played_board();
+    let output = render(&AnsiRenderer::default(), &BoardView::of(&board));
+    assert!(output.contains('\x1b'), "ANSI 输出应带颜色代码");
+    let expected = concat!(
+        "     0  1  2  3 \n",
+        "   +------------+\n",
+        " 0 |       1  □ |\n",
+        " 1 |       1  1 |\n",
+        " 2 | ⚑          |\n",
+        "   +------------+\n",
+    );
+    assert_eq!(strip_ansi(&output), expected);
+}
+
+#[test]
+fn test_plain_renderers_have_no_escape_codes_or_icons() {
+    let mut board = Board::new(difficulty_to_board_config(Difficulty::Easy));
+    board.left_click(Position { row: 4, col: 4 });
+    let view = BoardView::of(&board);
+    for renderer in [&AsciiRenderer as &dyn Renderer, &CompactRenderer] {
+        let output = render(renderer, &view);
+        assert!(output.is_ascii(), "输出应只含 ASCII: {}", output);
+        let legend = render_to_string(renderer, &view);
+        assert!(!legend.contains('\x1b') && !legend.contains('□'));
+    }
+}
+
+#[test]
+fn test_color_override_only_affects_ansi() {
+    let size = BoardSize {
+        width: 2,
+        height: 1,
+    };
+    let flagged = |_| {
+        let mut cell = Cell::new();
+        cell.set_state(CellState::Flagged);
+        cell
+    };
+    let view = BoardView::grid(size, flagged).with_colors(|pos| (pos.col == 1).then_some("<P>"));
+    let ansi = render(&AnsiRenderer::default(), &view);
+    assert_eq!(ansi.matches("<P>").count(), 1);
+    assert!(!render(&AsciiRenderer, &view).contains("<P>"));
+}
+
+#[test]
+fn test_hex_layout_shifts_odd_rows() {
+    let board = Board::new(BoardConfig {
+        board_size: BoardSize {
+            width: 2,
+            height: 2,
+        },
+        ..difficulty_to_hex_board_config(Difficulty::Easy)
+    });
+    let view = BoardView::of(&board);
+    assert_eq!(
+        render(&AsciiRenderer, &view),
+        "     0   1  \n 0   #   #  \n 1     #   #  \n"
+    );
+    assert_eq!(render(&CompactRenderer, &view), "##\n ##\n");
+}
+
+#[test]
+fn test_multi_and_negative_badges() {
+    let mut board = Board::new(BoardConfig {
+        mines_per_cell: 3,
+        ..small_config(3, 1)
+    });
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 0 }]);
+    board.place_negative_mines_for_test(&[Position { row: 0, col: 2 }]);
+    board.left_click(Position { row: 0, col: 1 });
+    board.right_click(Position { row: 0, col: 0 });
+    board.right_click(Position { row: 0, col: 0 });
+    let view = BoardView::of(&board);
+    let output = render(&AsciiRenderer, &view);
+    // 两面旗、数字 1 (2 - 1)、隐藏的负雷
+    assert!(output.contains("| F2 1  # |"), "{}", output);
+    // 图例在无颜色输出中也不含 Unicode 图标
+    let legend = render_to_string(&AsciiRenderer, &view);
+    assert!(!legend.contains('⚑') && !legend.contains('✹'));
+    assert_eq!(legend.lines().count(), output.lines().count() + 3);
+}
+
+#[test]
+fn test_render_mode_parse_and_select() {
+    for mode in RenderMode::ALL {
+        assert_eq!(RenderMode::parse(mode.name()), Some(mode));
+    }
+    assert_eq!(RenderMode::parse(" ASCII "), Some(RenderMode::Ascii));
+    assert_eq!(RenderMode::parse("html"), None);
+    assert_eq!(
+        select_render_mode(Some("compact"), Some(RenderMode::Ascii)),
+        RenderMode::Compact
+    );
+    assert_eq!(
+        select_render_mode(Some("bad"), Some(RenderMode::Ascii)),
+        RenderMode::Ascii
+    );
+    assert_eq!(select_render_mode(None, None), RenderMode::detect());
+}
+
+#[test]
+fn test_utf8_locale_detection() {
+    assert!(is_utf8_locale("en_US.UTF-8"));
+    assert!(is_utf8_locale("zh_CN.utf8"));
+    assert!(!is_utf8_locale("C"));
+    assert!(!is_utf8_locale("POSIX"));
+    assert!(!is_utf8_locale("zh_CN.GB18030"));
+}
@@ -0,0 +1,89 @@
+// 可见信息推理测试
+//
+// 验证单约束规则、子集规则和概率估计只依据翻开的格子
+
+use crate::core::{
+    Board, BoardConfig, BoardShape, BoardSize, Neighbourhood, Position, VisibleCell, analyze,
+};
+
+fn config(width: usize, height: usize, mine_count: usize) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize { width, height },
+        mine_count,
+        shape: BoardShape::Square,
+        neighbourhood: Neighbourhood::Moore,
+        mines_per_cell: 1,
+        negative_mines: 0,
+    }
+}
+
+#[test]
+fn test_single_constraint_finds_last_mine() {
+    let mut board = Board::new(config(3, 3, 1));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    let _ = board.left_click(Position { row: 2, col: 2 });
+
+    let analysis = analyze(&board);
+    assert_eq!(analysis.known_mines(), &[Position { row: 0, col: 0 }]);
+    assert!(analysis.known_safe().is_empty());
+    assert_eq!(analysis.probability(Position { row: 0, col: 0 }), Some(1.0));
+    assert_eq!(analysis.probability(Position { row: 1, col: 1 }), None);
+}
+
+#[test]
+fn test_subset_rule_solves_one_two_one() {
+    // 第一行隐藏，第二行为 1 2 1
+    let mut board = Board::new(config(3, 2, 2));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 2 }]);
+    for col in 0..3 {
+        let _ = board.left_click(Position { row: 1, col });
+    }
+    assert_eq!(
+        VisibleCell::of(&board, Position { row: 1, col: 1 }),
+        VisibleCell::Number(2)
+    );
+
+    let analysis = analyze(&board);
+    assert_eq!(
+        analysis.known_mines(),
+        &[Position { row: 0, col: 0 }, Position { row: 0, col: 2 }]
+    );
+    assert_eq!(analysis.known_safe(), &[Position { row: 0, col: 1 }]);
+    assert_eq!(analysis.safest(), Some(Position { row: 0, col: 1 }));
+}
+
+#[test]
+fn test_hidden_board_uses_mine_density() {
+    let mut board = Board::new(config(5, 5, 3));
+    board.place_mines_for_test(&[
+        Position { row: 0, col: 0 },
+        Position { row: 2, col: 2 },
+        Position { row: 4, col: 4 },
+    ]);
+
+    // 没有翻开任何格子时，地雷的位置对推理不可见
+    let analysis = analyze(&board);
+    assert!(analysis.known_mines().is_empty());
+    assert!(analysis.known_safe().is_empty());
+    for row in 0..5 {
+        for col in 0..5 {
+            assert_eq!(analysis.probability(Position { row, col }), Some(3.0 / 25.0));
+        }
+    }
+}
+
+#[test]
+fn test_flags_count_as_known_mines() {
+    let mut board = Board::new(config(3, 2, 2));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 2 }]);
+    let _ = board.left_click(Position { row: 1, col: 0 });
+    let _ = board.right_click(Position { row: 0, col: 0 });
+
+    // (1, 0) 的 1 已由旗子满足，它的其余邻居必然安全
+    let analysis = analyze(&board);
+    assert_eq!(
+        analysis.known_safe(),
+        &[Position { row: 0, col: 1 }, Position { row: 1, col: 1 }]
+    );
+    assert_eq!(analysis.probability(Position { row: 0, col: 0 }), None);
+}
@@ -0,0 +1,23 @@
+// 测试共用的棋盘
+//
+// 多个输出相关的测试使用同一个小棋盘，集中在这里避免各自复制一份
+
+use crate::config::{Difficulty, difficulty_to_board_config};
+use crate::core::{Board, BoardConfig, BoardSize, Position};
+
+/// 初级难度的规则，只改棋盘大小
+pub fn small_config(width: usize, height: usize) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize { width, height },
+        ..difficulty_to_board_config(Difficulty::Easy)
+    }
+}
+
+/// 4x3 棋盘，(0,3) 有雷，(2,0) 插了错旗，点开 (2,3) 展开其余格子
+pub fn played_board() -> Board {
+    let mut board = Board::new(small_config(4, 3));
+    board.place_mines_for_test(&[Position { row: 0, col: 3 }]);
+    board.right_click(Position { row: 2, col: 0 });
+    board.left_click(Position { row: 2, col: 3 });
+    board
+}
@@ -0,0 +1,197 @@
+// 主题测试
+//
+// 验证各配色在 16 色、256 色和真彩色下的转义序列、单色配色不输出颜色、
+// 图标集（含两列宽的 emoji）保持格子对齐，以及名字解析、颜色深度判断和设置文件中的主题键
+
+use crate::config::{Difficulty, Settings, difficulty_to_board_config};
+use crate::core::{Board, BoardConfig, BoardSize, Position};
+use crate::render::{
+    AnsiRenderer, BoardView, ColorDepth, GlyphSet, Palette, Renderer, Theme, ansi256_index,
+};
+
+// 1x3 棋盘：(0,0) 有雷并插旗，点开 (0,2) 后 (0,1) 是 1
+fn board() -> Board {
+    let mut board = Board::new(BoardConfig {
+        board_size: BoardSize {
+            width: 3,
+            height: 1,
+        },
+        ..difficulty_to_board_config(Difficulty::Easy)
+    });
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    board.right_click(Position { row: 0, col: 0 });
+    board.left_click(Position { row: 0, col: 2 });
+    board
+}
+
+fn render(theme: Theme, board: &Board) -> String {
+    let mut out = Vec::new();
+    AnsiRenderer::new(theme)
+        .render(&BoardView::of(board), &mut out)
+        .unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn theme(palette: Palette, glyphs: GlyphSet, depth: ColorDepth) -> Theme {
+    Theme {
+        palette,
+        glyphs,
+        depth,
+    }
+}
+
+#[test]
+fn test_default_theme_keeps_classic_codes() {
+    let output = render(Theme::default(), &board());
+    assert!(output.contains("\x1b[31m⚑"), "旗子为红色: {:?}", output);
+    assert!(output.contains("\x1b[34m1"), "1 为蓝色: {:?}", output);
+    assert!(output.contains("\x1b[90m 0"), "行号为暗色: {:?}", output);
+}
+
+#[test]
+fn test_color_depths() {
+    let board = board();
+    let classic = |depth| render(theme(Palette::Classic, GlyphSet::Unicode, depth), &board);
+    let true_color = classic(ColorDepth::TrueColor);
+    assert!(true_color.contains("\x1b[38;2;64;128;255m1"), "{:?}", true_color);
+    let ansi256 = classic(ColorDepth::Ansi256);
+    assert!(
+        ansi256.contains(&format!("\x1b[38;5;{}m1", ansi256_index((64, 128, 255)))),
+        "{:?}",
+        ansi256
+    );
+    assert!(!ansi256.contains("38;2;"));
+}
+
+#[test]
+fn test_high_contrast_is_bold() {
+    let board = board();
+    let basic = render(
+        theme(Palette::HighContrast, GlyphSet::Unicode, ColorDepth::Basic),
+        &board,
+    );
+    assert!(basic.contains("\x1b[1;94m1"), "{:?}", basic);
+    let true_color = render(
+        theme(Palette::HighContrast, GlyphSet::Unicode, ColorDepth::TrueColor),
+        &board,
+    );
+    assert!(true_color.contains("\x1b[1;38;2;"), "{:?}", true_color);
+}
+
+#[test]
+fn test_colorblind_avoids_red_green_numbers() {
+    let colors = Palette::ColorBlind.colors();
+    for n in 1..=3 {
+        let code = colors.number(n).code(ColorDepth::Basic);
+        assert!(
+            code != "\x1b[31m" && code != "\x1b[32m",
+            "数字 {} 不应使用红或绿",
+            n
+        );
+    }
+    assert_eq!(Palette::parse("deuteranopia"), Some(Palette::ColorBlind));
+    assert_eq!(Palette::parse("Protanopia"), Some(Palette::ColorBlind));
+}
+
+#[test]
+fn test_monochrome_has_no_colors() {
+    for depth in ColorDepth::ALL {
+        let output = render(theme(Palette::Monochrome, GlyphSet::Unicode, depth), &board());
+        assert!(!output.contains("38;"), "{:?}", output);
+        for code in 30..=37 {
+            assert!(!output.contains(&format!("[{}m", code)));
+        }
+    }
+}
+
+#[test]
+fn test_glyph_sets() {
+    let board = board();
+    let ascii = render(
+        theme(Palette::Classic, GlyphSet::Ascii, ColorDepth::Basic),
+        &board,
+    );
+    assert!(ascii.contains("\x1b[31mF") && !ascii.contains('⚑'));
+    let emoji = render(
+        theme(Palette::Classic, GlyphSet::Emoji, ColorDepth::Basic),
+        &board,
+    );
+    // 两列宽的图标只保留左侧的符号位，格子仍占三列
+    assert!(emoji.contains(" \x1b[31m🚩\x1b[0m \x1b[34m1"), "{:?}", emoji);
+}
+
+#[test]
+fn test_wide_glyph_keeps_badge() {
+    let mut board = Board::new(crate::config::multi_mine_board_config());
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }, Position { row: 0, col: 0 }]);
+    board.right_click(Position { row: 0, col: 0 });
+    board.right_click(Position { row: 0, col: 0 });
+    let output = render(
+        theme(Palette::Classic, GlyphSet::Emoji, ColorDepth::Basic),
+        &board,
+    );
+    assert!(output.contains("🚩\x1b[0m2"), "{:?}", output);
+}
+
+#[test]
+fn test_ansi256_index() {
+    assert_eq!(ansi256_index((255, 0, 0)), 196);
+    assert_eq!(ansi256_index((0, 0, 255)), 21);
+    assert_eq!(ansi256_index((0, 0, 0)), 16);
+    assert_eq!(ansi256_index((255, 255, 255)), 231);
+    assert_eq!(ansi256_index((128, 128, 128)), 244);
+    assert_eq!(ansi256_index((248, 248, 248)), 255);
+}
+
+#[test]
+fn test_names_and_apply() {
+    for palette in Palette::ALL {
+        assert_eq!(Palette::parse(palette.name()), Some(palette));
+    }
+    for glyphs in GlyphSet::ALL {
+        assert_eq!(GlyphSet::parse(glyphs.name()), Some(glyphs));
+    }
+    for depth in ColorDepth::ALL {
+        assert_eq!(ColorDepth::parse(depth.name()), Some(depth));
+    }
+    assert_eq!(ColorDepth::parse("24bit"), Some(ColorDepth::TrueColor));
+
+    let mut theme = Theme::default();
+    assert!(theme.apply("high-contrast"));
+    assert!(theme.apply("emoji"));
+    assert!(theme.apply("256"));
+    assert!(!theme.apply("neon"));
+    assert_eq!(
+        theme,
+        Theme {
+            palette: Palette::HighContrast,
+            glyphs: GlyphSet::Emoji,
+            depth: ColorDepth::Ansi256,
+        }
+    );
+}
+
+#[test]
+fn test_detect_color_depth() {
+    assert_eq!(
+        ColorDepth::from_env("truecolor", "xterm"),
+        ColorDepth::TrueColor
+    );
+    assert_eq!(ColorDepth::from_env("24bit", ""), ColorDepth::TrueColor);
+    assert_eq!(
+        ColorDepth::from_env("", "xterm-256color"),
+        ColorDepth::Ansi256
+    );
+    assert_eq!(ColorDepth::from_env("", "xterm"), ColorDepth::Basic);
+    assert_eq!(ColorDepth::from_env("", ""), ColorDepth::Basic);
+}
+
+#[test]
+fn test_settings_theme_keys() {
+    let (settings, unrecognised) =
+        Settings::parse("theme = monochrome\nglyphs = ascii\ncolors = truecolor\ntheme = neon\n");
+    assert_eq!(settings.theme, Some(Palette::Monochrome));
+    assert_eq!(settings.glyphs, Some(GlyphSet::Ascii));
+    assert_eq!(settings.colors, Some(ColorDepth::TrueColor));
+    assert_eq!(unrecognised, vec![(4, "theme = neon".to_string())]);
+}
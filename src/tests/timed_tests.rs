@@ -0,0 +1,166 @@
+// 计时模式测试
+//
+// 使用手动时钟验证倒计时加时、超时、限时挑战的连续棋盘，以及按模式保存的成绩
+
+use crate::config::{Difficulty, countdown_config, difficulty_to_board_config};
+use crate::core::{
+    Board, BoardSize, CellContent, ManualClock, Position, TimedChallenge, TimedConfig, TimedEvent,
+    TimedMode, TimedOutcome, TimedRecords, TimedResult,
+};
+use std::time::Duration;
+
+fn time_attack(size: usize, mine_count: usize) -> TimedConfig {
+    let mut board_config = difficulty_to_board_config(Difficulty::Easy);
+    board_config.board_size = BoardSize {
+        width: size,
+        height: size,
+    };
+    board_config.mine_count = mine_count;
+    TimedConfig {
+        mode: TimedMode::TimeAttack,
+        board_config,
+        time_limit: Duration::from_secs(60),
+        bonus_per_opening: Duration::ZERO,
+        seed: 1,
+    }
+}
+
+fn result(mode: TimedMode, outcome: TimedOutcome, boards: u32, cells: usize, secs: u64) -> TimedResult {
+    TimedResult {
+        mode,
+        outcome,
+        boards_cleared: boards,
+        cells_revealed: cells,
+        elapsed: Duration::from_secs(secs),
+    }
+}
+
+#[test]
+fn test_seeded_boards_are_reproducible() {
+    let config = difficulty_to_board_config(Difficulty::Medium);
+    let first = Position { row: 3, col: 7 };
+    let mut a = Board::with_seed(config, 42);
+    let mut b = Board::with_seed(config, 42);
+    let _ = a.left_click(first);
+    let _ = b.left_click(first);
+
+    for row in 0..16 {
+        for col in 0..16 {
+            let pos = Position { row, col };
+            let same = match (a.spoiler().content(pos), b.spoiler().content(pos)) {
+                (CellContent::Mine(x), CellContent::Mine(y)) => x == y,
+                (CellContent::Number(x), CellContent::Number(y)) => x == y,
+                _ => false,
+            };
+            assert!(same, "({}, {}) 不一致", row, col);
+        }
+    }
+}
+
+#[test]
+fn test_countdown_opening_grants_bonus() {
+    let clock = ManualClock::new();
+    let config = countdown_config(7);
+    let mut challenge = TimedChallenge::new(config, clock.clone());
+
+    clock.advance(Duration::from_secs(10));
+    // 首次点击周围没有地雷，一定会展开空白区域
+    let event = challenge.left_click(Position { row: 8, col: 8 });
+    assert_eq!(event, TimedEvent::Opening(config.bonus_per_opening));
+    assert_eq!(
+        challenge.remaining(),
+        config.time_limit + config.bonus_per_opening - Duration::from_secs(10)
+    );
+}
+
+#[test]
+fn test_countdown_ends_when_clock_runs_out() {
+    let clock = ManualClock::new();
+    let config = countdown_config(7);
+    let mut challenge = TimedChallenge::new(config, clock.clone());
+    assert_eq!(challenge.tick(), None);
+
+    clock.advance(config.time_limit + Duration::from_secs(3));
+    let event = challenge.left_click(Position { row: 0, col: 0 });
+    let TimedEvent::Finished(result) = event else {
+        panic!("超时后应该结束: {:?}", event);
+    };
+    assert_eq!(result.outcome, TimedOutcome::TimeUp);
+    // 用时记为截止时刻，而不是发现超时的时刻
+    assert_eq!(result.elapsed, config.time_limit);
+    assert!(!challenge.board().are_mines_placed());
+}
+
+#[test]
+fn test_countdown_mine_hit_finishes() {
+    let clock = ManualClock::new();
+    let mut challenge = TimedChallenge::new(countdown_config(3), clock);
+    let _ = challenge.left_click(Position { row: 8, col: 8 });
+
+    let mine = (0..16)
+        .flat_map(|row| (0..16).map(move |col| Position { row, col }))
+        .find(|&pos| matches!(challenge.board().spoiler().content(pos), CellContent::Mine(_)))
+        .unwrap();
+    let TimedEvent::Finished(result) = challenge.left_click(mine) else {
+        panic!("踩雷后应该结束");
+    };
+    assert_eq!(result.outcome, TimedOutcome::MineHit);
+    assert_eq!(result.boards_cleared, 0);
+    assert_eq!(challenge.result(), Some(result));
+}
+
+#[test]
+fn test_time_attack_moves_to_next_board() {
+    let clock = ManualClock::new();
+    let mut challenge = TimedChallenge::new(time_attack(3, 0), clock.clone());
+
+    // 没有地雷的棋盘一次点击即可清空
+    let center = Position { row: 1, col: 1 };
+    assert_eq!(challenge.left_click(center), TimedEvent::BoardCleared(1));
+    assert!(!challenge.board().are_mines_placed());
+    assert_eq!(challenge.left_click(center), TimedEvent::BoardCleared(2));
+
+    clock.advance(Duration::from_secs(61));
+    let result = challenge.tick().expect("时间到后应该结束");
+    assert_eq!(result.outcome, TimedOutcome::TimeUp);
+    assert_eq!(result.boards_cleared, 2);
+    assert_eq!(result.cells_revealed, 18);
+    assert!(matches!(
+        challenge.right_click(center),
+        TimedEvent::Finished(_)
+    ));
+}
+
+#[test]
+fn test_records_keep_modes_apart_and_pick_best() {
+    let mut records = TimedRecords::new();
+    records.record(result(TimedMode::Countdown, TimedOutcome::MineHit, 0, 200, 50));
+    records.record(result(TimedMode::Countdown, TimedOutcome::Cleared, 1, 216, 90));
+    records.record(result(TimedMode::Countdown, TimedOutcome::Cleared, 1, 216, 80));
+    records.record(result(TimedMode::TimeAttack, TimedOutcome::TimeUp, 4, 250, 180));
+    records.record(result(TimedMode::TimeAttack, TimedOutcome::MineHit, 4, 260, 120));
+
+    assert_eq!(records.results(TimedMode::Countdown).len(), 3);
+    assert_eq!(records.results(TimedMode::TimeAttack).len(), 2);
+    assert_eq!(
+        records.best(TimedMode::Countdown).unwrap().elapsed,
+        Duration::from_secs(80)
+    );
+    assert_eq!(records.best(TimedMode::TimeAttack).unwrap().cells_revealed, 260);
+}
+
+#[test]
+fn test_records_round_trip_through_text() {
+    let mut records = TimedRecords::new();
+    records.record(result(TimedMode::Countdown, TimedOutcome::Cleared, 1, 216, 95));
+    records.record(result(TimedMode::TimeAttack, TimedOutcome::TimeUp, 3, 190, 180));
+
+    let parsed = TimedRecords::parse(&records.to_text()).expect("valid records");
+    assert_eq!(parsed.results(TimedMode::Countdown), records.results(TimedMode::Countdown));
+    assert_eq!(parsed.results(TimedMode::TimeAttack), records.results(TimedMode::TimeAttack));
+
+    assert!(TimedRecords::parse("countdown cleared 1 2").is_err());
+    assert!(TimedRecords::parse("zen cleared 1 2 3").is_err());
+    assert!(TimedRecords::parse("countdown won 1 2 3").is_err());
+    assert!(TimedRecords::load("no_such_records_file.txt").unwrap().best(TimedMode::Countdown).is_none());
+}
@@ -0,0 +1,113 @@
+// 机器人比赛测试
+//
+// 验证 3BV 计算、固定种子的可复现性、多个机器人在相同种子上的比较，以及猜测和无效操作的统计
+
+use crate::bot::{
+    Bot, BotAction, RandomBot, SolverBot, Tournament, play_game, three_bv,
+};
+use crate::config::{Difficulty, difficulty_to_board_config};
+use crate::core::{Board, BoardConfig, BoardSize, Position, VisibleBoard};
+
+fn small_config(width: usize, height: usize) -> BoardConfig {
+    BoardConfig {
+        board_size: BoardSize { width, height },
+        mine_count: 1,
+        ..difficulty_to_board_config(Difficulty::Easy)
+    }
+}
+
+// 一直翻开同一个格子的机器人
+struct StubbornBot(Position);
+
+impl Bot for StubbornBot {
+    fn name(&self) -> &str {
+        "stubborn"
+    }
+
+    fn next_action(&mut self, _view: &VisibleBoard) -> BotAction {
+        BotAction::Reveal(self.0)
+    }
+}
+
+#[test]
+fn test_three_bv_counts_openings_and_isolated_numbers() {
+    // 角上一个地雷：其余格子连成一片空白，点一下即可清空
+    let mut board = Board::new(small_config(5, 5));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    assert_eq!(three_bv(&board), 1);
+
+    // 没有空白：8 个数字格子各需要点一下
+    let mut board = Board::new(small_config(3, 3));
+    board.place_mines_for_test(&[Position { row: 1, col: 1 }]);
+    assert_eq!(three_bv(&board), 8);
+
+    // 中间一列地雷把空白分成左右两片
+    let mut board = Board::new(small_config(5, 3));
+    board.place_mines_for_test(&[
+        Position { row: 0, col: 2 },
+        Position { row: 1, col: 2 },
+        Position { row: 2, col: 2 },
+    ]);
+    assert_eq!(three_bv(&board), 2);
+}
+
+#[test]
+fn test_games_are_reproducible_for_a_seed() {
+    let config = difficulty_to_board_config(Difficulty::Medium);
+    for seed in 0..5 {
+        let first = play_game(&mut SolverBot::new(), config, seed);
+        let second = play_game(&mut SolverBot::new(), config, seed);
+        assert_eq!(first, second);
+
+        // 随机机器人按种子重置，同样可复现
+        let mut bot = RandomBot::new();
+        let first = play_game(&mut bot, config, seed);
+        let second = play_game(&mut bot, config, seed);
+        assert_eq!(first, second);
+    }
+}
+
+#[test]
+fn test_bots_face_the_same_boards() {
+    let config = difficulty_to_board_config(Difficulty::Easy);
+    for seed in 0..10 {
+        let solver = play_game(&mut SolverBot::new(), config, seed);
+        let random = play_game(&mut RandomBot::new(), config, seed);
+        assert_eq!(solver.bbbv, random.bbbv);
+    }
+}
+
+#[test]
+fn test_tournament_compares_bots() {
+    let tournament = Tournament {
+        difficulties: vec![Difficulty::Easy],
+        games: 20,
+        base_seed: 100,
+    };
+    let mut bots: Vec<Box<dyn Bot>> = vec![Box::new(SolverBot::new()), Box::new(RandomBot::new())];
+    let results = tournament.run(&mut bots);
+
+    assert_eq!(results.len(), 2);
+    let (solver, random) = (&results[0], &results[1]);
+    assert_eq!(solver.bot, "solver");
+    assert_eq!(random.bot, "random");
+    assert!(results.iter().all(|stats| stats.games == 20));
+    assert!(solver.win_rate() > random.win_rate());
+    assert!(solver.average_guesses() < random.average_guesses());
+    assert_eq!(solver.invalid_actions, 0);
+    if solver.wins > 0 {
+        assert!(solver.bbbv_per_second() > 0.0);
+    }
+}
+
+#[test]
+fn test_invalid_actions_are_counted_and_games_are_capped() {
+    let config = difficulty_to_board_config(Difficulty::Easy);
+    // 中心已由裁判翻开，再次翻开是无效且不算猜测的操作
+    let center = Position { row: 4, col: 4 };
+    let record = play_game(&mut StubbornBot(center), config, 7);
+    assert!(!record.won);
+    assert_eq!(record.guesses, 0);
+    assert_eq!(record.actions, config.board_size.cell_count() * 2 + 1);
+    assert_eq!(record.invalid_actions, record.actions - 1);
+}
@@ -0,0 +1,284 @@
+// 联机对战测试
+//
+// 在本机端口上运行服务器，验证协议编解码、地雷布局不外泄、进度广播和胜负裁定、拒绝非经典规则的棋盘，
+// 以及超长消息的断开和过长名字的截断
+
+use crate::config::network::{MAX_LINE_BYTES, MAX_NAME_CHARS};
+use crate::core::{Board, BoardConfig, BoardShape, BoardSize, CellContent, CellState, Neighbourhood, Position};
+use crate::net::{ClientMessage, ServerMessage, VersusConfig, VersusServer, decode_view, encode_view};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const SEED: u64 = 42;
+
+fn versus_config(lives: u32) -> VersusConfig {
+    VersusConfig {
+        board_config: BoardConfig {
+            board_size: BoardSize {
+                width: 8,
+                height: 8,
+            },
+            mine_count: 10,
+            shape: BoardShape::Square,
+            neighbourhood: Neighbourhood::Moore,
+            mines_per_cell: 1,
+            negative_mines: 0,
+        },
+        seed: SEED,
+        lives,
+    }
+}
+
+fn pos(row: usize, col: usize) -> Position {
+    Position { row, col }
+}
+
+struct TestClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl TestClient {
+    fn connect(addr: std::net::SocketAddr, name: &str) -> Self {
+        let writer = TcpStream::connect(addr).unwrap();
+        let mut client = TestClient {
+            reader: BufReader::new(writer.try_clone().unwrap()),
+            writer,
+        };
+        assert!(matches!(client.next(), ServerMessage::Welcome { .. }));
+        client.send(&ClientMessage::Hello(name.to_string()));
+        client
+    }
+
+    fn send(&mut self, message: &ClientMessage) {
+        writeln!(self.writer, "{}", message.to_line()).unwrap();
+    }
+
+    fn next(&mut self) -> ServerMessage {
+        let mut line = String::new();
+        self.reader.read_line(&mut line).unwrap();
+        ServerMessage::parse(&line).unwrap_or_else(|| panic!("无法解析: {}", line))
+    }
+
+    // 读取消息直到比赛结果，返回途中收到的所有消息
+    fn until_result(&mut self) -> Vec<ServerMessage> {
+        let mut messages = Vec::new();
+        loop {
+            let message = self.next();
+            let done = matches!(message, ServerMessage::Result { .. });
+            messages.push(message);
+            if done {
+                return messages;
+            }
+        }
+    }
+}
+
+fn start(lives: u32) -> (JoinHandle<Option<usize>>, TestClient, TestClient) {
+    let server = VersusServer::bind("127.0.0.1:0", versus_config(lives)).unwrap();
+    let addr = server.local_addr().unwrap();
+    let handle = thread::spawn(move || server.run().unwrap());
+    let mut first = TestClient::connect(addr, "甲");
+    let mut second = TestClient::connect(addr, "乙");
+    // 双方都加入后才开赛，之前的点击会被拒绝
+    for client in [&mut first, &mut second] {
+        assert!(matches!(client.next(), ServerMessage::Start { .. }));
+    }
+    (handle, first, second)
+}
+
+// 与服务器相同的棋盘：相同种子并从中心开局
+fn reference_board() -> Board {
+    let config = versus_config(1).board_config;
+    let mut board = Board::with_seed(config, SEED);
+    let _ = board.left_click(pos(4, 4));
+    board
+}
+
+fn cells_where(board: &Board, wanted: impl Fn(&Board, Position) -> bool) -> Vec<Position> {
+    (0..8)
+        .flat_map(|row| (0..8).map(move |col| pos(row, col)))
+        .filter(|&p| wanted(board, p))
+        .collect()
+}
+
+#[test]
+fn test_protocol_round_trip() {
+    let client = [
+        ClientMessage::Hello("玩家 一".to_string()),
+        ClientMessage::Click(pos(3, 5)),
+        ClientMessage::Flag(pos(0, 7)),
+        ClientMessage::Quit,
+    ];
+    for message in client {
+        assert_eq!(ClientMessage::parse(&message.to_line()), Some(message));
+    }
+
+    let server = [
+        ServerMessage::Welcome {
+            player: 1,
+            size: BoardSize {
+                width: 16,
+                height: 9,
+            },
+            mines: 40,
+            lives: 3,
+        },
+        ServerMessage::Progress {
+            player: 0,
+            percent: 57,
+            lives: 2,
+            finish: Some(Duration::from_millis(12345)),
+        },
+        ServerMessage::Progress {
+            player: 1,
+            percent: 0,
+            lives: 3,
+            finish: None,
+        },
+        ServerMessage::Result { winner: None },
+        ServerMessage::Result { winner: Some(1) },
+        ServerMessage::Error("坐标超出范围".to_string()),
+    ];
+    for message in server {
+        assert_eq!(ServerMessage::parse(&message.to_line()), Some(message));
+    }
+
+    assert_eq!(ClientMessage::parse("CLICK 1"), None);
+    assert_eq!(ClientMessage::parse("DIG 1 2"), None);
+}
+
+#[test]
+fn test_view_does_not_reveal_mines() {
+    let board = reference_board();
+    let view = encode_view(&board);
+    assert!(!view.contains('*'));
+
+    let size = board.get_board_config().board_size;
+    let decoded = decode_view(&view, size).unwrap();
+    let hidden = decoded
+        .iter()
+        .filter(|&&cell| cell == crate::core::VisibleCell::Hidden)
+        .count();
+    assert_eq!(hidden, size.cell_count() - board.get_revealed_count());
+    assert_eq!(decode_view("#", size), None);
+}
+
+#[test]
+fn test_first_to_clear_wins() {
+    let (handle, mut first, mut second) = start(3);
+    let board = reference_board();
+    let safe = cells_where(&board, |board, p| {
+        board.get_cell_state(p) == CellState::Hidden
+            && matches!(board.spoiler().content(p), CellContent::Number(_))
+    });
+    for &p in &safe {
+        first.send(&ClientMessage::Click(p));
+    }
+
+    let messages = first.until_result();
+    assert!(matches!(messages.last(), Some(ServerMessage::Result { winner: Some(0) })));
+    for message in &messages {
+        if let ServerMessage::View(cells) = message {
+            assert!(!cells.contains('*'), "棋盘视图不应包含地雷");
+        }
+    }
+
+    // 对手收到进度广播，其中包含完成时间
+    let opponent_view = second.until_result();
+    assert!(opponent_view.iter().any(|message| matches!(
+        message,
+        ServerMessage::Progress {
+            player: 0,
+            percent: 100,
+            finish: Some(_),
+            ..
+        }
+    )));
+    assert!(matches!(opponent_view.last(), Some(ServerMessage::Result { winner: Some(0) })));
+    assert_eq!(handle.join().unwrap(), Some(0));
+}
+
+#[test]
+fn test_invalid_click_reports_error_and_quit_forfeits() {
+    let (handle, mut first, mut second) = start(3);
+
+    first.send(&ClientMessage::Click(pos(8, 0)));
+    let messages: Vec<ServerMessage> = (0..4).map(|_| first.next()).collect();
+    assert!(matches!(messages.last(), Some(ServerMessage::Error(_))));
+
+    first.send(&ClientMessage::Quit);
+    assert!(matches!(
+        second.until_result().last(),
+        Some(ServerMessage::Result { winner: Some(1) })
+    ));
+    assert_eq!(handle.join().unwrap(), Some(1));
+}
+
+#[test]
+fn test_both_out_with_equal_progress_is_draw() {
+    let (handle, mut first, mut second) = start(1);
+    let mine = cells_where(&reference_board(), |board, p| {
+        matches!(board.spoiler().content(p), CellContent::Mine(_))
+    })[0];
+
+    first.send(&ClientMessage::Click(mine));
+    second.send(&ClientMessage::Click(mine));
+
+    let messages = first.until_result();
+    assert!(messages.iter().any(|message| matches!(
+        message,
+        ServerMessage::Progress {
+            player: 0,
+            lives: 0,
+            ..
+        }
+    )));
+    assert!(matches!(messages.last(), Some(ServerMessage::Result { winner: None })));
+    assert_eq!(handle.join().unwrap(), None);
+}
+
+#[test]
+fn test_bind_rejects_non_classic_boards() {
+    let mut config = versus_config(1);
+    config.board_config.mines_per_cell = 2;
+    let err = VersusServer::bind("127.0.0.1:0", config).err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+    let mut config = versus_config(1);
+    config.board_config.negative_mines = 1;
+    let err = VersusServer::bind("127.0.0.1:0", config).err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn test_long_names_are_truncated() {
+    let line = format!("HELLO {}", "名".repeat(MAX_NAME_CHARS + 10));
+    assert_eq!(
+        ClientMessage::parse(&line),
+        Some(ClientMessage::Hello("名".repeat(MAX_NAME_CHARS)))
+    );
+}
+
+#[test]
+fn test_overlong_line_disconnects_player() {
+    let (handle, mut first, mut second) = start(1);
+    first
+        .writer
+        .write_all(&vec![b'A'; MAX_LINE_BYTES * 4])
+        .unwrap();
+
+    // 服务器断开发送超长消息的玩家，按弃权处理
+    let mut line = String::new();
+    while matches!(first.reader.read_line(&mut line), Ok(read) if read > 0) {
+        line.clear();
+    }
+    let messages = second.until_result();
+    assert_eq!(
+        messages.last(),
+        Some(&ServerMessage::Result { winner: Some(1) })
+    );
+    assert_eq!(handle.join().unwrap(), Some(1));
+}
@@ -0,0 +1,173 @@
+// 视口测试
+//
+// 验证任意宽度下行列号与格子对齐、只输出视口内的格子且坐标不变、
+// 视口的滚动、跳转、跟随和边界限制，以及小地图的字符和视口标记
+
+use crate::support::small_config;
+use crate::core::{Board, BoardShape, BoardSize, Position};
+use crate::render::{
+    AsciiRenderer, BoardView, CompactRenderer, Viewport, describe_row, render_to_string,
+};
+
+fn size(width: usize, height: usize) -> BoardSize {
+    BoardSize { width, height }
+}
+
+#[test]
+fn test_wide_board_labels_stay_aligned() {
+    let board = Board::new(small_config(105, 3));
+    let output = render_to_string(&AsciiRenderer, &BoardView::of(&board));
+    // 最后一行是图例
+    let lines: Vec<&str> = output.lines().collect();
+    let board_lines = &lines[..lines.len() - 1];
+
+    // 三位数的列号按位竖排成三行，每个数字与格子中的图标在同一列
+    let (header, grid) = board_lines.split_at(3);
+    let icon_column = |col: usize| 4 + 3 * col + 1;
+    for (col, expected) in [(0, "  0"), (9, "  9"), (10, " 10"), (104, "104")] {
+        let digits: String = header
+            .iter()
+            .map(|line| line.chars().nth(icon_column(col)).unwrap_or(' '))
+            .collect();
+        assert_eq!(digits, expected, "第 {} 列的列号", col);
+        assert_eq!(grid[1].chars().nth(icon_column(col)), Some('#'));
+    }
+    let width = grid[0].len();
+    assert!(grid.iter().all(|line| line.len() == width), "{}", output);
+}
+
+#[test]
+fn test_tall_board_labels_stay_aligned() {
+    let board = Board::new(small_config(3, 120));
+    let output = render_to_string(&AsciiRenderer, &BoardView::of(&board));
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "      0  1  2 ");
+    assert_eq!(lines[1], "    +---------+");
+    assert_eq!(lines[2], "  0 | #  #  # |");
+    assert_eq!(lines[121], "119 | #  #  # |");
+}
+
+#[test]
+fn test_small_boards_keep_layout() {
+    let board = Board::new(small_config(3, 2));
+    let output = render_to_string(&AsciiRenderer, &BoardView::of(&board));
+    // 棋盘之后是图例
+    assert!(
+        output.starts_with(concat!(
+            "     0  1  2 \n",
+            "   +---------+\n",
+            " 0 | #  #  # |\n",
+            " 1 | #  #  # |\n",
+            "   +---------+\n",
+        )),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_viewport_keeps_board_coordinates() {
+    let mut board = Board::new(small_config(20, 20));
+    board.place_mines_for_test(&[Position { row: 0, col: 0 }]);
+    board.right_click(Position { row: 11, col: 12 });
+    let viewport = Viewport {
+        top: 10,
+        left: 11,
+        width: 5,
+        height: 5,
+    };
+    let view = BoardView::of(&board).with_viewport(&viewport);
+    let output = render_to_string(&AsciiRenderer, &view);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "    11 12 13 14 15 ");
+    assert_eq!(lines[3], "11 | #  F  #  #  # |");
+    // 列号、上边框、5 行格子、下边框和图例
+    assert_eq!(lines.len(), 2 + 5 + 1 + 1);
+
+    assert_eq!(
+        render_to_string(&CompactRenderer, &view).lines().nth(1),
+        Some("#F###")
+    );
+    assert_eq!(describe_row(&view, 11), "第 11 行：第 11 列 未翻开；第 12 列 旗子；第 13 至 15 列 未翻开");
+}
+
+#[test]
+fn test_pan_and_clamp() {
+    let board = size(50, 40);
+    let mut viewport = Viewport::new(10, 8);
+    viewport.pan(-3, -3, board);
+    assert_eq!((viewport.top, viewport.left), (0, 0));
+    viewport.pan(5, 7, board);
+    assert_eq!((viewport.top, viewport.left), (5, 7));
+    viewport.pan(100, 100, board);
+    assert_eq!((viewport.top, viewport.left), (32, 40));
+    assert_eq!(viewport.rows(board), 32..40);
+    assert_eq!(viewport.cols(board), 40..50);
+}
+
+#[test]
+fn test_center_and_follow() {
+    let board = size(50, 40);
+    let mut viewport = Viewport::new(10, 8);
+    viewport.center_on(Position { row: 20, col: 25 }, board);
+    assert_eq!((viewport.top, viewport.left), (16, 20));
+
+    // 格子已在视口内时不移动
+    viewport.follow(Position { row: 17, col: 21 }, board);
+    assert_eq!((viewport.top, viewport.left), (16, 20));
+
+    viewport.follow(Position { row: 39, col: 0 }, board);
+    assert_eq!((viewport.top, viewport.left), (32, 0));
+    assert!(viewport.contains(Position { row: 39, col: 0 }));
+}
+
+#[test]
+fn test_viewport_size() {
+    let board = size(50, 40);
+    let mut viewport = Viewport::new(60, 30);
+    assert!(!viewport.covers(board));
+    viewport.resize(1, 1, board);
+    assert_eq!((viewport.width, viewport.height), (5, 5));
+    assert!(Viewport::new(9, 9).covers(size(9, 9)));
+
+    let square = Viewport::for_terminal(BoardShape::Square, Some(80), Some(40));
+    assert_eq!((square.width, square.height), (24, 26));
+    let hex = Viewport::for_terminal(BoardShape::Hex, Some(80), None);
+    assert_eq!(hex.width, 18);
+    // 不知道终端大小时至少能完整显示困难难度
+    assert!(Viewport::for_terminal(BoardShape::Square, None, None).covers(size(30, 16)));
+}
+
+#[test]
+fn test_minimap() {
+    let mut board = Board::new(small_config(80, 24));
+    board.place_mines_for_test(&[Position { row: 23, col: 79 }]);
+    board.right_click(Position { row: 23, col: 79 });
+    board.left_click(Position { row: 0, col: 0 });
+    let viewport = Viewport {
+        top: 0,
+        left: 0,
+        width: 10,
+        height: 5,
+    };
+    let (lines, block_width, block_height) = viewport.minimap(&BoardView::of(&board));
+    assert_eq!((block_width, block_height), (2, 2));
+    // 12 行小地图和一行列标记
+    assert_eq!(lines.len(), 13);
+    assert!(lines[0].starts_with("....") && lines[0].ends_with(" <"));
+    assert!(lines[2].ends_with(" <") && !lines[3].ends_with(" <"));
+    assert!(lines[11].ends_with('F'), "{:?}", lines);
+    assert_eq!(lines[12], "^^^^^");
+}
+
+#[test]
+fn test_minimap_partial_blocks() {
+    let mut board = Board::new(small_config(90, 30));
+    board.place_mines_for_test(&[Position { row: 0, col: 1 }]);
+    board.left_click(Position { row: 0, col: 0 });
+    let (lines, block_width, block_height) =
+        Viewport::new(10, 10).minimap(&BoardView::of(&board));
+    assert_eq!((block_width, block_height), (3, 3));
+    assert!(lines[0].starts_with("+#"), "{:?}", lines);
+    assert_eq!(lines[0].chars().filter(|&c| c != ' ' && c != '<').count(), 30);
+}